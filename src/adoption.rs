@@ -0,0 +1,27 @@
+pub mod adoption {
+    use crate::webscenarios::webscenarios::ZabbixWebScenario;
+
+    /// Untagged web scenario of host created by versions before tagging: it has the name wszl would create
+    /// and checks url (its first step)
+    pub fn find_legacy_web_scenario<'a>(web_scenarios: &'a [ZabbixWebScenario], hostid: &str, url: &str,
+                                        scenario_name: &str) -> Option<&'a ZabbixWebScenario> {
+        web_scenarios.iter().find(|web_scenario| {
+            web_scenario.hostid == hostid && web_scenario.name == scenario_name &&
+                get_first_step_url(web_scenario) == Some(url)
+        })
+    }
+
+    fn get_first_step_url(web_scenario: &ZabbixWebScenario) -> Option<&str> {
+        web_scenario.steps.iter().min_by_key(|step| step.no.parse::<u32>().unwrap_or(u32::MAX))
+            .map(|step| step.url.as_str())
+    }
+
+    /// Hand-made web scenario of host which checks url (its first step) or has the name wszl would create
+    pub fn find_adoptable_web_scenario<'a>(web_scenarios: &'a [ZabbixWebScenario], hostid: &str, url: &str,
+                                           scenario_name: &str) -> Option<&'a ZabbixWebScenario> {
+        let host_web_scenarios = || web_scenarios.iter().filter(move |web_scenario| web_scenario.hostid == hostid);
+
+        host_web_scenarios().find(|web_scenario| web_scenario.name == scenario_name)
+            .or_else(|| host_web_scenarios().find(|web_scenario| get_first_step_url(web_scenario) == Some(url)))
+    }
+}
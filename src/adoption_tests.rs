@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod adoption_tests {
+    use crate::adoption::adoption::{find_adoptable_web_scenario, find_legacy_web_scenario};
+    use crate::webscenarios::webscenarios::{ZabbixHttpStep, ZabbixWebScenario};
+
+    fn get_web_scenario(httptestid: &str, hostid: &str, name: &str, urls: &[&str]) -> ZabbixWebScenario {
+        ZabbixWebScenario {
+            httptestid: httptestid.to_string(),
+            hostid: hostid.to_string(),
+            name: name.to_string(),
+            steps: urls.iter().enumerate().map(|(index, url)| ZabbixHttpStep {
+                httpstepid: format!("{}{}", httptestid, index + 1), no: (index + 1).to_string(), url: url.to_string(),
+                name: String::new()
+            }).collect(),
+            tags: vec![]
+        }
+    }
+
+    #[test]
+    fn web_scenario_checking_url_should_be_adopted() {
+        let web_scenarios = [
+            get_web_scenario("1", "10001", "Shop", &["https://shop.example.com/login", "https://shop.example.com"]),
+            get_web_scenario("2", "10001", "Homepage", &["https://www.example.com"]),
+            get_web_scenario("3", "10002", "Shop", &["https://shop.example.com"])
+        ];
+
+        let adoptable = find_adoptable_web_scenario(&web_scenarios, "10001", "https://www.example.com",
+                                                    "Check index page 'https://www.example.com'");
+        assert_eq!(adoptable.unwrap().httptestid, "2");
+
+        // Only the first step counts, the other ones may check anything
+        assert!(find_adoptable_web_scenario(&web_scenarios, "10001", "https://shop.example.com",
+                                            "Check index page 'https://shop.example.com'").is_none());
+    }
+
+    #[test]
+    fn web_scenario_with_the_same_name_should_be_adopted() {
+        let web_scenarios = [
+            get_web_scenario("1", "10001", "Check index page 'https://www.example.com'", &["http://www.example.com"])
+        ];
+
+        let adoptable = find_adoptable_web_scenario(&web_scenarios, "10001", "https://www.example.com",
+                                                    "Check index page 'https://www.example.com'");
+        assert_eq!(adoptable.unwrap().httptestid, "1");
+
+        assert!(find_adoptable_web_scenario(&web_scenarios, "10002", "https://www.example.com",
+                                            "Check index page 'https://www.example.com'").is_none());
+    }
+
+    #[test]
+    fn legacy_web_scenario_should_match_both_name_and_url() {
+        let web_scenarios = [
+            get_web_scenario("1", "10001", "Check index page 'https://www.example.com'", &["http://www.example.com"]),
+            get_web_scenario("2", "10001", "Homepage", &["https://www.example.com"]),
+            get_web_scenario("3", "10001", "Check index page 'https://www.example.com'", &["https://www.example.com"])
+        ];
+
+        let legacy = find_legacy_web_scenario(&web_scenarios, "10001", "https://www.example.com",
+                                              "Check index page 'https://www.example.com'");
+        assert_eq!(legacy.unwrap().httptestid, "3");
+
+        assert!(find_legacy_web_scenario(&web_scenarios[..2], "10001", "https://www.example.com",
+                                         "Check index page 'https://www.example.com'").is_none());
+        assert!(find_legacy_web_scenario(&web_scenarios, "10002", "https://www.example.com",
+                                         "Check index page 'https://www.example.com'").is_none());
+    }
+}
@@ -0,0 +1,170 @@
+pub mod aggregate {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    use crate::config::config::AggregateConfig;
+    use crate::errors::errors::OperationError;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::http::http::send_post_request;
+    use crate::triggers::triggers::{create_trigger, TriggerParams};
+    use crate::types::types::{EmptyResult, OperationResult};
+    use crate::zabbix::zabbix;
+    use crate::zabbix::zabbix::{get_managed_tag, log_zabbix_error, ZabbixError, ZabbixRequest, ZabbixTag};
+
+    pub const AGGREGATE_ITEM_KEY: &str = "wszl.web.fail";
+
+    const ITEM_TYPE_CALCULATED: u8 = 15;
+    const VALUE_TYPE_UNSIGNED: u8 = 3;
+
+    /// Calculated item with sum of failed steps of all web scenarios on host
+    #[derive(Serialize)]
+    pub struct AggregateItemParams {
+        pub name: String,
+        pub key_: String,
+        pub hostid: String,
+        #[serde(rename = "type")]
+        pub item_type: u8,
+        pub value_type: u8,
+        pub delay: String,
+        pub params: String,
+        pub tags: Vec<ZabbixTag>
+    }
+
+    #[derive(Serialize)]
+    struct AggregateItemSearchParams {
+        hostids: Vec<String>,
+        output: Vec<String>,
+        filter: HashMap<String, String>
+    }
+
+    #[derive(Deserialize)]
+    struct AggregateItem {
+        hostid: String
+    }
+
+    #[derive(Deserialize)]
+    struct AggregateItemSearchResponse {
+        result: Option<Vec<AggregateItem>>,
+        error: Option<ZabbixError>
+    }
+
+    #[derive(Deserialize)]
+    struct CreateItemResponse {
+        error: Option<ZabbixError>
+    }
+
+    pub fn get_aggregate_item_params(host: &ZabbixHost, delay: &str) -> AggregateItemParams {
+        AggregateItemParams {
+            name: "Failed web scenario steps".to_string(),
+            key_: AGGREGATE_ITEM_KEY.to_string(),
+            hostid: host.hostid.to_string(),
+            item_type: ITEM_TYPE_CALCULATED,
+            value_type: VALUE_TYPE_UNSIGNED,
+            delay: delay.to_string(),
+            params: format!("sum(last_foreach(/{}/web.test.fail[*]))", host.host),
+            tags: vec![get_managed_tag()]
+        }
+    }
+
+    /// Single roll-up trigger for all websites of host
+    pub fn get_aggregate_trigger_params(host: &str, aggregate_config: &AggregateConfig) -> TriggerParams {
+        TriggerParams {
+            description: format!("Some websites on '{}' are unavailable", host),
+            expression: format!("last(/{}/{})>0", host, AGGREGATE_ITEM_KEY),
+            priority: aggregate_config.priority.to_string(),
+            url: String::new(),
+            comments: String::new(),
+            tags: vec![get_managed_tag()],
+            correlation_mode: None,
+            correlation_tag: String::new()
+        }
+    }
+
+    /// Creates aggregated item and trigger on hosts which don't have them yet
+    pub fn create_missing_aggregates(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                                     aggregate_config: &AggregateConfig, delay: &str,
+                                     hosts: &[&ZabbixHost]) -> EmptyResult {
+        if hosts.is_empty() {
+            return Ok(())
+        }
+
+        let host_ids: Vec<String> = hosts.iter().map(|host| host.hostid.to_string()).collect();
+
+        let aggregated_host_ids = find_aggregated_host_ids(client, api_endpoint, auth_token, host_ids)?;
+
+        for host in hosts.iter().filter(|host| !aggregated_host_ids.contains(&host.hostid)) {
+            info!("creating aggregated availability item and trigger for host '{}'", host.host);
+
+            create_aggregate_item(client, api_endpoint, auth_token, get_aggregate_item_params(host, delay))?;
+
+            create_trigger(client, api_endpoint, auth_token, get_aggregate_trigger_params(&host.host, aggregate_config))?;
+        }
+
+        Ok(())
+    }
+
+    fn find_aggregated_host_ids(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                                host_ids: Vec<String>) -> OperationResult<Vec<String>> {
+        let mut filter = HashMap::new();
+        filter.insert("key_".to_string(), AGGREGATE_ITEM_KEY.to_string());
+
+        let params = AggregateItemSearchParams {
+            hostids: host_ids,
+            output: vec!["hostid".to_string()],
+            filter
+        };
+
+        let request: ZabbixRequest<AggregateItemSearchParams> = ZabbixRequest::new(
+            "item.get", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: AggregateItemSearchResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match search_response.result {
+                    Some(items) => Ok(items.into_iter().map(|item| item.hostid).collect()),
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        error!("unable to find aggregated items");
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find aggregated items");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn create_aggregate_item(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                             params: AggregateItemParams) -> EmptyResult {
+        let request: ZabbixRequest<AggregateItemParams> = ZabbixRequest::new(
+            "item.create", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let create_response: CreateItemResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match create_response.error {
+                    Some(_) => {
+                        log_zabbix_error(&create_response.error);
+                        error!("unable to create aggregated item");
+                        Err(OperationError::Error)
+                    }
+                    None => Ok(())
+                }
+            }
+            Err(_) => {
+                error!("unable to create aggregated item");
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
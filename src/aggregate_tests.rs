@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod aggregate_tests {
+    use crate::aggregate::aggregate::{get_aggregate_item_params, get_aggregate_trigger_params};
+    use crate::config::config::AggregateConfig;
+    use crate::hosts::hosts::ZabbixHost;
+
+    #[test]
+    fn aggregated_item_should_sum_failures_of_host_scenarios() {
+        let host = ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(),
+                                maintenance_status: "0".to_string(), status: "0".to_string(),
+                                proxy_hostid: "0".to_string() };
+
+        let params = get_aggregate_item_params(&host, "5m");
+
+        assert_eq!(params.key_, "wszl.web.fail");
+        assert_eq!(params.hostid, "10001");
+        assert_eq!(params.item_type, 15);
+        assert_eq!(params.params, "sum(last_foreach(/web-01/web.test.fail[*]))");
+        assert_eq!(params.delay, "5m");
+    }
+
+    #[test]
+    fn roll_up_trigger_should_fire_on_any_failure() {
+        let params = get_aggregate_trigger_params("web-01", &AggregateConfig { priority: 5 });
+
+        assert_eq!(params.description, "Some websites on 'web-01' are unavailable");
+        assert_eq!(params.expression, "last(/web-01/wszl.web.fail)>0");
+        assert_eq!(params.priority, "5");
+        assert_eq!(params.tags[0].value, "wszl");
+    }
+}
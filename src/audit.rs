@@ -0,0 +1,116 @@
+pub mod audit {
+    use std::collections::BTreeMap;
+    use std::fs::OpenOptions;
+    use std::io::Write;
+    use std::sync::Mutex;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use serde::Serialize;
+    use serde_json::Value;
+
+    /// Audit file of every Zabbix API endpoint, parallel runs of configs keep their own files
+    static AUDIT_FILE_PATHS: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
+    /// Zabbix API methods which change objects
+    const MUTATION_METHOD_SUFFIXES: [&str; 4] = [".create", ".update", ".delete", ".massadd"];
+
+    /// One line of the audit file
+    #[derive(Serialize)]
+    pub struct AuditRecord {
+        pub timestamp: u64,
+        pub method: String,
+        pub object: String,
+        pub ids: Vec<String>,
+        pub summary: String,
+        pub result: String
+    }
+
+    /// Mutations are recorded only after audit file of the endpoint is set.
+    /// Without `file_path` audit file set by previous config of the same endpoint is dropped.
+    pub fn init_audit_log(api_endpoint: &str, file_path: Option<&str>) {
+        let mut audit_file_paths = AUDIT_FILE_PATHS.lock().unwrap();
+
+        match file_path {
+            Some(file_path) => audit_file_paths.insert(api_endpoint.to_string(), file_path.to_string()),
+            None => audit_file_paths.remove(api_endpoint)
+        };
+    }
+
+    pub fn is_mutation(method: &str) -> bool {
+        MUTATION_METHOD_SUFFIXES.iter().any(|suffix| method.ends_with(suffix))
+    }
+
+    /// Appends record about API call to the audit file as a JSON line.
+    /// `response` is API response text or transport error.
+    pub fn record_mutation(api_endpoint: &str, method: &str, params: &Value, response: Result<&str, String>) {
+        let audit_file_paths = AUDIT_FILE_PATHS.lock().unwrap();
+
+        if let Some(audit_file_path) = audit_file_paths.get(api_endpoint) {
+            let record = get_audit_record(method, params, response);
+
+            let result = OpenOptions::new().create(true).append(true).open(audit_file_path)
+                .and_then(|mut file| writeln!(file, "{}", serde_json::to_string(&record).unwrap()));
+
+            if let Err(e) = result {
+                error!("unable to write audit record to '{}': {}", audit_file_path, e);
+            }
+        }
+    }
+
+    pub fn get_audit_record(method: &str, params: &Value, response: Result<&str, String>) -> AuditRecord {
+        let object = method.split('.').next().unwrap_or_default().to_string();
+
+        let mut ids = vec![];
+
+        let result = match response {
+            Ok(response_text) => {
+                let response: Value = serde_json::from_str(response_text).unwrap_or_default();
+
+                match response.get("error") {
+                    Some(error) => format!("error: {} {}", error["message"].as_str().unwrap_or_default(),
+                                           error["data"].as_str().unwrap_or_default()),
+                    None => {
+                        // i.e. {"httptestids": ["123"]}
+                        if let Some(result) = response["result"].as_object() {
+                            ids = result.values()
+                                .filter_map(|value| value.as_array())
+                                .flatten()
+                                .filter_map(|id| id.as_str().map(String::from))
+                                .collect();
+                        }
+
+                        "success".to_string()
+                    }
+                }
+            }
+            Err(e) => format!("error: {}", e)
+        };
+
+        AuditRecord {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+            method: method.to_string(),
+            object,
+            ids,
+            summary: get_params_summary(params),
+            result
+        }
+    }
+
+    /// Short description of created/changed object: its name and host
+    fn get_params_summary(params: &Value) -> String {
+        let mut parts = vec![];
+
+        for property in &["name", "description", "hostid", "url"] {
+            if let Some(value) = params[*property].as_str() {
+                parts.push(format!("{}='{}'", property, value));
+            }
+        }
+
+        if let Some(ids) = params.as_array() {
+            let ids: Vec<String> = ids.iter().filter_map(|id| id.as_str().map(String::from)).collect();
+            parts.push(format!("ids={}", ids.join(",")));
+        }
+
+        parts.join(" ")
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod audit_tests {
+    use std::env;
+
+    use serde_json::json;
+
+    use crate::audit::audit::{get_audit_record, init_audit_log, is_mutation, record_mutation};
+
+    #[test]
+    fn only_changing_methods_should_be_mutations() {
+        assert!(is_mutation("httptest.create"));
+        assert!(is_mutation("trigger.update"));
+        assert!(is_mutation("httptest.delete"));
+        assert!(is_mutation("hostgroup.massadd"));
+        assert!(!is_mutation("httptest.get"));
+        assert!(!is_mutation("user.login"));
+    }
+
+    #[test]
+    fn successful_mutation_should_be_recorded_with_ids() {
+        let params = json!({"name": "Check index page 'https://example.com'", "hostid": "10001", "steps": []});
+
+        let record = get_audit_record("httptest.create", &params,
+                                      Ok(r#"{"jsonrpc":"2.0","result":{"httptestids":["42"]},"id":1}"#));
+
+        assert_eq!(record.object, "httptest");
+        assert_eq!(record.ids, vec!["42"]);
+        assert_eq!(record.summary, "name='Check index page 'https://example.com'' hostid='10001'");
+        assert_eq!(record.result, "success");
+    }
+
+    #[test]
+    fn failed_mutation_should_be_recorded_with_error() {
+        let params = json!({"description": "Site 'https://example.com' is unavailable"});
+
+        let record = get_audit_record("trigger.create", &params,
+            Ok(r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"Invalid params.","data":"Trigger exists."},"id":1}"#));
+
+        assert!(record.ids.is_empty());
+        assert_eq!(record.result, "error: Invalid params. Trigger exists.");
+
+        let record = get_audit_record("trigger.create", &params, Err("connection refused".to_string()));
+
+        assert_eq!(record.result, "error: connection refused");
+    }
+
+    #[test]
+    fn config_without_audit_file_should_not_write_to_file_of_previous_config() {
+        let api_endpoint = "http://zabbix-audit/api_jsonrpc.php";
+        let file_path = env::temp_dir().join(format!("wszl-test-audit-{}.jsonl", std::process::id()));
+        let file_path = file_path.to_str().unwrap();
+
+        init_audit_log(api_endpoint, Some(file_path));
+        init_audit_log(api_endpoint, None);
+
+        record_mutation(api_endpoint, "httptest.delete", &json!(["42"]), Ok(r#"{"result":{"httptestids":["42"]}}"#));
+
+        assert!(!std::path::Path::new(file_path).exists());
+    }
+}
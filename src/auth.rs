@@ -4,8 +4,9 @@ pub mod auth {
 
     use crate::errors::errors::OperationError;
     use crate::http::http::send_post_request;
-    use crate::types::types::StringResult;
-    use crate::zabbix::zabbix::JSONRPC;
+    use crate::types::types::{EmptyResult, OperationResult, StringResult};
+    use crate::zabbix::zabbix;
+    use crate::zabbix::zabbix::{JSONRPC, log_zabbix_error, ZabbixError, ZabbixRequest};
 
     #[derive(Serialize)]
     struct AuthRequest {
@@ -27,6 +28,30 @@ pub mod auth {
         result: String
     }
 
+    #[derive(Deserialize)]
+    struct LogoutResponse {
+        result: Option<bool>,
+        error: Option<ZabbixError>
+    }
+
+    #[derive(Serialize)]
+    struct CheckSessionRequest {
+        jsonrpc: String,
+        method: String,
+        params: CheckSessionParams,
+        id: i8
+    }
+
+    #[derive(Serialize)]
+    struct CheckSessionParams {
+        sessionid: String
+    }
+
+    #[derive(Deserialize)]
+    struct CheckSessionResponse {
+        result: Option<serde_json::Value>
+    }
+
     pub fn login_to_zabbix_api(client: &reqwest::blocking::Client, api_endpoint: &str,
                                username: &str, password: &str) -> StringResult {
         let auth_request = AuthRequest {
@@ -39,12 +64,12 @@ pub mod auth {
             auth: None
         };
 
-        match send_post_request(&client, api_endpoint, auth_request) {
+        match send_post_request(client, api_endpoint, auth_request) {
             Ok(response) => {
                 match serde_json::from_str::<AuthResponse>(&response) {
                     Ok(auth_response) => {
                         debug!("auth token: {}", auth_response.result);
-                        Ok(String::from(auth_response.result))
+                        Ok(auth_response.result)
                     }
                     Err(_) => {
                         error!("unsupported auth response");
@@ -58,4 +83,78 @@ pub mod auth {
             }
         }
     }
+
+    pub fn logout_from_zabbix_api(client: &reqwest::blocking::Client, api_endpoint: &str,
+                                  auth_token: &str) -> EmptyResult {
+        let request: ZabbixRequest<Vec<String>> = ZabbixRequest::new(
+            "user.logout", vec![], auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let logout_response: LogoutResponse = serde_json::from_str(&response)
+                                                .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match logout_response.result {
+                    Some(_) => {
+                        debug!("logout success");
+                        Ok(())
+                    }
+                    None => {
+                        log_zabbix_error(&logout_response.error);
+                        error!("unable to logout");
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("logout error");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Session is still accepted by Zabbix (not expired or logged out)
+    pub fn is_session_active(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str) -> bool {
+        check_session(client, api_endpoint, auth_token).is_some()
+    }
+
+    /// User type of session: 1 - user, 2 - admin, 3 - super admin
+    pub fn get_session_user_type(client: &reqwest::blocking::Client, api_endpoint: &str,
+                                 auth_token: &str) -> OperationResult<u8> {
+        let user_type = check_session(client, api_endpoint, auth_token).and_then(|session| {
+            match &session["type"] {
+                serde_json::Value::String(user_type) => user_type.parse().ok(),
+                user_type => user_type.as_u64().map(|user_type| user_type as u8)
+            }
+        });
+
+        match user_type {
+            Some(user_type) => Ok(user_type),
+            None => {
+                error!("unable to get user type of api session");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn check_session(client: &reqwest::blocking::Client, api_endpoint: &str,
+                     auth_token: &str) -> Option<serde_json::Value> {
+        let request = CheckSessionRequest {
+            jsonrpc: JSONRPC.to_string(),
+            method: "user.checkAuthentication".to_string(),
+            params: CheckSessionParams { sessionid: auth_token.to_string() },
+            id: 1
+        };
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                match serde_json::from_str::<CheckSessionResponse>(&response) {
+                    Ok(check_response) => check_response.result,
+                    Err(_) => None
+                }
+            }
+            Err(_) => None
+        }
+    }
 }
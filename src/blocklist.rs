@@ -0,0 +1,95 @@
+pub mod blocklist {
+    use std::collections::HashSet;
+    use std::fs;
+    use std::fs::OpenOptions;
+    use std::io::Write;
+    use std::path::Path;
+
+    use regex::Regex;
+
+    use crate::types::types::{EmptyResult, OperationResult};
+
+    const COMMENT_PREFIX: &str = "#";
+    const WILDCARD: &str = "*";
+
+    /// Urls which wszl never manages: exact urls and patterns with `*` wildcards
+    pub struct Blocklist {
+        urls: HashSet<String>,
+        patterns: Vec<Regex>
+    }
+
+    impl Blocklist {
+        /// Missing file is an empty blocklist
+        pub fn load(file_path: &Path) -> OperationResult<Blocklist> {
+            if !file_path.exists() {
+                return Ok(Blocklist::parse(""))
+            }
+
+            let blocklist = Blocklist::parse(&fs::read_to_string(file_path)?);
+
+            info!("blocked urls loaded from file '{}': {}", file_path.display(),
+                  blocklist.urls.len() + blocklist.patterns.len());
+
+            Ok(blocklist)
+        }
+
+        pub fn parse(content: &str) -> Blocklist {
+            let mut urls = HashSet::new();
+            let mut patterns = vec![];
+
+            for line in content.lines().map(|line| line.trim()) {
+                if line.is_empty() || line.starts_with(COMMENT_PREFIX) {
+                    continue;
+                }
+
+                if line.contains(WILDCARD) {
+                    patterns.push(get_wildcard_regex(line));
+
+                } else {
+                    urls.insert(line.to_string());
+                }
+            }
+
+            Blocklist { urls, patterns }
+        }
+
+        pub fn is_blocked(&self, url: &str) -> bool {
+            if self.urls.contains(url) {
+                debug!("url '{}' is in blocklist, skip", url);
+                return true
+            }
+
+            match self.patterns.iter().find(|pattern| pattern.is_match(url)) {
+                Some(pattern) => {
+                    debug!("url '{}' matches blocklist pattern '{}', skip", url, pattern.as_str());
+                    true
+                }
+                None => false
+            }
+        }
+    }
+
+    fn get_wildcard_regex(pattern: &str) -> Regex {
+        let parts: Vec<String> = pattern.split(WILDCARD).map(regex::escape).collect();
+        Regex::new(&format!("^{}$", parts.join(".*"))).expect("escaped wildcard pattern is a valid regex")
+    }
+
+    /// Appends urls which aren't blocked yet
+    pub fn append_to_blocklist(file_path: &Path, urls: &[String]) -> EmptyResult {
+        let blocklist = Blocklist::load(file_path)?;
+
+        let mut file = OpenOptions::new().create(true).append(true).open(file_path)?;
+
+        for url in urls {
+            if blocklist.is_blocked(url) {
+                info!("url '{}' is already in blocklist", url);
+
+            } else {
+                writeln!(file, "{}", url)?;
+                info!("url '{}' has been added to blocklist '{}'", url, file_path.display());
+            }
+        }
+
+        Ok(())
+    }
+}
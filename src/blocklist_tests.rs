@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod blocklist_tests {
+    use std::env;
+    use std::fs;
+
+    use crate::blocklist::blocklist::{append_to_blocklist, Blocklist};
+
+    #[test]
+    fn exact_urls_and_wildcard_patterns_should_be_blocked() {
+        let blocklist = Blocklist::parse(
+            "# removed on purpose\nhttps://old.example.com\n\nhttps://*.staging.example.com/*\n"
+        );
+
+        assert!(blocklist.is_blocked("https://old.example.com"));
+        assert!(!blocklist.is_blocked("https://old.example.com/healthz"));
+        assert!(blocklist.is_blocked("https://shop.staging.example.com/healthz"));
+        assert!(!blocklist.is_blocked("https://shop.example.com/healthz"));
+        assert!(!blocklist.is_blocked("# removed on purpose"));
+    }
+
+    #[test]
+    fn appended_urls_should_be_blocked_once() {
+        let file_path = env::temp_dir().join(format!("wszl-test-blocklist-{}.txt", std::process::id()));
+
+        append_to_blocklist(&file_path, &["https://a.example.com".to_string()]).unwrap();
+        append_to_blocklist(&file_path, &["https://a.example.com".to_string(),
+                                          "https://b.example.com".to_string()]).unwrap();
+
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), "https://a.example.com\nhttps://b.example.com\n");
+        assert!(Blocklist::load(&file_path).unwrap().is_blocked("https://b.example.com"));
+
+        fs::remove_file(&file_path).unwrap();
+    }
+}
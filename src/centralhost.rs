@@ -0,0 +1,38 @@
+pub mod centralhost {
+    use regex::Regex;
+
+    use crate::config::config::CentralHostConfig;
+    use crate::errors::errors::OperationError;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::types::types::OperationResult;
+
+    /// Host which checks of matching urls are created on instead of the host of item
+    pub struct CentralHost {
+        pub host: ZabbixHost,
+        patterns: Vec<Regex>
+    }
+
+    impl CentralHost {
+        pub fn new(central_host_config: &CentralHostConfig, host: ZabbixHost) -> OperationResult<CentralHost> {
+            let mut patterns = vec![];
+
+            for pattern in &central_host_config.patterns {
+                match Regex::new(pattern) {
+                    Ok(regex) => patterns.push(regex),
+                    Err(e) => {
+                        error!("invalid central-host pattern '{}': {}", pattern, e);
+                        return Err(OperationError::Error)
+                    }
+                }
+            }
+
+            Ok(CentralHost { host, patterns })
+        }
+
+        /// All urls are routed when there are no patterns. Urls of the central host itself stay there.
+        pub fn is_routed(&self, url: &str, origin_hostid: &str) -> bool {
+            origin_hostid != self.host.hostid &&
+                (self.patterns.is_empty() || self.patterns.iter().any(|pattern| pattern.is_match(url)))
+        }
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod centralhost_tests {
+    use std::collections::HashMap;
+
+    use crate::centralhost::centralhost::CentralHost;
+    use crate::config::config::{CentralHostConfig, TemplatesConfig};
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::templates::templates::NameTemplates;
+
+    fn get_host(hostid: &str, host: &str) -> ZabbixHost {
+        ZabbixHost { hostid: hostid.to_string(), host: host.to_string(), maintenance_status: "0".to_string(),
+                     status: "0".to_string(), proxy_hostid: "0".to_string() }
+    }
+
+    #[test]
+    fn only_matching_urls_of_other_hosts_should_be_routed() {
+        let central_host = CentralHost::new(&CentralHostConfig {
+            host: "WebChecks".to_string(),
+            patterns: vec!["^https://shop\\.".to_string()]
+        }, get_host("10100", "WebChecks")).unwrap();
+
+        assert!(central_host.is_routed("https://shop.example.com", "10001"));
+        assert!(!central_host.is_routed("https://www.example.com", "10001"));
+        assert!(!central_host.is_routed("https://shop.example.com", "10100"));
+
+        let central_host = CentralHost::new(&CentralHostConfig { host: "WebChecks".to_string(), patterns: vec![] },
+                                            get_host("10100", "WebChecks")).unwrap();
+
+        assert!(central_host.is_routed("https://www.example.com", "10001"));
+    }
+
+    #[test]
+    fn invalid_pattern_should_be_rejected() {
+        let central_host_config = CentralHostConfig { host: "WebChecks".to_string(), patterns: vec!["(".to_string()] };
+
+        assert!(CentralHost::new(&central_host_config, get_host("10100", "WebChecks")).is_err());
+    }
+
+    #[test]
+    fn scenario_name_should_contain_origin_host() {
+        let templates_config = TemplatesConfig {
+            trigger_comments: "Item of {{ origin_host.host }}".to_string(),
+            ..TemplatesConfig::default()
+        };
+        let templates = NameTemplates::new(&templates_config).unwrap();
+
+        let names = templates.get_central_object_names(&get_host("10100", "WebChecks"), &get_host("10001", "web-01"),
+                                                       "https://shop.example.com", &HashMap::new(), None).unwrap();
+
+        assert_eq!(names.scenario, "Check index page 'https://shop.example.com' (web-01)");
+        assert_eq!(names.trigger_expression,
+                   "last(/WebChecks/web.test.fail[Check index page 'https://shop.example.com' (web-01)])<>0");
+        assert_eq!(names.trigger_comments, "Item of web-01");
+    }
+}
@@ -0,0 +1,68 @@
+pub mod circuit {
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    /// Run has been aborted by the circuit breaker
+    pub const ABORTED_EXIT_CODE: i32 = 3;
+
+    /// Breaker of every Zabbix API endpoint, parallel runs against different servers fail independently
+    static API_CIRCUIT_BREAKERS: Mutex<BTreeMap<String, CircuitBreaker>> = Mutex::new(BTreeMap::new());
+
+    /// Opens after `max_consecutive_failures` failed calls in a row, 0 disables it.
+    /// Open breaker stays open until reset, so the rest of the run fails fast.
+    pub struct CircuitBreaker {
+        max_consecutive_failures: usize,
+        consecutive_failures: usize,
+        open: bool
+    }
+
+    impl CircuitBreaker {
+        pub const fn new(max_consecutive_failures: usize) -> CircuitBreaker {
+            CircuitBreaker { max_consecutive_failures, consecutive_failures: 0, open: false }
+        }
+
+        /// Returns true when this call has opened the breaker
+        pub fn record(&mut self, success: bool) -> bool {
+            if success {
+                self.consecutive_failures = 0;
+                return false
+            }
+
+            self.consecutive_failures += 1;
+
+            if !self.open && self.max_consecutive_failures > 0 &&
+                self.consecutive_failures >= self.max_consecutive_failures {
+                self.open = true;
+                return true
+            }
+
+            false
+        }
+
+        pub fn is_open(&self) -> bool {
+            self.open
+        }
+    }
+
+    /// Breaker is per run, daemon mode resets it before every run
+    pub fn reset_api_circuit_breaker(api_endpoint: &str, max_consecutive_failures: usize) {
+        API_CIRCUIT_BREAKERS.lock().unwrap().insert(api_endpoint.to_string(),
+                                                    CircuitBreaker::new(max_consecutive_failures));
+    }
+
+    /// Calls of urls without breaker (i.e. outside of `gen` run) aren't counted
+    pub fn record_api_call_result(api_endpoint: &str, success: bool) {
+        let mut circuit_breakers = API_CIRCUIT_BREAKERS.lock().unwrap();
+
+        if let Some(circuit_breaker) = circuit_breakers.get_mut(api_endpoint) {
+            if circuit_breaker.record(success) {
+                error!("{} consecutive api calls to '{}' have failed, the rest of the run is aborted",
+                       circuit_breaker.max_consecutive_failures, api_endpoint);
+            }
+        }
+    }
+
+    pub fn is_api_circuit_open(api_endpoint: &str) -> bool {
+        API_CIRCUIT_BREAKERS.lock().unwrap().get(api_endpoint).map(CircuitBreaker::is_open).unwrap_or(false)
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod circuit_tests {
+    use crate::circuit::circuit::{CircuitBreaker, is_api_circuit_open, record_api_call_result,
+                                  reset_api_circuit_breaker};
+
+    #[test]
+    fn breaker_should_open_after_consecutive_failures() {
+        let mut breaker = CircuitBreaker::new(3);
+
+        assert!(!breaker.record(false));
+        assert!(!breaker.record(false));
+        assert!(breaker.record(false));
+        assert!(breaker.is_open());
+
+        assert!(!breaker.record(false));
+        assert!(!breaker.record(true));
+        assert!(breaker.is_open());
+    }
+
+    #[test]
+    fn success_should_reset_failures() {
+        let mut breaker = CircuitBreaker::new(2);
+
+        breaker.record(false);
+        breaker.record(true);
+        breaker.record(false);
+
+        assert!(!breaker.is_open());
+    }
+
+    #[test]
+    fn zero_limit_should_disable_breaker() {
+        let mut breaker = CircuitBreaker::new(0);
+
+        for _ in 0..100 {
+            breaker.record(false);
+        }
+
+        assert!(!breaker.is_open());
+    }
+
+    #[test]
+    fn breakers_of_endpoints_should_be_independent() {
+        reset_api_circuit_breaker("http://zabbix-eu/api_jsonrpc.php", 1);
+        reset_api_circuit_breaker("http://zabbix-us/api_jsonrpc.php", 1);
+
+        record_api_call_result("http://zabbix-eu/api_jsonrpc.php", false);
+
+        assert!(is_api_circuit_open("http://zabbix-eu/api_jsonrpc.php"));
+        assert!(!is_api_circuit_open("http://zabbix-us/api_jsonrpc.php"));
+        assert!(!is_api_circuit_open("http://pushgateway:9091"));
+    }
+}
@@ -0,0 +1,125 @@
+pub mod clean {
+    use std::time::Duration;
+
+    use reqwest::blocking::Client;
+
+    use crate::config::config::Config;
+    use crate::errors::errors::OperationError;
+    use crate::hooks::hooks::{ACTION_DELETE, get_hook_env, HookEvent, RESULT_FAILED, RESULT_SUCCESS, run_hook};
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::session::session::{close_api_session, open_api_session};
+    use crate::types::types::OperationResult;
+    use crate::webscenarios::webscenarios::{delete_web_scenario, find_managed_web_scenarios,
+                                            ZabbixManagedWebScenario};
+
+    /// Managed web scenario of url on host
+    pub struct CheckToDelete<'a> {
+        pub httptestid: &'a str,
+        pub host: &'a ZabbixHost,
+        pub url: String
+    }
+
+    /// Deletes managed checks of urls (on `host` only, when set), returns number of deleted checks.
+    /// Any failed deletion is an error after all checks have been tried.
+    pub fn clean_managed_checks(client: &Client, config: &Config, urls: &[String],
+                                host: Option<&str>) -> OperationResult<usize> {
+        let api_endpoint = &config.zabbix.api.endpoint;
+
+        match open_api_session(client, &config.zabbix.api) {
+            Ok(auth_token) => {
+                let result = find_managed_web_scenarios(client, api_endpoint, &auth_token)
+                    .and_then(|web_scenarios| {
+                        let checks = get_checks_to_delete(&web_scenarios, urls, host);
+
+                        if checks.is_empty() {
+                            info!("no managed checks found for given urls");
+                        }
+
+                        let mut deleted = 0;
+                        let mut failed = false;
+
+                        for check in &checks {
+                            if delete_check(client, config, &auth_token, check) {
+                                deleted += 1;
+                            } else {
+                                failed = true;
+                            }
+                        }
+
+                        if failed { Err(OperationError::Error) } else { Ok(deleted) }
+                    });
+
+                if close_api_session(client, &config.zabbix.api, &auth_token).is_err() {
+                    warn!("unable to close api session");
+                }
+
+                result
+            }
+            Err(_) => {
+                error!("unable to login");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    pub fn get_checks_to_delete<'a>(web_scenarios: &'a [ZabbixManagedWebScenario], urls: &[String],
+                                    host: Option<&str>) -> Vec<CheckToDelete<'a>> {
+        let mut checks = vec![];
+
+        for web_scenario in web_scenarios {
+            let url = web_scenario.steps.first().map(|step| step.url.to_string()).unwrap_or_default();
+
+            if !urls.contains(&url) {
+                continue;
+            }
+
+            for scenario_host in &web_scenario.hosts {
+                if host.map(|host| host == scenario_host.host).unwrap_or(true) {
+                    checks.push(CheckToDelete {
+                        httptestid: &web_scenario.httptestid,
+                        host: scenario_host,
+                        url: url.to_string()
+                    });
+                }
+            }
+        }
+
+        checks
+    }
+
+    fn delete_check(client: &Client, config: &Config, auth_token: &str, check: &CheckToDelete) -> bool {
+        let hooks_config = &config.hooks;
+        let hook_timeout = Duration::from_secs(hooks_config.timeout_secs);
+        let host = &check.host.host;
+        let url = &check.url;
+
+        if let Some(pre_delete_command) = &hooks_config.pre_delete {
+            let event = HookEvent { action: ACTION_DELETE, host, url, result: "" };
+
+            if run_hook(pre_delete_command, &get_hook_env(&event), hook_timeout).is_err() {
+                error!("pre-delete hook has failed for url '{}' of host '{}'", url, host);
+                return false
+            }
+        }
+
+        let result = delete_web_scenario(client, &config.zabbix.api.endpoint, auth_token, check.httptestid);
+
+        match result {
+            Ok(_) => info!("check of url '{}' has been deleted from host '{}'", url, host),
+            Err(_) => error!("unable to delete check of url '{}' from host '{}'", url, host)
+        }
+
+        if let Some(post_delete_command) = &hooks_config.post_delete {
+            let event = HookEvent {
+                action: ACTION_DELETE, host, url,
+                result: if result.is_ok() { RESULT_SUCCESS } else { RESULT_FAILED }
+            };
+
+            if run_hook(post_delete_command, &get_hook_env(&event), hook_timeout).is_err() {
+                error!("post-delete hook has failed for url '{}'", url);
+            }
+        }
+
+        result.is_ok()
+    }
+}
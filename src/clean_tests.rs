@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod clean_tests {
+    use crate::clean::clean::get_checks_to_delete;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::webscenarios::webscenarios::{ZabbixManagedWebScenario, ZabbixWebScenarioStep};
+
+    fn get_web_scenario(httptestid: &str, url: &str, host: &str) -> ZabbixManagedWebScenario {
+        ZabbixManagedWebScenario {
+            httptestid: httptestid.to_string(),
+            name: format!("Check index page '{}'", url),
+            status: "0".to_string(),
+            hosts: vec![ZabbixHost { hostid: "10001".to_string(), host: host.to_string(),
+                                     maintenance_status: "0".to_string(), status: "0".to_string(),
+                                     proxy_hostid: "0".to_string() }],
+            steps: vec![ZabbixWebScenarioStep { url: url.to_string() }]
+        }
+    }
+
+    #[test]
+    fn checks_should_be_selected_by_url_and_host() {
+        let web_scenarios = vec![
+            get_web_scenario("301", "https://a.example.com", "web-01"),
+            get_web_scenario("302", "https://a.example.com", "web-02"),
+            get_web_scenario("303", "https://b.example.com", "web-01")
+        ];
+
+        let urls = vec!["https://a.example.com".to_string()];
+
+        let checks = get_checks_to_delete(&web_scenarios, &urls, None);
+        assert_eq!(checks.iter().map(|check| check.httptestid).collect::<Vec<&str>>(), vec!["301", "302"]);
+
+        let checks = get_checks_to_delete(&web_scenarios, &urls, Some("web-02"));
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].httptestid, "302");
+        assert_eq!(checks[0].host.host, "web-02");
+        assert_eq!(checks[0].url, "https://a.example.com");
+    }
+}
@@ -1,32 +1,600 @@
 pub mod config {
+    use std::collections::HashMap;
+    use std::env;
     use std::fs;
-    use std::path::Path;
+    use std::io;
+    use std::path::{Path, PathBuf};
 
-    use yaml_rust::YamlLoader;
+    use yaml_rust::{Yaml, YamlLoader};
 
     use crate::errors::errors::OperationError;
     use crate::types::types::OperationResult;
+    use crate::urlcheck::urlcheck::get_server_address;
 
     pub struct Config {
-        pub zabbix: ZabbixConfig
+        pub zabbix: ZabbixConfig,
+        pub metrics: MetricsConfig,
+        pub tracing: TracingConfig,
+        pub processing: ProcessingConfig,
+        pub items: ItemsConfig,
+        pub discovery: DiscoveryConfig,
+        pub urls: UrlsConfig,
+        pub notifications: NotificationsConfig,
+        pub audit: AuditConfig,
+        pub probe: Option<ProbeConfig>,
+        pub dashboard: Option<DashboardConfig>,
+        pub services: Option<ServicesConfig>,
+        pub aggregate: Option<AggregateConfig>,
+        /// Trigger on slow response of every url
+        pub latency: Option<LatencyConfig>,
+        /// Proxy which web scenarios use for requests, per url
+        pub http_proxy: Option<HttpProxyConfig>,
+        /// Checks of urls are created on this host instead of the host of item
+        pub central_host: Option<CentralHostConfig>,
+        /// Maintenance of hosts with newly created checks
+        pub grace: Option<GraceConfig>,
+        pub hooks: HooksConfig,
+        pub templates: TemplatesConfig,
+        /// Rhai script with overrides for urls
+        pub script_file: Option<String>,
+        /// Zabbix API credentials from Vault
+        pub vault: Option<VaultConfig>,
+        pub daemon: DaemonConfig,
+        /// Logs are also sent to syslog
+        pub syslog: Option<SyslogConfig>
+    }
+
+    pub struct SyslogConfig {
+        /// `udp://host:514`, `tcp://host:601` or `unix:///dev/log`
+        pub address: String,
+        pub facility: String,
+        pub app_name: String,
+        pub level: String
+    }
+
+    /// Schedule of `gen` runs for `daemon` command
+    pub struct DaemonConfig {
+        pub schedule: DaemonSchedule,
+        /// Random delay up to this value is added to every run
+        pub jitter_secs: u64
+    }
+
+    #[derive(PartialEq, Debug)]
+    pub enum DaemonSchedule {
+        /// Seconds between the end of a run and the start of the next one
+        Interval(u64),
+        /// Cron expression, i.e. `*/10 * * * *`
+        Cron(String)
     }
 
     pub struct ZabbixConfig {
         pub api: ZabbixApiConfig,
-        pub scenario: WebScenarioConfig
+        pub scenario: WebScenarioConfig,
+        /// Scenario settings of hosts monitored by proxy, unset values are taken from `scenario`
+        pub proxy_scenario: Option<WebScenarioConfig>,
+        /// Hosts with managed web scenarios are added to this group
+        pub host_group: Option<String>,
+        /// Graph of response time and download speed for every created scenario
+        pub graph: Option<GraphConfig>,
+        /// Triggers get this tag with url as value and close only problems with the same value
+        pub correlation_tag: Option<String>
     }
 
     pub struct ZabbixApiConfig {
         pub endpoint: String,
         pub username: String,
-        pub password: String
+        /// Empty when not configured, asked on terminal then
+        pub password: String,
+        /// Read/save password with OS keyring
+        pub keyring: bool,
+        /// Session token is kept in this file between runs
+        pub session_file: Option<String>
+    }
+
+    /// Secret in Vault KV v2 with Zabbix API credentials
+    pub struct VaultConfig {
+        pub address: String,
+        /// KV v2 secrets engine mount
+        pub mount: String,
+        pub path: String,
+        /// `VAULT_TOKEN` environment variable is used when neither token nor AppRole is set
+        pub token: Option<String>,
+        pub approle: Option<VaultAppRoleConfig>,
+        pub username_key: String,
+        pub password_key: String
+    }
+
+    pub struct VaultAppRoleConfig {
+        pub role_id: String,
+        pub secret_id: String
     }
 
     pub struct WebScenarioConfig {
+        /// Timeout of every step, i.e. `15s`
         pub response_timeout: String,
         pub expected_status_code: String,
         pub attempts: u8,
-        pub update_interval: String
+        pub update_interval: String,
+        pub retrieve_mode: RetrieveMode,
+        /// Scenario variables, i.e. credentials used by steps of url script
+        pub variables: Vec<ScenarioVariableConfig>
+    }
+
+    #[derive(Clone)]
+    pub struct ScenarioVariableConfig {
+        pub name: String,
+        pub value: String
+    }
+
+    /// Which part of the response web scenario steps download
+    #[derive(PartialEq, Debug, Clone, Copy)]
+    pub enum RetrieveMode {
+        Body,
+        /// Headers only, for checks which don't need the page content
+        Headers,
+        Both
+    }
+
+    pub struct GraphConfig {
+        /// Name template, `{host}` and `{url}` are replaced
+        pub name: String,
+        pub width: u32,
+        pub height: u32
+    }
+
+    /// Dashboard with web monitoring widgets, updated after every run
+    pub struct DashboardConfig {
+        pub name: String
+    }
+
+    /// Zabbix services linked to created triggers
+    pub struct ServicesConfig {
+        /// Name of the top level service
+        pub root: String,
+        pub group_by: ServiceGroupBy,
+        /// SLA target, percent
+        pub sla: f64
+    }
+
+    /// Parent service of url service
+    #[derive(PartialEq, Debug)]
+    pub enum ServiceGroupBy {
+        Host,
+        Domain
+    }
+
+    /// Calculated item per host with failures of all its web scenarios and a roll-up trigger
+    pub struct AggregateConfig {
+        /// Severity of roll-up trigger
+        pub priority: u8
+    }
+
+    /// One-time maintenance which suppresses problems of managed triggers on hosts with just created checks
+    pub struct GraceConfig {
+        pub duration_secs: u64,
+        /// Name prefix of maintenance, time of creation is appended
+        pub name: String
+    }
+
+    /// Response time trigger per url, threshold is taken from the first rule matching url
+    pub struct LatencyConfig {
+        pub threshold_ms: u64,
+        pub priority: u8,
+        pub rules: Vec<LatencyRuleConfig>
+    }
+
+    pub struct LatencyRuleConfig {
+        /// Regular expression for url
+        pub pattern: String,
+        pub threshold_ms: u64
+    }
+
+    /// Proxy of web scenarios, taken from the first rule matching url
+    pub struct HttpProxyConfig {
+        /// Proxy of urls matching no rule, empty for direct requests
+        pub proxy: String,
+        pub rules: Vec<HttpProxyRuleConfig>
+    }
+
+    /// Host which probes urls from a central vantage point, i.e. host monitored by proxy
+    pub struct CentralHostConfig {
+        /// Technical name of the host
+        pub host: String,
+        /// Regular expressions, urls matching any of them are checked from the central host, all urls when empty
+        pub patterns: Vec<String>
+    }
+
+    pub struct HttpProxyRuleConfig {
+        /// Regular expression for url
+        pub pattern: String,
+        /// Empty for direct requests
+        pub proxy: String
+    }
+
+    /// Shell commands executed around object creation and at the end of run
+    pub struct HooksConfig {
+        /// Non-zero exit code cancels creation of url objects
+        pub pre_create: Option<String>,
+        pub post_create: Option<String>,
+        /// Non-zero exit code cancels deletion of the check (`wszl clean`)
+        pub pre_delete: Option<String>,
+        pub post_delete: Option<String>,
+        pub run_end: Option<String>,
+        /// Hook command is killed after this time and considered failed
+        pub timeout_secs: u64
+    }
+
+    /// Tera templates of names and definitions of created objects
+    pub struct TemplatesConfig {
+        pub scenario_name: String,
+        pub step_name: String,
+        pub trigger_name: String,
+        pub trigger_expression: String,
+        pub trigger_comments: String,
+        /// Custom variables, available as `vars.<name>`
+        pub vars: HashMap<String, String>,
+        /// Longer names of web scenarios and steps are shortened, Zabbix allows 64 characters
+        pub max_name_length: usize
+    }
+
+    impl Default for TemplatesConfig {
+        fn default() -> Self {
+            TemplatesConfig {
+                scenario_name: SCENARIO_NAME_TEMPLATE_DEFAULT_VALUE.to_string(),
+                step_name: STEP_NAME_TEMPLATE_DEFAULT_VALUE.to_string(),
+                trigger_name: TRIGGER_NAME_TEMPLATE_DEFAULT_VALUE.to_string(),
+                trigger_expression: TRIGGER_EXPRESSION_TEMPLATE_DEFAULT_VALUE.to_string(),
+                trigger_comments: String::new(),
+                vars: HashMap::new(),
+                max_name_length: NAME_MAX_LENGTH_DEFAULT_VALUE
+            }
+        }
+    }
+
+    pub struct MetricsConfig {
+        pub textfile_path: Option<String>,
+        pub pushgateway_url: Option<String>,
+        pub job: String
+    }
+
+    pub struct TracingConfig {
+        pub otlp_endpoint: Option<String>,
+        pub service_name: String
+    }
+
+    /// Direct request to url before web scenario creation
+    pub struct ProbeConfig {
+        /// Unreachable urls are skipped, otherwise only flagged in the report
+        pub skip_unreachable: bool,
+        pub timeout_secs: u64
+    }
+
+    pub struct AuditConfig {
+        /// Append-only file with every create/update/delete made in Zabbix
+        pub file_path: Option<String>
+    }
+
+    pub struct NotificationsConfig {
+        pub webhook_url: Option<String>,
+        pub slack: Option<ChatWebhookConfig>,
+        pub mattermost: Option<ChatWebhookConfig>,
+        pub telegram: Option<TelegramConfig>,
+        pub email: Option<EmailConfig>,
+        pub trapper: Option<TrapperConfig>
+    }
+
+    /// Trapper item which receives outcome of every run
+    pub struct TrapperConfig {
+        /// Zabbix server or proxy, `host:port`
+        pub server: String,
+        pub host: String,
+        pub key: String
+    }
+
+    /// Incoming webhook of Slack or Mattermost
+    pub struct ChatWebhookConfig {
+        pub webhook_url: String,
+        pub notify_on: NotifyOn
+    }
+
+    pub struct TelegramConfig {
+        pub bot_token: String,
+        pub chat_id: String,
+        pub notify_on: NotifyOn
+    }
+
+    pub struct EmailConfig {
+        pub smtp_host: String,
+        pub smtp_port: u16,
+        pub security: SmtpSecurity,
+        pub username: Option<String>,
+        pub password: Option<String>,
+        pub from: String,
+        pub to: Vec<String>,
+        pub notify_on: NotifyOn
+    }
+
+    #[derive(PartialEq, Debug)]
+    pub enum SmtpSecurity {
+        StartTls,
+        Tls,
+        None
+    }
+
+    /// Which runs are reported to a chat or by email
+    #[derive(PartialEq, Debug)]
+    pub enum NotifyOn {
+        /// Every run
+        Always,
+        /// Runs which created objects or had failures
+        Changes,
+        /// Runs with failures
+        Errors
+    }
+
+    pub struct ProcessingConfig {
+        pub hosts_per_chunk: usize,
+        pub concurrency: usize,
+        /// Urls of hosts in maintenance are deferred until the maintenance is over
+        pub skip_maintenance: bool,
+        /// Disabled items and not monitored hosts are ignored
+        pub skip_disabled: bool,
+        /// Only one `gen` run at a time holds the lock on this file
+        pub lock_file: String,
+        /// Failed items and the last successful run, see `gen --retry-failed` and `gen --full`
+        pub state_file: String,
+        /// Web scenario of disappeared url is renamed to the only new url of the host (keeps history)
+        pub detect_renames: bool,
+        /// Run is aborted after this number of failed API calls in a row, 0 - never
+        pub max_consecutive_failures: usize,
+        /// Run is aborted after more failed items than this, 0 - never
+        pub max_errors: usize,
+        /// Managed scenarios per host, 0 - unlimited
+        pub max_scenarios_per_host: usize,
+        /// New scenarios per run, 0 - unlimited
+        pub max_scenarios_per_run: usize
+    }
+
+    pub struct ItemsConfig {
+        pub patterns: Vec<ItemKeyPatternConfig>,
+        /// Items in 'not supported' state are skipped
+        pub skip_unsupported: bool,
+        /// Items which haven't been updated for this time are skipped
+        pub stale_after_secs: Option<u64>,
+        /// Only items with all of these tags are processed (tags of the same name - any of them)
+        pub tags: Vec<ItemTagConfig>
+    }
+
+    /// `tag:value` or just `tag` (any value)
+    #[derive(Debug, PartialEq)]
+    pub struct ItemTagConfig {
+        pub tag: String,
+        pub value: Option<String>
+    }
+
+    pub enum ItemKeyPatternConfig {
+        /// Item key mask, `*` captures url. For example `vhost.item[*]`
+        Mask(String),
+        /// Regular expression with capture group for url and search string for item.get
+        Regex { regex: String, search: String }
+    }
+
+    pub struct DiscoveryConfig {
+        /// Take urls from low level discovery data instead of item keys
+        pub lld: Option<LldDiscoveryConfig>,
+        /// File with urls mapped to hosts, see `urlsfile` module for format
+        pub urls_file: Option<String>,
+        pub docker: Option<DockerDiscoveryConfig>,
+        pub vhosts: Option<VhostsDiscoveryConfig>
+    }
+
+    /// Virtual hosts from nginx/Apache configs on this machine
+    pub struct VhostsDiscoveryConfig {
+        /// Zabbix host for all discovered urls, local host name by default
+        pub host: String,
+        pub nginx_dirs: Vec<String>,
+        pub apache_dirs: Vec<String>
+    }
+
+    /// Urls from Traefik labels of Docker containers
+    pub struct DockerDiscoveryConfig {
+        pub socket: String,
+        /// Zabbix host for all discovered urls
+        pub host: String,
+        /// Scheme for routers without tls
+        pub scheme: String
+    }
+
+    const DOCKER_SOCKET_DEFAULT_VALUE: &str = "/var/run/docker.sock";
+
+    const DOCKER_SCHEME_DEFAULT_VALUE: &str = "http";
+
+    pub struct LldDiscoveryConfig {
+        /// Key of item which holds discovery JSON in its last value
+        pub item_key: String,
+        pub url_macro: String
+    }
+
+    const LLD_URL_MACRO_DEFAULT_VALUE: &str = "{#URL}";
+
+    /// Processing of discovered urls
+    pub struct UrlsConfig {
+        pub rewrites: Vec<UrlRewriteConfig>,
+        pub force_scheme: Option<String>,
+        pub append_path: Option<String>,
+        /// Captured urls should match any of these patterns (if set)
+        pub include: Vec<String>,
+        /// Captured urls matching any of these patterns are skipped
+        pub exclude: Vec<String>,
+        pub dedup_scope: DedupScope,
+        /// Urls which are never managed, `wszl clean --block` appends to it
+        pub blocklist_file: String
+    }
+
+    /// Where identical urls are considered duplicates
+    #[derive(PartialEq, Debug)]
+    pub enum DedupScope {
+        /// Same url on the same host
+        Host,
+        /// Same url on any host
+        Global
+    }
+
+    pub struct UrlRewriteConfig {
+        pub search: String,
+        pub replace: String
+    }
+
+    pub const ITEM_KEY_PATTERN_DEFAULT_VALUE: &str = "vhost.item[*]";
+
+    const KEYRING_DEFAULT_VALUE: bool = false;
+
+    pub const LOCK_FILE_DEFAULT_VALUE: &str = "wszl.lock";
+
+    pub const STATE_FILE_DEFAULT_VALUE: &str = "wszl-state.json";
+
+    const DETECT_RENAMES_DEFAULT_VALUE: bool = false;
+
+    const MAX_CONSECUTIVE_FAILURES_DEFAULT_VALUE: i64 = 20;
+
+    const BLOCKLIST_FILE_DEFAULT_VALUE: &str = "wszl-blocklist.txt";
+
+    const DAEMON_INTERVAL_DEFAULT_VALUE: u64 = 1800;
+
+    const SYSLOG_ADDRESS_DEFAULT_VALUE: &str = "unix:///dev/log";
+
+    const SYSLOG_FACILITY_DEFAULT_VALUE: &str = "daemon";
+
+    const SYSLOG_APP_NAME_DEFAULT_VALUE: &str = "wszl";
+
+    const SYSLOG_LEVEL_DEFAULT_VALUE: &str = "info";
+
+    const VAULT_MOUNT_DEFAULT_VALUE: &str = "secret";
+
+    const VAULT_USERNAME_KEY_DEFAULT_VALUE: &str = "username";
+
+    const VAULT_PASSWORD_KEY_DEFAULT_VALUE: &str = "password";
+
+    const HOSTS_PER_CHUNK_DEFAULT_VALUE: i64 = 100;
+
+    const CONCURRENCY_DEFAULT_VALUE: i64 = 1;
+
+    const SKIP_MAINTENANCE_DEFAULT_VALUE: bool = true;
+
+    const CONFIG_FILE_NAME: &str = "wszl.yml";
+    const SYSTEM_CONFIG_DIR: &str = "/etc/wszl";
+
+    const SKIP_DISABLED_DEFAULT_VALUE: bool = true;
+
+    const SKIP_UNSUPPORTED_ITEMS_DEFAULT_VALUE: bool = true;
+
+    const METRICS_JOB_DEFAULT_VALUE: &str = "wszl";
+
+    const TRACING_SERVICE_NAME_DEFAULT_VALUE: &str = "wszl";
+
+    const SMTP_STARTTLS_PORT_DEFAULT_VALUE: i64 = 587;
+    const SMTP_TLS_PORT_DEFAULT_VALUE: i64 = 465;
+    const SMTP_PORT_DEFAULT_VALUE: i64 = 25;
+
+    const GRAPH_NAME_DEFAULT_VALUE: &str = "Web performance '{url}'";
+    const GRAPH_WIDTH_DEFAULT_VALUE: i64 = 900;
+    const GRAPH_HEIGHT_DEFAULT_VALUE: i64 = 200;
+
+    const DASHBOARD_NAME_DEFAULT_VALUE: &str = "Web monitoring";
+
+    const SERVICES_ROOT_DEFAULT_VALUE: &str = "Websites";
+    const SERVICES_SLA_DEFAULT_VALUE: f64 = 99.9;
+
+    const AGGREGATE_PRIORITY_DEFAULT_VALUE: i64 = 4;
+
+    const HOOK_TIMEOUT_DEFAULT_VALUE: u64 = 60;
+
+    const GRACE_DURATION_DEFAULT_VALUE: u64 = 3600;
+    const GRACE_NAME_DEFAULT_VALUE: &str = "WSZL grace period";
+
+    const LATENCY_THRESHOLD_DEFAULT_VALUE: u64 = 5000;
+    const LATENCY_PRIORITY_DEFAULT_VALUE: i64 = 2;
+
+    const SCENARIO_NAME_TEMPLATE_DEFAULT_VALUE: &str = "Check index page '{{ url }}'";
+    const STEP_NAME_TEMPLATE_DEFAULT_VALUE: &str = "Get page";
+    const NAME_MAX_LENGTH_DEFAULT_VALUE: usize = NAME_MAX_LENGTH_MAX_VALUE;
+    /// Shortened name keeps a few characters besides its hash suffix
+    const NAME_MAX_LENGTH_MIN_VALUE: usize = 16;
+    /// Zabbix rejects longer names of web scenarios and steps
+    const NAME_MAX_LENGTH_MAX_VALUE: usize = 64;
+    const TRIGGER_NAME_TEMPLATE_DEFAULT_VALUE: &str = "Site '{{ url }}' is unavailable";
+    const TRIGGER_EXPRESSION_TEMPLATE_DEFAULT_VALUE: &str =
+                                        "last(/{{ host.host }}/web.test.fail[{{ scenario | key_param }}])<>0";
+
+    const PROBE_TIMEOUT_DEFAULT_VALUE: i64 = 5;
+
+    const TRAPPER_PORT_DEFAULT_VALUE: u16 = 10051;
+    const TRAPPER_KEY_DEFAULT_VALUE: &str = "wszl.run";
+
+    /// Config file locations in search order: current directory, XDG config directory
+    /// (`$XDG_CONFIG_HOME/wszl/wszl.yml` or `~/.config/wszl/wszl.yml`), `/etc/wszl/wszl.yml`
+    pub fn get_config_file_locations(xdg_config_home: Option<String>, home: Option<String>) -> Vec<PathBuf> {
+        let mut locations = vec![PathBuf::from(CONFIG_FILE_NAME)];
+
+        let xdg_config_dir = xdg_config_home.filter(|path| !path.is_empty()).map(PathBuf::from)
+            .or_else(|| home.map(|home| Path::new(&home).join(".config")));
+
+        if let Some(xdg_config_dir) = xdg_config_dir {
+            locations.push(xdg_config_dir.join("wszl").join(CONFIG_FILE_NAME));
+        }
+
+        locations.push(Path::new(SYSTEM_CONFIG_DIR).join(CONFIG_FILE_NAME));
+
+        locations
+    }
+
+    /// The first existing config file from standard locations, see `get_config_file_locations`
+    pub fn find_config_file() -> Option<PathBuf> {
+        let locations = get_config_file_locations(env::var("XDG_CONFIG_HOME").ok(), env::var("HOME").ok());
+
+        for location in &locations {
+            debug!("looking for config file '{}'", location.display());
+        }
+
+        locations.into_iter().find(|location| location.is_file())
+    }
+
+    /// Config files (`*.yml`, `*.yaml`) of directory in alphabetical order
+    pub fn find_config_files(directory: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut config_files = vec![];
+
+        for entry in fs::read_dir(directory)? {
+            let path = entry?.path();
+
+            let is_yaml = path.extension().map(|extension| extension == "yml" || extension == "yaml")
+                            .unwrap_or(false);
+
+            if is_yaml && path.is_file() {
+                config_files.push(path);
+            }
+        }
+
+        config_files.sort();
+
+        Ok(config_files)
+    }
+
+    /// Lanes of `--parallel` run, indexes of configs. Circuit breaker, API call stats, timings and audit file
+    /// are kept per Zabbix API endpoint, so configs of the same endpoint share a lane and run one by one.
+    /// Configs without endpoint (i.e. invalid ones) get their own lanes.
+    pub fn get_parallel_lanes(api_endpoints: &[Option<String>]) -> Vec<Vec<usize>> {
+        let mut lanes: Vec<(Option<&str>, Vec<usize>)> = vec![];
+
+        for (index, api_endpoint) in api_endpoints.iter().enumerate() {
+            let api_endpoint = api_endpoint.as_deref();
+
+            match lanes.iter_mut().find(|(lane_endpoint, _)| api_endpoint.is_some() && *lane_endpoint == api_endpoint) {
+                Some((_, lane)) => lane.push(index),
+                None => lanes.push((api_endpoint, vec![index]))
+            }
+        }
+
+        lanes.into_iter().map(|(_, lane)| lane).collect()
     }
 
     pub fn load_config_from_file(file_path: &Path) -> OperationResult<Config> {
@@ -44,10 +612,27 @@ pub mod config {
 
                 let api_endpoint = zabbix_api_config["endpoint"].as_str()
                                                .expect("property 'endpoint' wasn't found");
-                let username = zabbix_api_config["username"].as_str()
-                                               .expect("property 'username' wasn't found");
-                let password = zabbix_api_config["password"].as_str()
-                                               .expect("property 'password' wasn't found");
+                let vault_config = get_vault_config(&config["vault"])?;
+
+                let daemon_config = get_daemon_config(&config["daemon"])?;
+
+                let syslog_config = &config["logging"]["syslog"];
+
+                let syslog = syslog_config.as_hash().map(|_| SyslogConfig {
+                    address: syslog_config["address"].as_str().unwrap_or(SYSLOG_ADDRESS_DEFAULT_VALUE).to_string(),
+                    facility: syslog_config["facility"].as_str().unwrap_or(SYSLOG_FACILITY_DEFAULT_VALUE).to_string(),
+                    app_name: syslog_config["app-name"].as_str().unwrap_or(SYSLOG_APP_NAME_DEFAULT_VALUE).to_string(),
+                    level: syslog_config["level"].as_str().unwrap_or(SYSLOG_LEVEL_DEFAULT_VALUE).to_string()
+                });
+
+                let username = match vault_config {
+                    Some(_) => zabbix_api_config["username"].as_str().unwrap_or_default(),
+                    None => zabbix_api_config["username"].as_str()
+                                               .expect("property 'username' wasn't found")
+                };
+                let password = zabbix_api_config["password"].as_str().unwrap_or_default();
+
+                let keyring = zabbix_api_config["keyring"].as_bool().unwrap_or(KEYRING_DEFAULT_VALUE);
 
                 let web_scenario_config = &zabbix_config["scenario"];
 
@@ -63,6 +648,102 @@ pub mod config {
                 let update_interval = web_scenario_config["update-interval"].as_str()
                     .expect("property 'update-interval' wasn't found");
 
+                let retrieve_mode = get_retrieve_mode(&web_scenario_config["retrieve-mode"])?;
+
+                let variables = get_scenario_variables(&web_scenario_config["variables"])?;
+
+                let proxy_scenario_config = &zabbix_config["proxy-scenario"];
+
+                let proxy_retrieve_mode = match proxy_scenario_config["retrieve-mode"].as_str() {
+                    Some(_) => get_retrieve_mode(&proxy_scenario_config["retrieve-mode"])?,
+                    None => retrieve_mode
+                };
+
+                let proxy_variables = match proxy_scenario_config["variables"].as_hash() {
+                    Some(_) => get_scenario_variables(&proxy_scenario_config["variables"])?,
+                    None => variables.clone()
+                };
+
+                let proxy_scenario = proxy_scenario_config.as_hash().map(|_| WebScenarioConfig {
+                    response_timeout: proxy_scenario_config["response-timeout"].as_str()
+                                        .unwrap_or(response_timeout).to_string(),
+                    expected_status_code: proxy_scenario_config["expect-status-code"].as_str()
+                                        .unwrap_or(expected_status_code).to_string(),
+                    attempts: proxy_scenario_config["attempts"].as_i64().unwrap_or(attempts) as u8,
+                    update_interval: proxy_scenario_config["update-interval"].as_str()
+                                        .unwrap_or(update_interval).to_string(),
+                    retrieve_mode: proxy_retrieve_mode,
+                    variables: proxy_variables
+                });
+
+                let graph_config = get_graph_config(&zabbix_config["graph"]);
+
+                let metrics_config = &config["metrics"];
+
+                let metrics_job = metrics_config["job"].as_str()
+                                    .unwrap_or(METRICS_JOB_DEFAULT_VALUE);
+
+                let tracing_config = &config["tracing"];
+
+                let service_name = tracing_config["service-name"].as_str()
+                                    .unwrap_or(TRACING_SERVICE_NAME_DEFAULT_VALUE);
+
+                let notifications_config = get_notifications_config(&config["notifications"])?;
+
+                let processing_config = &config["processing"];
+
+                let hosts_per_chunk = processing_config["hosts-per-chunk"].as_i64()
+                                        .unwrap_or(HOSTS_PER_CHUNK_DEFAULT_VALUE);
+
+                let concurrency = processing_config["concurrency"].as_i64()
+                                        .unwrap_or(CONCURRENCY_DEFAULT_VALUE);
+
+                let skip_maintenance = processing_config["skip-maintenance"].as_bool()
+                                        .unwrap_or(SKIP_MAINTENANCE_DEFAULT_VALUE);
+
+                let skip_disabled = processing_config["skip-disabled"].as_bool()
+                                        .unwrap_or(SKIP_DISABLED_DEFAULT_VALUE);
+
+                let items_config = get_items_config(&config["items"])?;
+
+                let discovery_config = get_discovery_config(&config["discovery"]);
+
+                let urls_config = get_urls_config(&config["urls"])?;
+
+                let probe_config = get_probe_config(&config["probe"])?;
+
+                let services_config = get_services_config(&config["services"])?;
+
+                let templates_config = get_templates_config(&config["templates"])?;
+
+                let aggregate_config = config["host-aggregate"].as_hash().map(|_| AggregateConfig {
+                    priority: config["host-aggregate"]["priority"].as_i64()
+                                .unwrap_or(AGGREGATE_PRIORITY_DEFAULT_VALUE) as u8
+                });
+
+                let latency_config = get_latency_config(&config["latency"])?;
+
+                let http_proxy_config = get_http_proxy_config(&config["http-proxy"])?;
+
+                let central_host_config = get_central_host_config(&config["central-host"])?;
+
+                let hooks_config = get_hooks_config(&config["hooks"])?;
+
+                let grace_config = match config["grace-maintenance"].as_hash() {
+                    Some(_) => Some(GraceConfig {
+                        duration_secs: get_duration_secs(&config["grace-maintenance"]["duration"],
+                                                         "grace-maintenance duration")?
+                                            .unwrap_or(GRACE_DURATION_DEFAULT_VALUE),
+                        name: config["grace-maintenance"]["name"].as_str()
+                                .unwrap_or(GRACE_NAME_DEFAULT_VALUE).to_string()
+                    }),
+                    None => None
+                };
+
+                let dashboard_config = config["dashboard"].as_hash().map(|_| DashboardConfig {
+                    name: config["dashboard"]["name"].as_str().unwrap_or(DASHBOARD_NAME_DEFAULT_VALUE).to_string()
+                });
+
                 info!("config has been loaded");
 
                 Ok(
@@ -71,15 +752,72 @@ pub mod config {
                             api: ZabbixApiConfig {
                                 endpoint: api_endpoint.to_string(),
                                 username: username.to_string(),
-                                password: password.to_string()
+                                password: password.to_string(),
+                                keyring,
+                                session_file: zabbix_api_config["session-file"].as_str().map(String::from)
                             },
                             scenario: WebScenarioConfig {
                                 response_timeout: response_timeout.to_string(),
                                 expected_status_code: expected_status_code.to_string(),
                                 attempts: attempts as u8,
-                                update_interval: update_interval.to_string()
-                            }
-                        }
+                                update_interval: update_interval.to_string(),
+                                retrieve_mode,
+                                variables
+                            },
+                            proxy_scenario,
+                            host_group: zabbix_config["host-group"].as_str().map(String::from),
+                            correlation_tag: zabbix_config["correlation-tag"].as_str().map(String::from),
+                            graph: graph_config
+                        },
+                        metrics: MetricsConfig {
+                            textfile_path: metrics_config["textfile"].as_str().map(String::from),
+                            pushgateway_url: metrics_config["pushgateway"].as_str().map(String::from),
+                            job: metrics_job.to_string()
+                        },
+                        tracing: TracingConfig {
+                            otlp_endpoint: tracing_config["otlp-endpoint"].as_str().map(String::from),
+                            service_name: service_name.to_string()
+                        },
+                        processing: ProcessingConfig {
+                            hosts_per_chunk: hosts_per_chunk as usize,
+                            concurrency: concurrency as usize,
+                            skip_maintenance,
+                            skip_disabled,
+                            lock_file: processing_config["lock-file"].as_str()
+                                        .unwrap_or(LOCK_FILE_DEFAULT_VALUE).to_string(),
+                            state_file: processing_config["state-file"].as_str()
+                                        .unwrap_or(STATE_FILE_DEFAULT_VALUE).to_string(),
+                            detect_renames: processing_config["detect-renames"].as_bool()
+                                        .unwrap_or(DETECT_RENAMES_DEFAULT_VALUE),
+                            max_consecutive_failures: processing_config["max-consecutive-failures"].as_i64()
+                                        .unwrap_or(MAX_CONSECUTIVE_FAILURES_DEFAULT_VALUE).max(0) as usize,
+                            max_errors: processing_config["max-errors"].as_i64().unwrap_or(0).max(0) as usize,
+                            max_scenarios_per_host: processing_config["max-scenarios-per-host"].as_i64()
+                                        .unwrap_or(0).max(0) as usize,
+                            max_scenarios_per_run: processing_config["max-scenarios-per-run"].as_i64()
+                                        .unwrap_or(0).max(0) as usize
+                        },
+                        items: items_config,
+                        discovery: discovery_config,
+                        urls: urls_config,
+                        notifications: notifications_config,
+                        audit: AuditConfig {
+                            file_path: config["audit"]["file"].as_str().map(String::from)
+                        },
+                        probe: probe_config,
+                        dashboard: dashboard_config,
+                        services: services_config,
+                        aggregate: aggregate_config,
+                        latency: latency_config,
+                        http_proxy: http_proxy_config,
+                        central_host: central_host_config,
+                        grace: grace_config,
+                        hooks: hooks_config,
+                        templates: templates_config,
+                        script_file: config["script"]["file"].as_str().map(String::from),
+                        vault: vault_config,
+                        daemon: daemon_config,
+                        syslog
                     }
                 )
             }
@@ -89,4 +827,637 @@ pub mod config {
             }
         }
     }
+
+    fn get_items_config(items_config: &Yaml) -> OperationResult<ItemsConfig> {
+        let mut patterns = vec![];
+
+        if let Some(pattern_values) = items_config["patterns"].as_vec() {
+            for pattern_value in pattern_values {
+                match get_item_key_pattern_config(pattern_value) {
+                    Some(pattern) => patterns.push(pattern),
+                    None => {
+                        error!("invalid item key pattern, expected mask string or 'regex' and 'search' properties");
+                        return Err(OperationError::Error)
+                    }
+                }
+            }
+        }
+
+        if patterns.is_empty() {
+            patterns.push(ItemKeyPatternConfig::Mask(ITEM_KEY_PATTERN_DEFAULT_VALUE.to_string()));
+        }
+
+        let skip_unsupported = items_config["skip-unsupported"].as_bool()
+                                        .unwrap_or(SKIP_UNSUPPORTED_ITEMS_DEFAULT_VALUE);
+
+        let stale_after_secs = get_duration_secs(&items_config["stale-after"], "items stale-after")?;
+
+        let tags = get_item_tags(&items_config["tags"])?;
+
+        Ok(ItemsConfig { patterns, skip_unsupported, stale_after_secs, tags })
+    }
+
+    fn get_item_tags(tags_config: &Yaml) -> OperationResult<Vec<ItemTagConfig>> {
+        let mut tags = vec![];
+
+        if let Some(tag_values) = tags_config.as_vec() {
+            for tag_value in tag_values {
+                let tag = tag_value.as_str().unwrap_or_default();
+
+                let (tag, value) = match tag.split_once(':') {
+                    Some((tag, value)) => (tag, Some(value.to_string())),
+                    None => (tag, None)
+                };
+
+                if tag.is_empty() {
+                    error!("invalid item tag filter, expected 'tag:value' or 'tag' string");
+                    return Err(OperationError::Error)
+                }
+
+                tags.push(ItemTagConfig { tag: tag.to_string(), value });
+            }
+        }
+
+        Ok(tags)
+    }
+
+    /// Duration as string with suffix or integer seconds
+    fn get_duration_secs(value: &Yaml, property: &str) -> OperationResult<Option<u64>> {
+        match value.as_str() {
+            Some(value) => match parse_duration_secs(value) {
+                Some(secs) => Ok(Some(secs)),
+                None => {
+                    error!("invalid {} value '{}', expected duration like 30m, 12h or 7d", property, value);
+                    Err(OperationError::Error)
+                }
+            }
+            None => Ok(value.as_i64().map(|secs| secs as u64))
+        }
+    }
+
+    fn get_hooks_config(hooks_config: &Yaml) -> OperationResult<HooksConfig> {
+        let timeout_secs = get_duration_secs(&hooks_config["timeout"], "hooks timeout")?
+                                .unwrap_or(HOOK_TIMEOUT_DEFAULT_VALUE);
+
+        if timeout_secs == 0 {
+            error!("hooks timeout must be greater than zero");
+            return Err(OperationError::Error)
+        }
+
+        Ok(HooksConfig {
+            pre_create: hooks_config["pre-create"].as_str().map(String::from),
+            post_create: hooks_config["post-create"].as_str().map(String::from),
+            pre_delete: hooks_config["pre-delete"].as_str().map(String::from),
+            post_delete: hooks_config["post-delete"].as_str().map(String::from),
+            run_end: hooks_config["run-end"].as_str().map(String::from),
+            timeout_secs
+        })
+    }
+
+    fn get_daemon_config(daemon_config: &Yaml) -> OperationResult<DaemonConfig> {
+        let schedule = match daemon_config["schedule"].as_str() {
+            Some(expression) => DaemonSchedule::Cron(expression.to_string()),
+            None => {
+                let interval_secs = get_duration_secs(&daemon_config["interval"], "daemon interval")?
+                                        .unwrap_or(DAEMON_INTERVAL_DEFAULT_VALUE);
+                DaemonSchedule::Interval(interval_secs)
+            }
+        };
+
+        let jitter_secs = get_duration_secs(&daemon_config["jitter"], "daemon jitter")?.unwrap_or(0);
+
+        Ok(DaemonConfig { schedule, jitter_secs })
+    }
+
+    fn get_latency_config(latency_config: &Yaml) -> OperationResult<Option<LatencyConfig>> {
+        if latency_config.as_hash().is_none() {
+            return Ok(None)
+        }
+
+        let mut rules = vec![];
+
+        for rule_config in latency_config["rules"].as_vec().unwrap_or(&vec![]) {
+            match (rule_config["pattern"].as_str(), get_threshold_ms(&rule_config["threshold"])?) {
+                (Some(pattern), Some(threshold_ms)) => rules.push(LatencyRuleConfig {
+                    pattern: pattern.to_string(), threshold_ms
+                }),
+                _ => {
+                    error!("invalid latency rule, expected 'pattern' and 'threshold' properties");
+                    return Err(OperationError::Error)
+                }
+            }
+        }
+
+        Ok(Some(LatencyConfig {
+            threshold_ms: get_threshold_ms(&latency_config["threshold"])?.unwrap_or(LATENCY_THRESHOLD_DEFAULT_VALUE),
+            priority: latency_config["priority"].as_i64().unwrap_or(LATENCY_PRIORITY_DEFAULT_VALUE) as u8,
+            rules
+        }))
+    }
+
+    fn get_http_proxy_config(http_proxy_config: &Yaml) -> OperationResult<Option<HttpProxyConfig>> {
+        if http_proxy_config.as_hash().is_none() {
+            return Ok(None)
+        }
+
+        let mut rules = vec![];
+
+        for rule_config in http_proxy_config["rules"].as_vec().unwrap_or(&vec![]) {
+            match (rule_config["pattern"].as_str(), rule_config["proxy"].as_str()) {
+                (Some(pattern), Some(proxy)) => rules.push(HttpProxyRuleConfig {
+                    pattern: pattern.to_string(), proxy: proxy.to_string()
+                }),
+                _ => {
+                    error!("invalid http-proxy rule, expected 'pattern' and 'proxy' properties");
+                    return Err(OperationError::Error)
+                }
+            }
+        }
+
+        Ok(Some(HttpProxyConfig {
+            proxy: http_proxy_config["proxy"].as_str().unwrap_or_default().to_string(),
+            rules
+        }))
+    }
+
+    fn get_central_host_config(central_host_config: &Yaml) -> OperationResult<Option<CentralHostConfig>> {
+        if central_host_config.as_hash().is_none() {
+            return Ok(None)
+        }
+
+        match central_host_config["host"].as_str() {
+            Some(host) => Ok(Some(CentralHostConfig {
+                host: host.to_string(),
+                patterns: get_string_list(&central_host_config["patterns"])
+            })),
+            None => {
+                error!("invalid central-host config, expected 'host' property");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Response time: `500ms`, `3s` or seconds without suffix
+    fn get_threshold_ms(value: &Yaml) -> OperationResult<Option<u64>> {
+        let threshold_ms = match value {
+            Yaml::BadValue | Yaml::Null => return Ok(None),
+            Yaml::Integer(secs) if *secs >= 0 => Some(*secs as u64 * 1000),
+            Yaml::Real(_) => value.as_f64().filter(|secs| *secs >= 0.0).map(|secs| (secs * 1000.0).round() as u64),
+            Yaml::String(value) => parse_threshold_ms(value),
+            _ => None
+        };
+
+        match threshold_ms {
+            Some(threshold_ms) => Ok(Some(threshold_ms)),
+            None => {
+                error!("invalid latency threshold {:?}, expected response time like 500ms or 3s", value);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    pub fn parse_threshold_ms(value: &str) -> Option<u64> {
+        let value = value.trim();
+
+        match value.strip_suffix("ms") {
+            Some(millis) => millis.trim().parse::<u64>().ok(),
+            None => {
+                let secs = value.strip_suffix('s').unwrap_or(value).trim();
+                secs.parse::<f64>().ok().filter(|secs| *secs >= 0.0).map(|secs| (secs * 1000.0).round() as u64)
+            }
+        }
+    }
+
+    /// Duration with suffix: `s`, `m`, `h`, `d` (i.e. `90s`, `12h`), seconds without suffix
+    pub fn parse_duration_secs(value: &str) -> Option<u64> {
+        let value = value.trim();
+
+        let (number, multiplier) = match value.chars().last()? {
+            's' => (&value[..value.len() - 1], 1),
+            'm' => (&value[..value.len() - 1], 60),
+            'h' => (&value[..value.len() - 1], 3600),
+            'd' => (&value[..value.len() - 1], 86400),
+            _ => (value, 1)
+        };
+
+        number.parse::<u64>().ok().and_then(|number| number.checked_mul(multiplier))
+    }
+
+    fn get_item_key_pattern_config(pattern_value: &Yaml) -> Option<ItemKeyPatternConfig> {
+        match pattern_value.as_str() {
+            Some(mask) => Some(ItemKeyPatternConfig::Mask(mask.to_string())),
+            None => {
+                let regex = pattern_value["regex"].as_str()?;
+                let search = pattern_value["search"].as_str()?;
+
+                Some(ItemKeyPatternConfig::Regex {
+                    regex: regex.to_string(), search: search.to_string()
+                })
+            }
+        }
+    }
+
+    fn get_discovery_config(discovery_config: &Yaml) -> DiscoveryConfig {
+        let lld_config = &discovery_config["lld"];
+
+        let lld = lld_config["item-key"].as_str().map(|item_key| {
+            let url_macro = lld_config["url-macro"].as_str().unwrap_or(LLD_URL_MACRO_DEFAULT_VALUE);
+
+            LldDiscoveryConfig {
+                item_key: item_key.to_string(),
+                url_macro: url_macro.to_string()
+            }
+        });
+
+        let docker_config = &discovery_config["docker"];
+
+        let docker = docker_config["host"].as_str().map(|host| {
+            let socket = docker_config["socket"].as_str().unwrap_or(DOCKER_SOCKET_DEFAULT_VALUE);
+            let scheme = docker_config["scheme"].as_str().unwrap_or(DOCKER_SCHEME_DEFAULT_VALUE);
+
+            DockerDiscoveryConfig {
+                socket: socket.to_string(),
+                host: host.to_string(),
+                scheme: scheme.to_string()
+            }
+        });
+
+        DiscoveryConfig {
+            lld,
+            urls_file: discovery_config["urls-file"].as_str().map(String::from),
+            docker,
+            vhosts: get_vhosts_discovery_config(&discovery_config["vhosts"])
+        }
+    }
+
+    fn get_vhosts_discovery_config(vhosts_config: &Yaml) -> Option<VhostsDiscoveryConfig> {
+        let nginx_dirs = get_string_list(&vhosts_config["nginx-dirs"]);
+        let apache_dirs = get_string_list(&vhosts_config["apache-dirs"]);
+
+        if nginx_dirs.is_empty() && apache_dirs.is_empty() {
+            return None
+        }
+
+        let host = match vhosts_config["host"].as_str() {
+            Some(host) => host.to_string(),
+            None => get_local_host_name()
+        };
+
+        Some(VhostsDiscoveryConfig { host, nginx_dirs, apache_dirs })
+    }
+
+    fn get_local_host_name() -> String {
+        fs::read_to_string("/proc/sys/kernel/hostname")
+            .or_else(|_| fs::read_to_string("/etc/hostname"))
+            .map(|host_name| host_name.trim().to_string())
+            .expect("unable to get local host name, set property 'host' for 'vhosts'")
+    }
+
+    fn get_string_list(value: &Yaml) -> Vec<String> {
+        value.as_vec()
+            .map(|values| values.iter().filter_map(|value| value.as_str()).map(String::from).collect())
+            .unwrap_or_default()
+    }
+
+    fn get_notifications_config(notifications_config: &Yaml) -> OperationResult<NotificationsConfig> {
+        let slack_config = &notifications_config["slack"];
+
+        let slack = match slack_config["webhook-url"].as_str() {
+            Some(webhook_url) => Some(ChatWebhookConfig {
+                webhook_url: webhook_url.to_string(),
+                notify_on: get_notify_on(&slack_config["notify-on"])?
+            }),
+            None => None
+        };
+
+        let mattermost_config = &notifications_config["mattermost"];
+
+        let mattermost = match mattermost_config["webhook-url"].as_str() {
+            Some(webhook_url) => Some(ChatWebhookConfig {
+                webhook_url: webhook_url.to_string(),
+                notify_on: get_notify_on(&mattermost_config["notify-on"])?
+            }),
+            None => None
+        };
+
+        let telegram_config = &notifications_config["telegram"];
+
+        let telegram = match (telegram_config["bot-token"].as_str(), get_scalar_string(&telegram_config["chat-id"])) {
+            (Some(bot_token), Some(chat_id)) => Some(TelegramConfig {
+                bot_token: bot_token.to_string(),
+                chat_id,
+                notify_on: get_notify_on(&telegram_config["notify-on"])?
+            }),
+            _ => None
+        };
+
+        Ok(NotificationsConfig {
+            webhook_url: notifications_config["webhook-url"].as_str().map(String::from),
+            slack,
+            mattermost,
+            telegram,
+            email: get_email_config(&notifications_config["email"])?,
+            trapper: get_trapper_config(&notifications_config["zabbix-trapper"])?
+        })
+    }
+
+    fn get_trapper_config(trapper_config: &Yaml) -> OperationResult<Option<TrapperConfig>> {
+        let server = match trapper_config["server"].as_str() {
+            Some(server) => server,
+            None => return Ok(None)
+        };
+        let host = trapper_config["host"].as_str().expect("property 'host' wasn't found");
+        let key = trapper_config["key"].as_str().unwrap_or(TRAPPER_KEY_DEFAULT_VALUE);
+
+        let server = match get_server_address(server, TRAPPER_PORT_DEFAULT_VALUE) {
+            Ok(server) => server,
+            Err(e) => {
+                error!("invalid zabbix-trapper server '{}': {}", server, e);
+                return Err(OperationError::Error)
+            }
+        };
+
+        Ok(Some(TrapperConfig {
+            server,
+            host: host.to_string(),
+            key: key.to_string()
+        }))
+    }
+
+    fn get_email_config(email_config: &Yaml) -> OperationResult<Option<EmailConfig>> {
+        let smtp_host = match email_config["smtp-host"].as_str() {
+            Some(smtp_host) => smtp_host,
+            None => return Ok(None)
+        };
+
+        let security = match email_config["security"].as_str() {
+            None | Some("starttls") => SmtpSecurity::StartTls,
+            Some("tls") => SmtpSecurity::Tls,
+            Some("none") => SmtpSecurity::None,
+            Some(value) => {
+                error!("invalid email security value '{}', expected 'starttls', 'tls' or 'none'", value);
+                return Err(OperationError::Error)
+            }
+        };
+
+        let smtp_port = email_config["smtp-port"].as_i64().unwrap_or(match security {
+            SmtpSecurity::StartTls => SMTP_STARTTLS_PORT_DEFAULT_VALUE,
+            SmtpSecurity::Tls => SMTP_TLS_PORT_DEFAULT_VALUE,
+            SmtpSecurity::None => SMTP_PORT_DEFAULT_VALUE
+        });
+
+        let from = email_config["from"].as_str().expect("property 'from' wasn't found");
+
+        let to = get_string_list(&email_config["to"]);
+
+        if to.is_empty() {
+            error!("email report recipients ('to' property) aren't set");
+            return Err(OperationError::Error)
+        }
+
+        Ok(Some(EmailConfig {
+            smtp_host: smtp_host.to_string(),
+            smtp_port: smtp_port as u16,
+            security,
+            username: email_config["username"].as_str().map(String::from),
+            password: email_config["password"].as_str().map(String::from),
+            from: from.to_string(),
+            to,
+            notify_on: get_notify_on(&email_config["notify-on"])?
+        }))
+    }
+
+    /// Map of variable names and values, names are wrapped in braces by Zabbix convention: `{token}`
+    fn get_scenario_variables(variables_config: &Yaml) -> OperationResult<Vec<ScenarioVariableConfig>> {
+        let mut variables = vec![];
+
+        if let Some(variable_values) = variables_config.as_hash() {
+            for (name, value) in variable_values {
+                match (name.as_str(), value.as_str()) {
+                    (Some(name), Some(value)) => variables.push(ScenarioVariableConfig {
+                        name: name.to_string(), value: value.to_string()
+                    }),
+                    _ => {
+                        error!("invalid scenario variable, expected string name and value");
+                        return Err(OperationError::Error)
+                    }
+                }
+            }
+        }
+
+        Ok(variables)
+    }
+
+    fn get_retrieve_mode(retrieve_mode: &Yaml) -> OperationResult<RetrieveMode> {
+        match retrieve_mode.as_str() {
+            None | Some("body") => Ok(RetrieveMode::Body),
+            Some("headers") => Ok(RetrieveMode::Headers),
+            Some("both") => Ok(RetrieveMode::Both),
+            Some(value) => {
+                error!("invalid retrieve-mode value '{}', expected 'body', 'headers' or 'both'", value);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn get_notify_on(notify_on: &Yaml) -> OperationResult<NotifyOn> {
+        match notify_on.as_str() {
+            None | Some("changes") => Ok(NotifyOn::Changes),
+            Some("always") => Ok(NotifyOn::Always),
+            Some("errors") => Ok(NotifyOn::Errors),
+            Some(value) => {
+                error!("invalid notify-on value '{}', expected 'always', 'changes' or 'errors'", value);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Telegram chat ids are numbers, but may be written as strings too
+    fn get_scalar_string(value: &Yaml) -> Option<String> {
+        match value {
+            Yaml::String(value) => Some(value.to_string()),
+            Yaml::Integer(value) => Some(value.to_string()),
+            _ => None
+        }
+    }
+
+    fn get_graph_config(graph_config: &Yaml) -> Option<GraphConfig> {
+        graph_config.as_hash()?;
+
+        let name = graph_config["name"].as_str().unwrap_or(GRAPH_NAME_DEFAULT_VALUE);
+        let width = graph_config["width"].as_i64().unwrap_or(GRAPH_WIDTH_DEFAULT_VALUE);
+        let height = graph_config["height"].as_i64().unwrap_or(GRAPH_HEIGHT_DEFAULT_VALUE);
+
+        Some(GraphConfig {
+            name: name.to_string(),
+            width: width as u32,
+            height: height as u32
+        })
+    }
+
+    fn get_templates_config(templates_config: &Yaml) -> OperationResult<TemplatesConfig> {
+        let mut config = TemplatesConfig::default();
+
+        let templates = vec![
+            ("scenario-name", &mut config.scenario_name),
+            ("step-name", &mut config.step_name),
+            ("trigger-name", &mut config.trigger_name),
+            ("trigger-expression", &mut config.trigger_expression),
+            ("trigger-comments", &mut config.trigger_comments)
+        ];
+
+        for (key, template) in templates {
+            if let Some(value) = templates_config[key].as_str() {
+                *template = value.to_string();
+            }
+        }
+
+        if let Some(vars) = templates_config["vars"].as_hash() {
+            for (name, value) in vars {
+                if let (Some(name), Some(value)) = (name.as_str(), get_scalar_string(value)) {
+                    config.vars.insert(name.to_string(), value);
+                }
+            }
+        }
+
+        if let Some(max_name_length) = templates_config["max-name-length"].as_i64() {
+            if max_name_length < NAME_MAX_LENGTH_MIN_VALUE as i64 ||
+               max_name_length > NAME_MAX_LENGTH_MAX_VALUE as i64 {
+                error!("invalid templates max-name-length {}, expected {} to {}", max_name_length,
+                       NAME_MAX_LENGTH_MIN_VALUE, NAME_MAX_LENGTH_MAX_VALUE);
+                return Err(OperationError::Error)
+            }
+
+            config.max_name_length = max_name_length as usize;
+        }
+
+        Ok(config)
+    }
+
+    fn get_vault_config(vault_config: &Yaml) -> OperationResult<Option<VaultConfig>> {
+        if vault_config.as_hash().is_none() {
+            return Ok(None)
+        }
+
+        let (address, path) = match (vault_config["address"].as_str(), vault_config["path"].as_str()) {
+            (Some(address), Some(path)) => (address, path),
+            _ => {
+                error!("vault config requires 'address' and 'path' properties");
+                return Err(OperationError::Error)
+            }
+        };
+
+        let approle_config = &vault_config["approle"];
+
+        let approle = match approle_config.as_hash() {
+            Some(_) => match (approle_config["role-id"].as_str(), approle_config["secret-id"].as_str()) {
+                (Some(role_id), Some(secret_id)) => Some(VaultAppRoleConfig {
+                    role_id: role_id.to_string(),
+                    secret_id: secret_id.to_string()
+                }),
+                _ => {
+                    error!("vault approle config requires 'role-id' and 'secret-id' properties");
+                    return Err(OperationError::Error)
+                }
+            },
+            None => None
+        };
+
+        Ok(Some(VaultConfig {
+            address: address.trim_end_matches('/').to_string(),
+            mount: vault_config["mount"].as_str().unwrap_or(VAULT_MOUNT_DEFAULT_VALUE).to_string(),
+            path: path.to_string(),
+            token: vault_config["token"].as_str().map(String::from),
+            approle,
+            username_key: vault_config["username-key"].as_str()
+                            .unwrap_or(VAULT_USERNAME_KEY_DEFAULT_VALUE).to_string(),
+            password_key: vault_config["password-key"].as_str()
+                            .unwrap_or(VAULT_PASSWORD_KEY_DEFAULT_VALUE).to_string()
+        }))
+    }
+
+    fn get_services_config(services_config: &Yaml) -> OperationResult<Option<ServicesConfig>> {
+        if services_config.as_hash().is_none() {
+            return Ok(None)
+        }
+
+        let group_by = match services_config["group-by"].as_str() {
+            None | Some("host") => ServiceGroupBy::Host,
+            Some("domain") => ServiceGroupBy::Domain,
+            Some(value) => {
+                error!("invalid services group-by value '{}', expected 'host' or 'domain'", value);
+                return Err(OperationError::Error)
+            }
+        };
+
+        let sla = match &services_config["sla"] {
+            Yaml::Real(_) => services_config["sla"].as_f64(),
+            Yaml::Integer(value) => Some(*value as f64),
+            _ => None
+        }.unwrap_or(SERVICES_SLA_DEFAULT_VALUE);
+
+        let root = services_config["root"].as_str().unwrap_or(SERVICES_ROOT_DEFAULT_VALUE);
+
+        Ok(Some(ServicesConfig { root: root.to_string(), group_by, sla }))
+    }
+
+    fn get_probe_config(probe_config: &Yaml) -> OperationResult<Option<ProbeConfig>> {
+        if probe_config.as_hash().is_none() {
+            return Ok(None)
+        }
+
+        let skip_unreachable = match probe_config["unreachable"].as_str() {
+            None | Some("flag") => false,
+            Some("skip") => true,
+            Some(value) => {
+                error!("invalid probe unreachable value '{}', expected 'flag' or 'skip'", value);
+                return Err(OperationError::Error)
+            }
+        };
+
+        let timeout_secs = probe_config["timeout"].as_i64().unwrap_or(PROBE_TIMEOUT_DEFAULT_VALUE);
+
+        Ok(Some(ProbeConfig {
+            skip_unreachable,
+            timeout_secs: timeout_secs as u64
+        }))
+    }
+
+    fn get_urls_config(urls_config: &Yaml) -> OperationResult<UrlsConfig> {
+        let mut rewrites = vec![];
+
+        if let Some(rewrite_values) = urls_config["rewrite"].as_vec() {
+            for rewrite_value in rewrite_values {
+                match (rewrite_value["search"].as_str(), rewrite_value["replace"].as_str()) {
+                    (Some(search), Some(replace)) => rewrites.push(UrlRewriteConfig {
+                        search: search.to_string(), replace: replace.to_string()
+                    }),
+                    _ => {
+                        error!("invalid url rewrite rule, expected 'search' and 'replace' properties");
+                        return Err(OperationError::Error)
+                    }
+                }
+            }
+        }
+
+        let dedup_scope = match urls_config["dedup-scope"].as_str() {
+            None | Some("host") => DedupScope::Host,
+            Some("global") => DedupScope::Global,
+            Some(value) => {
+                error!("invalid dedup-scope value '{}', expected 'host' or 'global'", value);
+                return Err(OperationError::Error)
+            }
+        };
+
+        Ok(UrlsConfig {
+            rewrites,
+            force_scheme: urls_config["force-scheme"].as_str().map(String::from),
+            append_path: urls_config["append-path"].as_str().map(String::from),
+            include: get_string_list(&urls_config["include"]),
+            exclude: get_string_list(&urls_config["exclude"]),
+            dedup_scope,
+            blocklist_file: urls_config["blocklist-file"].as_str()
+                                .unwrap_or(BLOCKLIST_FILE_DEFAULT_VALUE).to_string()
+        })
+    }
 }
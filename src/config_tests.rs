@@ -1,8 +1,12 @@
 #[cfg(test)]
 mod config_tests {
-    use std::path::Path;
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
 
-    use crate::config::config::load_config_from_file;
+    use crate::config::config::{DaemonSchedule, DedupScope, find_config_files, get_config_file_locations,
+                                get_parallel_lanes, ItemKeyPatternConfig, ItemTagConfig, load_config_from_file, NotifyOn,
+                                parse_duration_secs, parse_threshold_ms, RetrieveMode, ServiceGroupBy, SmtpSecurity};
 
     #[test]
     fn complete_config_should_be_loaded_from_file() {
@@ -13,13 +17,296 @@ mod config_tests {
                 assert_eq!(config.zabbix.api.endpoint, "http://zabbix/api_jsonrpc.php");
                 assert_eq!(config.zabbix.api.username, "abcd");
                 assert_eq!(config.zabbix.api.password, "0329jg02934jg34g");
+                assert_eq!(config.zabbix.api.session_file.unwrap(), "/var/lib/wszl/session.json");
 
                 assert_eq!(config.zabbix.scenario.response_timeout, "15s");
                 assert_eq!(config.zabbix.scenario.expected_status_code, "200");
                 assert_eq!(config.zabbix.scenario.attempts, 3);
                 assert_eq!(config.zabbix.scenario.update_interval, "5m");
+                assert_eq!(config.zabbix.scenario.retrieve_mode, RetrieveMode::Body);
+                assert_eq!(config.zabbix.scenario.variables.len(), 2);
+                assert_eq!(config.zabbix.scenario.variables[0].name, "user");
+                assert_eq!(config.zabbix.scenario.variables[0].value, "monitor");
+                assert_eq!(config.zabbix.scenario.variables[1].name, "{lang}");
+
+                let proxy_scenario = config.zabbix.proxy_scenario.as_ref().unwrap();
+                assert_eq!(proxy_scenario.response_timeout, "15s");
+                assert_eq!(proxy_scenario.attempts, 5);
+                assert_eq!(proxy_scenario.update_interval, "10m");
+                assert_eq!(proxy_scenario.retrieve_mode, RetrieveMode::Headers);
+                assert_eq!(proxy_scenario.variables.len(), 2);
+
+                assert_eq!(config.dashboard.unwrap().name, "Websites");
+
+                assert_eq!(config.aggregate.unwrap().priority, 5);
+
+                let grace_config = config.grace.unwrap();
+                assert_eq!(grace_config.duration_secs, 7200);
+                assert_eq!(grace_config.name, "WSZL grace period");
+
+                let latency_config = config.latency.unwrap();
+                assert_eq!(latency_config.threshold_ms, 3000);
+                assert_eq!(latency_config.priority, 2);
+                assert_eq!(latency_config.rules.len(), 1);
+                assert_eq!(latency_config.rules[0].pattern, "/api/");
+                assert_eq!(latency_config.rules[0].threshold_ms, 500);
+
+                let http_proxy_config = config.http_proxy.unwrap();
+                assert_eq!(http_proxy_config.proxy, "http://egress.example.com:3128");
+                assert_eq!(http_proxy_config.rules.len(), 1);
+                assert_eq!(http_proxy_config.rules[0].pattern, "\\.internal(:\\d+)?/");
+                assert_eq!(http_proxy_config.rules[0].proxy, "");
+
+                let central_host_config = config.central_host.unwrap();
+                assert_eq!(central_host_config.host, "WebChecks");
+                assert_eq!(central_host_config.patterns, vec!["^https://shop\\."]);
+
+                assert_eq!(config.script_file.unwrap(), "/etc/zabbix/wszl.rhai");
+
+                let vault_config = config.vault.unwrap();
+                assert_eq!(vault_config.address, "https://vault.example.com:8200");
+                assert_eq!(vault_config.mount, "secret");
+                assert_eq!(vault_config.path, "monitoring/zabbix");
+                assert!(vault_config.token.is_none());
+                let approle_config = vault_config.approle.unwrap();
+                assert_eq!(approle_config.role_id, "wszl-role");
+                assert_eq!(approle_config.secret_id, "wszl-secret");
+                assert_eq!(vault_config.username_key, "username");
+                assert_eq!(vault_config.password_key, "zabbix-password");
+
+                assert_eq!(config.daemon.schedule, DaemonSchedule::Cron("*/10 * * * *".to_string()));
+                assert_eq!(config.daemon.jitter_secs, 90);
+
+                let syslog_config = config.syslog.unwrap();
+                assert_eq!(syslog_config.address, "udp://logs.example.com:514");
+                assert_eq!(syslog_config.facility, "local3");
+                assert_eq!(syslog_config.app_name, "wszl");
+                assert_eq!(syslog_config.level, "warn");
+
+                assert_eq!(config.templates.scenario_name, "Check index page '{{ url }}'");
+                assert_eq!(config.templates.trigger_comments, "Owner: {{ vars.team }}");
+                assert_eq!(config.templates.vars["team"], "web-team");
+                assert_eq!(config.templates.max_name_length, 48);
+
+                assert!(config.hooks.pre_create.is_none());
+                assert_eq!(config.hooks.post_create.unwrap(), "/usr/local/bin/cmdb-sync");
+                assert!(config.hooks.pre_delete.is_none());
+                assert_eq!(config.hooks.post_delete.unwrap(), "/usr/local/bin/cmdb-remove");
+                assert_eq!(config.hooks.timeout_secs, 120);
+
+                let services_config = config.services.unwrap();
+                assert_eq!(services_config.root, "Websites");
+                assert_eq!(services_config.group_by, ServiceGroupBy::Domain);
+                assert_eq!(services_config.sla, 99.5);
+
+                assert_eq!(config.zabbix.host_group.unwrap(), "Web monitored");
+                assert_eq!(config.zabbix.correlation_tag, Some("url".to_string()));
+
+                let graph_config = config.zabbix.graph.unwrap();
+                assert_eq!(graph_config.name, "Web performance '{url}' on {host}");
+                assert_eq!(graph_config.width, 900);
+                assert_eq!(graph_config.height, 200);
+
+                assert_eq!(config.metrics.textfile_path.unwrap(), "/var/lib/node_exporter/textfile/wszl.prom");
+                assert_eq!(config.metrics.pushgateway_url.unwrap(), "http://pushgateway:9091");
+                assert_eq!(config.metrics.job, "wszl");
+
+                assert_eq!(config.notifications.webhook_url.unwrap(), "http://alerts/hooks/wszl");
+
+                let slack_config = config.notifications.slack.unwrap();
+                assert_eq!(slack_config.webhook_url, "https://hooks.slack.com/services/T000/B000/XXXX");
+                assert_eq!(slack_config.notify_on, NotifyOn::Changes);
+
+                assert!(config.notifications.mattermost.is_none());
+
+                let telegram_config = config.notifications.telegram.unwrap();
+                assert_eq!(telegram_config.bot_token, "123456:ABCDEF");
+                assert_eq!(telegram_config.chat_id, "-1001234567890");
+                assert_eq!(telegram_config.notify_on, NotifyOn::Errors);
+
+                let email_config = config.notifications.email.unwrap();
+                assert_eq!(email_config.smtp_host, "smtp.example.com");
+                assert_eq!(email_config.smtp_port, 587);
+                assert_eq!(email_config.security, SmtpSecurity::StartTls);
+                assert!(email_config.username.is_none());
+                assert_eq!(email_config.from, "wszl@example.com");
+                assert_eq!(email_config.to, vec!["ops@example.com", "web-team@example.com"]);
+                assert_eq!(email_config.notify_on, NotifyOn::Errors);
+
+                let trapper_config = config.notifications.trapper.unwrap();
+                assert_eq!(trapper_config.server, "zabbix:10051");
+                assert_eq!(trapper_config.host, "wszl-runner");
+                assert_eq!(trapper_config.key, "wszl.run");
+
+                assert_eq!(config.audit.file_path.unwrap(), "/var/log/wszl/audit.log");
+
+                assert_eq!(config.tracing.otlp_endpoint.unwrap(), "http://otel-collector:4318");
+                assert_eq!(config.tracing.service_name, "wszl-test");
+
+                assert_eq!(config.processing.hosts_per_chunk, 50);
+                assert_eq!(config.processing.concurrency, 4);
+                assert!(!config.processing.skip_maintenance);
+                assert!(!config.processing.skip_disabled);
+                assert_eq!(config.processing.lock_file, "/run/wszl/wszl.lock");
+                assert_eq!(config.processing.state_file, "/var/lib/wszl/state.json");
+                assert!(config.processing.detect_renames);
+                assert_eq!(config.processing.max_consecutive_failures, 5);
+                assert_eq!(config.processing.max_errors, 100);
+                assert_eq!(config.processing.max_scenarios_per_host, 50);
+                assert_eq!(config.processing.max_scenarios_per_run, 500);
+
+                assert_eq!(config.items.patterns.len(), 2);
+
+                match &config.items.patterns[0] {
+                    ItemKeyPatternConfig::Mask(mask) => assert_eq!(mask, "vhost.item[*]"),
+                    _ => panic!("mask pattern expected")
+                }
+
+                match &config.items.patterns[1] {
+                    ItemKeyPatternConfig::Regex { regex, search } => {
+                        assert_eq!(regex, "^nginx\\.vhost\\[([^,]+),\\d+\\]$");
+                        assert_eq!(search, "nginx.vhost[");
+                    }
+                    _ => panic!("regex pattern expected")
+                }
+
+                assert!(config.items.skip_unsupported);
+                assert_eq!(config.items.stale_after_secs, Some(86400));
+                assert_eq!(config.items.tags, vec![
+                    ItemTagConfig { tag: "wszl".to_string(), value: Some("yes".to_string()) },
+                    ItemTagConfig { tag: "team".to_string(), value: None }
+                ]);
+
+                let lld_config = config.discovery.lld.unwrap();
+                assert_eq!(lld_config.item_key, "vhost.discovery");
+                assert_eq!(lld_config.url_macro, "{#URL}");
+                assert_eq!(config.discovery.urls_file.unwrap(), "/etc/zabbix/wszl-urls.yml");
+
+                let docker_config = config.discovery.docker.unwrap();
+                assert_eq!(docker_config.socket, "/var/run/docker.sock");
+                assert_eq!(docker_config.host, "docker-01");
+                assert_eq!(docker_config.scheme, "http");
+
+                let vhosts_config = config.discovery.vhosts.unwrap();
+                assert_eq!(vhosts_config.host, "web-01");
+                assert_eq!(vhosts_config.nginx_dirs, vec!["/etc/nginx/conf.d", "/etc/nginx/sites-enabled"]);
+                assert!(vhosts_config.apache_dirs.is_empty());
+
+                let probe_config = config.probe.unwrap();
+                assert!(probe_config.skip_unreachable);
+                assert_eq!(probe_config.timeout_secs, 5);
+
+                assert_eq!(config.urls.rewrites.len(), 1);
+                assert_eq!(config.urls.rewrites[0].search, "^www\\.");
+                assert_eq!(config.urls.rewrites[0].replace, "");
+                assert_eq!(config.urls.force_scheme.unwrap(), "https");
+                assert_eq!(config.urls.append_path.unwrap(), "/healthz");
+                assert!(config.urls.include.is_empty());
+                assert_eq!(config.urls.exclude, vec!["\\.local$"]);
+                assert_eq!(config.urls.dedup_scope, DedupScope::Global);
+                assert_eq!(config.urls.blocklist_file, "/etc/zabbix/wszl-blocklist.txt");
             }
             Err(_) => panic!("config should be loaded")
         }
     }
+
+    #[test]
+    fn durations_should_be_parsed() {
+        assert_eq!(parse_duration_secs("90"), Some(90));
+        assert_eq!(parse_duration_secs("90s"), Some(90));
+        assert_eq!(parse_duration_secs("30m"), Some(1800));
+        assert_eq!(parse_duration_secs("12h"), Some(43200));
+        assert_eq!(parse_duration_secs("7d"), Some(604800));
+        assert_eq!(parse_duration_secs("a week"), None);
+        assert_eq!(parse_duration_secs("18446744073709551615d"), None);
+    }
+
+    #[test]
+    fn latency_thresholds_should_be_parsed() {
+        assert_eq!(parse_threshold_ms("500ms"), Some(500));
+        assert_eq!(parse_threshold_ms("3s"), Some(3000));
+        assert_eq!(parse_threshold_ms("1.5"), Some(1500));
+        assert_eq!(parse_threshold_ms("fast"), None);
+    }
+
+    #[test]
+    fn config_should_be_searched_in_standard_locations() {
+        assert_eq!(get_config_file_locations(Some("/home/ops/.xdg".to_string()), Some("/home/ops".to_string())),
+                   vec![PathBuf::from("wszl.yml"), PathBuf::from("/home/ops/.xdg/wszl/wszl.yml"),
+                        PathBuf::from("/etc/wszl/wszl.yml")]);
+
+        assert_eq!(get_config_file_locations(None, Some("/home/ops".to_string())),
+                   vec![PathBuf::from("wszl.yml"), PathBuf::from("/home/ops/.config/wszl/wszl.yml"),
+                        PathBuf::from("/etc/wszl/wszl.yml")]);
+
+        assert_eq!(get_config_file_locations(None, None),
+                   vec![PathBuf::from("wszl.yml"), PathBuf::from("/etc/wszl/wszl.yml")]);
+    }
+
+    #[test]
+    fn config_files_of_directory_should_be_found_in_order() {
+        let config_dir = env::temp_dir().join(format!("wszl-test-conf.d-{}", std::process::id()));
+        fs::create_dir_all(&config_dir).unwrap();
+
+        for file_name in &["team-b.yaml", "team-a.yml", "README.md"] {
+            fs::write(config_dir.join(file_name), "").unwrap();
+        }
+
+        let config_files = find_config_files(&config_dir).unwrap();
+
+        fs::remove_dir_all(&config_dir).unwrap();
+
+        assert_eq!(config_files, vec![config_dir.join("team-a.yml"), config_dir.join("team-b.yaml")]);
+    }
+
+    #[test]
+    fn configs_of_the_same_endpoint_should_share_parallel_lane() {
+        let config_dir = env::temp_dir().join(format!("wszl-test-lanes-{}", std::process::id()));
+        fs::create_dir_all(&config_dir).unwrap();
+
+        let config = fs::read_to_string("tests/wszl.yml").unwrap();
+
+        fs::write(config_dir.join("team-a.yml"), &config).unwrap();
+        fs::write(config_dir.join("team-b.yml"), config.replace("http://zabbix/", "http://zabbix-us/")).unwrap();
+        fs::write(config_dir.join("team-c.yml"), &config).unwrap();
+        fs::write(config_dir.join("team-d.yml"), "zabbix: [").unwrap();
+        fs::write(config_dir.join("team-e.yml"), "zabbix: [").unwrap();
+
+        let api_endpoints: Vec<Option<String>> = find_config_files(&config_dir).unwrap().iter()
+            .map(|config_file| load_config_from_file(config_file).ok().map(|config| config.zabbix.api.endpoint))
+            .collect();
+
+        fs::remove_dir_all(&config_dir).unwrap();
+
+        assert_eq!(api_endpoints[0], api_endpoints[2]);
+        assert_eq!(get_parallel_lanes(&api_endpoints), vec![vec![0, 2], vec![1], vec![3], vec![4]]);
+    }
+
+    #[test]
+    fn overflowing_duration_should_be_rejected() {
+        let config_file = env::temp_dir().join(format!("wszl-test-duration-{}.yml", std::process::id()));
+
+        let config = fs::read_to_string("tests/wszl.yml").unwrap();
+        fs::write(&config_file, config.replace("stale-after: 24h", "stale-after: 999999999999999999d")).unwrap();
+
+        let result = load_config_from_file(&config_file);
+
+        fs::remove_file(&config_file).unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn name_length_above_zabbix_limit_should_be_rejected() {
+        let config_file = env::temp_dir().join(format!("wszl-test-name-length-{}.yml", std::process::id()));
+
+        let config = fs::read_to_string("tests/wszl.yml").unwrap();
+        fs::write(&config_file, config.replace("max-name-length: 48", "max-name-length: 65")).unwrap();
+
+        let result = load_config_from_file(&config_file);
+
+        fs::remove_file(&config_file).unwrap();
+
+        assert!(result.is_err());
+    }
 }
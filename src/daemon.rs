@@ -0,0 +1,115 @@
+pub mod daemon {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+    use std::str::FromStr;
+    use std::thread;
+    use std::time::Duration;
+
+    use chrono::{DateTime, Local, TimeZone};
+
+    use crate::config::config::{DaemonConfig, DaemonSchedule};
+    use crate::errors::errors::OperationError;
+    use crate::shutdown::shutdown::is_shutdown_requested;
+    use crate::systemd::systemd::{notify_ready, notify_status, notify_stopping, notify_watchdog};
+    use crate::types::types::OperationResult;
+
+    const SHUTDOWN_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+    pub enum RunSchedule {
+        Interval(Duration),
+        Cron(Box<cron::Schedule>)
+    }
+
+    pub fn get_run_schedule(daemon_config: &DaemonConfig) -> OperationResult<RunSchedule> {
+        match &daemon_config.schedule {
+            DaemonSchedule::Interval(secs) => Ok(RunSchedule::Interval(Duration::from_secs(*secs))),
+            DaemonSchedule::Cron(expression) => {
+                get_cron_schedule(expression).map(|schedule| RunSchedule::Cron(Box::new(schedule)))
+            }
+        }
+    }
+
+    /// Standard 5-field expression (minute hour day month weekday), 6 or 7 fields with seconds and year
+    pub fn get_cron_schedule(expression: &str) -> OperationResult<cron::Schedule> {
+        let expression = if expression.split_whitespace().count() == 5 {
+            format!("0 {}", expression)
+        } else { expression.to_string() };
+
+        match cron::Schedule::from_str(&expression) {
+            Ok(schedule) => Ok(schedule),
+            Err(e) => {
+                error!("invalid cron expression '{}': {}", expression, e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    pub fn get_next_run_delay<Tz: TimeZone>(schedule: &RunSchedule, now: &DateTime<Tz>) -> Duration {
+        match schedule {
+            RunSchedule::Interval(interval) => *interval,
+            RunSchedule::Cron(schedule) => {
+                schedule.after(now).next()
+                    .and_then(|next_run| (next_run - now.clone()).to_std().ok())
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    /// Random delay in `[0, max_secs]`, spreads runs of several wszl instances with the same schedule
+    pub fn get_jitter(max_secs: u64) -> Duration {
+        if max_secs == 0 {
+            return Duration::from_secs(0)
+        }
+
+        let random = RandomState::new().build_hasher().finish();
+        Duration::from_millis(random % (max_secs * 1000 + 1))
+    }
+
+    /// Calls `run` on schedule until termination signal. First run starts immediately.
+    /// systemd watchdog is notified between runs, so a run must finish within `WatchdogSec`.
+    pub fn run_daemon<F: FnMut()>(schedule: &RunSchedule, jitter_secs: u64, mut run: F) {
+        info!("daemon has been started");
+        notify_ready();
+
+        loop {
+            notify_status("running");
+            run();
+            notify_watchdog();
+
+            if is_shutdown_requested() {
+                break;
+            }
+
+            let delay = get_next_run_delay(schedule, &Local::now()) + get_jitter(jitter_secs);
+
+            info!("next run in {}s", delay.as_secs());
+            notify_status(&format!("next run in {}s", delay.as_secs()));
+
+            if !wait(delay) {
+                break;
+            }
+        }
+
+        notify_stopping();
+        info!("daemon has been stopped");
+    }
+
+    /// Returns `false` if shutdown has been requested while waiting
+    fn wait(delay: Duration) -> bool {
+        let mut remaining = delay;
+
+        while remaining > Duration::from_secs(0) {
+            if is_shutdown_requested() {
+                return false
+            }
+
+            let step = remaining.min(SHUTDOWN_CHECK_INTERVAL);
+            thread::sleep(step);
+            remaining -= step;
+
+            notify_watchdog();
+        }
+
+        !is_shutdown_requested()
+    }
+}
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod daemon_tests {
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::daemon::daemon::{get_cron_schedule, get_jitter, get_next_run_delay, RunSchedule};
+
+    #[test]
+    fn next_cron_run_should_be_at_schedule_slot() {
+        let schedule = RunSchedule::Cron(Box::new(get_cron_schedule("*/10 * * * *").unwrap()));
+
+        let now = Utc.with_ymd_and_hms(2021, 3, 1, 12, 3, 30).unwrap();
+
+        assert_eq!(get_next_run_delay(&schedule, &now), Duration::from_secs(390));
+    }
+
+    #[test]
+    fn interval_should_be_delay_after_run() {
+        let schedule = RunSchedule::Interval(Duration::from_secs(1800));
+
+        let now = Utc.with_ymd_and_hms(2021, 3, 1, 12, 3, 30).unwrap();
+
+        assert_eq!(get_next_run_delay(&schedule, &now), Duration::from_secs(1800));
+    }
+
+    #[test]
+    fn invalid_cron_expression_should_be_error() {
+        assert!(get_cron_schedule("every ten minutes").is_err());
+    }
+
+    #[test]
+    fn jitter_should_not_exceed_max_value() {
+        assert_eq!(get_jitter(0), Duration::from_secs(0));
+
+        for _ in 0..100 {
+            assert!(get_jitter(30) <= Duration::from_secs(30));
+        }
+    }
+}
@@ -0,0 +1,240 @@
+pub mod dashboard {
+    use reqwest::blocking::Client;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    use crate::config::config::{Config, DashboardConfig};
+    use crate::errors::errors::OperationError;
+    use crate::http::http::send_post_request;
+    use crate::session::session::{close_api_session, open_api_session};
+    use crate::types::types::{EmptyResult, OperationResult};
+    use crate::webscenarios::webscenarios::{find_managed_web_scenarios, ZabbixManagedWebScenario};
+    use crate::zabbix::zabbix;
+    use crate::zabbix::zabbix::{log_zabbix_error, MANAGED_TAG_NAME, MANAGED_TAG_VALUE, ZabbixError,
+                                ZabbixRequest};
+
+    /// Widget field types
+    const FIELD_TYPE_INTEGER: u8 = 0;
+    const FIELD_TYPE_STRING: u8 = 1;
+
+    /// Dashboard grid width (Zabbix 5.4+)
+    const DASHBOARD_WIDTH: u32 = 24;
+
+    const RESPONSE_TIME_ITEM_PATTERN: &str = "Response time for step *";
+
+    #[derive(Serialize)]
+    pub struct DashboardWidget {
+        #[serde(rename = "type")]
+        pub widget_type: String,
+        pub name: String,
+        pub x: u32,
+        pub y: u32,
+        pub width: u32,
+        pub height: u32,
+        pub fields: Vec<WidgetField>
+    }
+
+    #[derive(Serialize)]
+    pub struct WidgetField {
+        #[serde(rename = "type")]
+        pub field_type: u8,
+        pub name: String,
+        pub value: String
+    }
+
+    #[derive(Serialize)]
+    struct DashboardPage {
+        widgets: Vec<DashboardWidget>
+    }
+
+    #[derive(Serialize)]
+    struct CreateDashboardParams {
+        name: String,
+        pages: Vec<DashboardPage>
+    }
+
+    #[derive(Serialize)]
+    struct UpdateDashboardParams {
+        dashboardid: String,
+        pages: Vec<DashboardPage>
+    }
+
+    #[derive(Serialize)]
+    struct GetDashboardParams {
+        output: Vec<String>,
+        filter: DashboardFilter
+    }
+
+    #[derive(Serialize)]
+    struct DashboardFilter {
+        name: String
+    }
+
+    #[derive(Deserialize)]
+    struct ZabbixDashboard {
+        dashboardid: String
+    }
+
+    #[derive(Deserialize)]
+    struct GetDashboardResponse {
+        result: Option<Vec<ZabbixDashboard>>,
+        error: Option<ZabbixError>
+    }
+
+    #[derive(Deserialize)]
+    struct SaveDashboardResponse {
+        error: Option<ZabbixError>
+    }
+
+    /// Creates dashboard with web monitoring widgets or replaces widgets of existing one (found by name)
+    pub fn update_dashboard(client: &Client, config: &Config, dashboard_config: &DashboardConfig) -> EmptyResult {
+        let api_endpoint = &config.zabbix.api.endpoint;
+
+        match open_api_session(client, &config.zabbix.api) {
+            Ok(auth_token) => {
+                let result = find_managed_web_scenarios(client, api_endpoint, &auth_token)
+                    .and_then(|web_scenarios| {
+                        let widgets = get_dashboard_widgets(&get_managed_host_names(&web_scenarios));
+
+                        match find_dashboard_id(client, api_endpoint, &auth_token, &dashboard_config.name)? {
+                            Some(dashboardid) => {
+                                let params = UpdateDashboardParams {
+                                    dashboardid, pages: vec![DashboardPage { widgets }]
+                                };
+                                save_dashboard(client, api_endpoint, &auth_token, "dashboard.update", params)
+                            }
+                            None => {
+                                let params = CreateDashboardParams {
+                                    name: dashboard_config.name.to_string(),
+                                    pages: vec![DashboardPage { widgets }]
+                                };
+                                save_dashboard(client, api_endpoint, &auth_token, "dashboard.create", params)
+                            }
+                        }
+                    });
+
+                if close_api_session(client, &config.zabbix.api, &auth_token).is_err() {
+                    warn!("unable to close api session");
+                }
+
+                if result.is_ok() {
+                    info!("dashboard '{}' has been updated", dashboard_config.name);
+                }
+
+                result
+            }
+            Err(_) => {
+                error!("unable to login");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Hosts with managed web scenarios, sorted and without duplicates
+    pub fn get_managed_host_names(web_scenarios: &[ZabbixManagedWebScenario]) -> Vec<String> {
+        let mut host_names: Vec<String> = web_scenarios.iter()
+            .flat_map(|web_scenario| web_scenario.hosts.iter().map(|host| host.host.to_string()))
+            .collect();
+
+        host_names.sort();
+        host_names.dedup();
+        host_names
+    }
+
+    /// Problems of managed triggers and response time of managed scenarios on given hosts
+    pub fn get_dashboard_widgets(host_names: &[String]) -> Vec<DashboardWidget> {
+        let problems_widget = DashboardWidget {
+            widget_type: "problems".to_string(),
+            name: "Web monitoring problems".to_string(),
+            x: 0,
+            y: 0,
+            width: DASHBOARD_WIDTH,
+            height: 6,
+            fields: vec![
+                get_field(FIELD_TYPE_STRING, "tags.tag.0", MANAGED_TAG_NAME),
+                get_field(FIELD_TYPE_INTEGER, "tags.operator.0", "1"),
+                get_field(FIELD_TYPE_STRING, "tags.value.0", MANAGED_TAG_VALUE),
+                get_field(FIELD_TYPE_INTEGER, "show_lines", "25")
+            ]
+        };
+
+        let mut response_time_fields: Vec<WidgetField> = host_names.iter().enumerate()
+            .map(|(index, host_name)| get_field(FIELD_TYPE_STRING, &format!("ds.hosts.0.{}", index), host_name))
+            .collect();
+
+        response_time_fields.push(get_field(FIELD_TYPE_STRING, "ds.items.0.0", RESPONSE_TIME_ITEM_PATTERN));
+
+        let response_time_widget = DashboardWidget {
+            widget_type: "svggraph".to_string(),
+            name: "Response time of web pages".to_string(),
+            x: 0,
+            y: 6,
+            width: DASHBOARD_WIDTH,
+            height: 8,
+            fields: response_time_fields
+        };
+
+        vec![problems_widget, response_time_widget]
+    }
+
+    fn get_field(field_type: u8, name: &str, value: &str) -> WidgetField {
+        WidgetField { field_type, name: name.to_string(), value: value.to_string() }
+    }
+
+    fn find_dashboard_id(client: &Client, api_endpoint: &str, auth_token: &str,
+                         name: &str) -> OperationResult<Option<String>> {
+        let params = GetDashboardParams {
+            output: vec!["dashboardid".to_string()],
+            filter: DashboardFilter { name: name.to_string() }
+        };
+
+        let request: ZabbixRequest<GetDashboardParams> = ZabbixRequest::new(
+            "dashboard.get", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: GetDashboardResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match search_response.result {
+                    Some(dashboards) => Ok(dashboards.into_iter().next().map(|dashboard| dashboard.dashboardid)),
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        error!("unable to find dashboard '{}'", name);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find dashboard '{}'", name);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn save_dashboard<P: Serialize>(client: &Client, api_endpoint: &str, auth_token: &str,
+                                    method: &str, params: P) -> EmptyResult {
+        let request: ZabbixRequest<P> = ZabbixRequest::new(method, params, auth_token);
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let save_response: SaveDashboardResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match save_response.error {
+                    Some(_) => {
+                        log_zabbix_error(&save_response.error);
+                        error!("unable to save dashboard");
+                        Err(OperationError::Error)
+                    }
+                    None => Ok(())
+                }
+            }
+            Err(_) => {
+                error!("unable to save dashboard");
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
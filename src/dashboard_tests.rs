@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod dashboard_tests {
+    use crate::dashboard::dashboard::{get_dashboard_widgets, get_managed_host_names};
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::webscenarios::webscenarios::ZabbixManagedWebScenario;
+
+    fn get_web_scenario(host: &str) -> ZabbixManagedWebScenario {
+        ZabbixManagedWebScenario {
+            httptestid: "301".to_string(),
+            name: "Check index page 'https://example.com'".to_string(),
+            status: "0".to_string(),
+            hosts: vec![ZabbixHost { hostid: "10001".to_string(), host: host.to_string(),
+                                     maintenance_status: "0".to_string(), status: "0".to_string(),
+                                     proxy_hostid: "0".to_string() }],
+            steps: vec![]
+        }
+    }
+
+    #[test]
+    fn managed_hosts_should_be_sorted_and_unique() {
+        let web_scenarios = vec![get_web_scenario("web-02"), get_web_scenario("web-01"), get_web_scenario("web-02")];
+
+        assert_eq!(get_managed_host_names(&web_scenarios), vec!["web-01", "web-02"]);
+    }
+
+    #[test]
+    fn problems_should_be_filtered_by_managed_tag() {
+        let widgets = get_dashboard_widgets(&["web-01".to_string(), "web-02".to_string()]);
+
+        assert_eq!(widgets.len(), 2);
+
+        let problems_widget = &widgets[0];
+        assert_eq!(problems_widget.widget_type, "problems");
+
+        let tag_fields: Vec<(&str, &str)> = problems_widget.fields.iter()
+            .map(|field| (field.name.as_str(), field.value.as_str())).collect();
+
+        assert!(tag_fields.contains(&("tags.tag.0", "managed-by")));
+        assert!(tag_fields.contains(&("tags.value.0", "wszl")));
+    }
+
+    #[test]
+    fn response_time_graph_should_cover_managed_hosts() {
+        let widgets = get_dashboard_widgets(&["web-01".to_string(), "web-02".to_string()]);
+
+        let graph_widget = &widgets[1];
+        assert_eq!(graph_widget.widget_type, "svggraph");
+        assert_eq!(graph_widget.fields[0].name, "ds.hosts.0.0");
+        assert_eq!(graph_widget.fields[0].value, "web-01");
+        assert_eq!(graph_widget.fields[1].name, "ds.hosts.0.1");
+        assert_eq!(graph_widget.fields[1].value, "web-02");
+        assert_eq!(graph_widget.fields[2].name, "ds.items.0.0");
+    }
+}
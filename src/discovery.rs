@@ -0,0 +1,284 @@
+pub mod discovery {
+    use std::collections::HashMap;
+
+    use serde_json::Value;
+
+    use crate::config::config::DedupScope;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::itemoverrides::itemoverrides::get_item_overrides;
+    use crate::items::items::{ZabbixItem, ZabbixLldDataItem};
+    use crate::patterns::patterns::{capture_url, ItemKeyPattern};
+    use crate::report::report::{ItemOutcome, ItemStatus};
+    use crate::scripting::scripting::UrlOverrides;
+    use crate::urlcheck::urlcheck::normalize_url;
+
+    const ITEM_STATE_NOT_SUPPORTED: &str = "1";
+
+    /// Url discovered for a host, `source` describes where it came from (item name, etc.)
+    #[derive(Clone)]
+    pub struct DiscoveredUrl {
+        pub source: String,
+        /// Key of the item url was taken from, empty for urls from outside of Zabbix
+        pub key: String,
+        /// Id of the item url was taken from, empty for urls from outside of Zabbix
+        pub itemid: String,
+        pub hostid: String,
+        pub url: String,
+        /// Overrides carried by the item (tags or description)
+        pub overrides: UrlOverrides,
+        /// Named groups of item key pattern besides url
+        pub groups: HashMap<String, String>
+    }
+
+    /// Url from a source outside of Zabbix, host is referenced by technical name
+    pub struct ExternalUrl {
+        pub source: String,
+        pub host: String,
+        pub url: String
+    }
+
+    /// Maps external urls to Zabbix hosts. Urls of unknown hosts are returned as failures.
+    pub fn resolve_external_urls(urls: &[ExternalUrl], hosts: &[ZabbixHost]) ->
+                                                    (Vec<DiscoveredUrl>, Vec<ItemOutcome>) {
+        let mut resolved_urls = vec![];
+        let mut failures = vec![];
+
+        for external_url in urls {
+            match hosts.iter().find(|host| host.host == external_url.host) {
+                Some(host) => resolved_urls.push(DiscoveredUrl {
+                    source: external_url.source.to_string(),
+                    key: String::new(),
+                    itemid: String::new(),
+                    hostid: host.hostid.to_string(),
+                    url: external_url.url.to_string(),
+                    overrides: UrlOverrides::default(),
+                    groups: HashMap::new()
+                }),
+                None => {
+                    let mut outcome = ItemOutcome::new(&external_url.source, &external_url.host);
+                    outcome.url = external_url.url.to_string();
+                    outcome.fail(&format!("host '{}' wasn't found", external_url.host));
+                    failures.push(outcome);
+                }
+            }
+        }
+
+        (resolved_urls, failures)
+    }
+
+    /// Urls captured from item keys. Items which don't match any pattern are returned as failures.
+    pub fn get_urls_from_items(items: &[ZabbixItem], patterns: &[ItemKeyPattern]) ->
+                                                    (Vec<DiscoveredUrl>, Vec<ItemOutcome>) {
+        let mut urls = vec![];
+        let mut failures = vec![];
+
+        for item in items {
+            match capture_url(patterns, &item.key_) {
+                Some(captured_url) => match get_item_overrides(&item.description, &item.tags) {
+                    Ok(overrides) => urls.push(DiscoveredUrl {
+                        source: item.name.to_string(),
+                        key: item.key_.to_string(),
+                        itemid: item.itemid.to_string(),
+                        hostid: item.hostid.to_string(),
+                        url: captured_url.url,
+                        overrides,
+                        groups: captured_url.groups
+                    }),
+                    Err(reason) => {
+                        let mut outcome = ItemOutcome::new(&item.name, &item.hostid);
+                        outcome.itemid = item.itemid.to_string();
+                        outcome.url = captured_url.url;
+                        outcome.fail(&format!("invalid overrides of item '{}': {}", item.name, reason));
+                        failures.push(outcome);
+                    }
+                },
+                None => {
+                    let mut outcome = ItemOutcome::new(&item.name, &item.hostid);
+                    outcome.itemid = item.itemid.to_string();
+                    outcome.fail("unsupported item format");
+                    failures.push(outcome);
+                }
+            }
+        }
+
+        (urls, failures)
+    }
+
+    /// Validated and normalized urls (see `normalize_url`), invalid ones are returned as failures.
+    pub fn normalize_urls(urls: Vec<DiscoveredUrl>) -> (Vec<DiscoveredUrl>, Vec<ItemOutcome>) {
+        let mut valid_urls = vec![];
+        let mut failures = vec![];
+
+        for mut discovered_url in urls {
+            match normalize_url(&discovered_url.url) {
+                Ok(url) => {
+                    discovered_url.url = url;
+                    valid_urls.push(discovered_url);
+                }
+                Err(reason) => {
+                    warn!("invalid url '{}' of item '{}': {}", discovered_url.url, discovered_url.source, reason);
+                    let mut outcome = ItemOutcome::new(&discovered_url.source, &discovered_url.hostid);
+                    outcome.itemid = discovered_url.itemid;
+                    outcome.url = discovered_url.url;
+                    outcome.fail(&format!("invalid url: {}", reason));
+                    failures.push(outcome);
+                }
+            }
+        }
+
+        (valid_urls, failures)
+    }
+
+    /// Urls from low level discovery data (last value of item with LLD JSON).
+    pub fn get_urls_from_lld_items(items: &[ZabbixLldDataItem], url_macro: &str) ->
+                                                    (Vec<DiscoveredUrl>, Vec<ItemOutcome>) {
+        let mut urls = vec![];
+        let mut failures = vec![];
+
+        for item in items {
+            match get_macro_values(&item.lastvalue, url_macro) {
+                Some(values) => {
+                    debug!("item '{}' discovery data has {} urls", item.name, values.len());
+
+                    for url in values {
+                        urls.push(DiscoveredUrl {
+                            source: item.name.to_string(),
+                            key: item.key_.to_string(),
+                            itemid: item.itemid.to_string(),
+                            hostid: item.hostid.to_string(),
+                            url,
+                            overrides: UrlOverrides::default(),
+                            groups: HashMap::new()
+                        });
+                    }
+                }
+                None => {
+                    let mut outcome = ItemOutcome::new(&item.name, &item.hostid);
+                    outcome.itemid = item.itemid.to_string();
+                    outcome.fail("unsupported discovery data format");
+                    failures.push(outcome);
+                }
+            }
+        }
+
+        (urls, failures)
+    }
+
+    /// Which items can't be trusted as url source
+    pub struct StaleItemPolicy {
+        pub skip_unsupported: bool,
+        pub max_age_secs: Option<u64>,
+        /// Current unix time
+        pub now: u64
+    }
+
+    /// Reason why item data is stale, `None` for healthy item
+    pub fn get_stale_reason(state: &str, lastclock: &str, policy: &StaleItemPolicy) -> Option<String> {
+        if policy.skip_unsupported && state == ITEM_STATE_NOT_SUPPORTED {
+            return Some("item isn't supported".to_string())
+        }
+
+        if let Some(max_age_secs) = policy.max_age_secs {
+            let lastclock = lastclock.parse::<u64>().unwrap_or(0);
+
+            if lastclock == 0 {
+                return Some("item has no data".to_string())
+            }
+
+            let age_secs = policy.now.saturating_sub(lastclock);
+
+            if age_secs > max_age_secs {
+                return Some(format!("item hasn't been updated for {}s", age_secs))
+            }
+        }
+
+        None
+    }
+
+    /// Zabbix item ids grow, so items created after the item with `since_item_id` have higher ids.
+    /// Items have no change time to filter by, changed items aren't recognized. Unknown id is treated as new.
+    pub fn is_new_item(itemid: &str, since_item_id: u64) -> bool {
+        itemid.parse::<u64>().map(|itemid| itemid > since_item_id).unwrap_or(true)
+    }
+
+    pub fn get_last_item_id<'a>(item_ids: impl Iterator<Item = &'a str>) -> Option<u64> {
+        item_ids.filter_map(|itemid| itemid.parse::<u64>().ok()).max()
+    }
+
+    /// Splits items to healthy ones and outcomes of stale items.
+    /// `get_properties` returns id, name, host id, state and last clock of item.
+    pub fn skip_stale_items<T>(items: Vec<T>, policy: &StaleItemPolicy,
+                               get_properties: impl Fn(&T) -> (&str, &str, &str, &str, &str)) ->
+                                                                                    (Vec<T>, Vec<ItemOutcome>) {
+        let mut healthy_items = vec![];
+        let mut stale_items = vec![];
+
+        for item in items {
+            let (itemid, name, hostid, state, lastclock) = get_properties(&item);
+
+            match get_stale_reason(state, lastclock, policy) {
+                Some(reason) => {
+                    warn!("item '{}' is skipped: {}", name, reason);
+                    let mut outcome = ItemOutcome::new(name, hostid);
+                    outcome.itemid = itemid.to_string();
+                    outcome.status = ItemStatus::Stale(reason);
+                    stale_items.push(outcome);
+                }
+                None => healthy_items.push(item)
+            }
+        }
+
+        (healthy_items, stale_items)
+    }
+
+    /// Keeps the first occurrence of every url within scope, the rest are returned as duplicates.
+    /// `seen_urls` holds already taken urls (with their sources) between calls.
+    pub fn deduplicate_urls(urls: Vec<DiscoveredUrl>, scope: &DedupScope,
+                            seen_urls: &mut HashMap<String, String>) -> (Vec<DiscoveredUrl>, Vec<ItemOutcome>) {
+        let mut unique_urls = vec![];
+        let mut duplicates = vec![];
+
+        for discovered_url in urls {
+            let key = match scope {
+                DedupScope::Host => format!("{} {}", discovered_url.hostid, discovered_url.url),
+                DedupScope::Global => discovered_url.url.to_string()
+            };
+
+            match seen_urls.get(&key) {
+                Some(first_source) => {
+                    debug!("url '{}' of item '{}' is a duplicate, skip", discovered_url.url, discovered_url.source);
+                    let mut outcome = ItemOutcome::new(&discovered_url.source, &discovered_url.hostid);
+                    outcome.itemid = discovered_url.itemid.to_string();
+                    outcome.url = discovered_url.url.to_string();
+                    outcome.status = ItemStatus::Duplicate(first_source.to_string());
+                    duplicates.push(outcome);
+                }
+                None => {
+                    seen_urls.insert(key, discovered_url.source.to_string());
+                    unique_urls.push(discovered_url);
+                }
+            }
+        }
+
+        (unique_urls, duplicates)
+    }
+
+    /// Supports both `{"data":[...]}` and plain array formats of LLD JSON.
+    /// Rows without macro are ignored.
+    pub fn get_macro_values(lld_json: &str, macro_name: &str) -> Option<Vec<String>> {
+        let value: Value = serde_json::from_str(lld_json).ok()?;
+
+        let rows = match &value {
+            Value::Array(rows) => rows,
+            Value::Object(_) => value["data"].as_array()?,
+            _ => return None
+        };
+
+        Some(
+            rows.iter()
+                .filter_map(|row| row[macro_name].as_str())
+                .map(|macro_value| macro_value.to_string())
+                .collect()
+        )
+    }
+}
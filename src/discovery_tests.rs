@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod discovery_tests {
+    use std::collections::HashMap;
+
+    use crate::config::config::DedupScope;
+    use crate::discovery::discovery::{deduplicate_urls, DiscoveredUrl, get_last_item_id, get_macro_values,
+                                      get_stale_reason, is_new_item, normalize_urls, StaleItemPolicy};
+    use crate::scripting::scripting::UrlOverrides;
+
+    #[test]
+    fn macro_values_should_be_extracted_from_data_object() {
+        let json = r#"{"data":[{"{#URL}":"https://a.example.com"},{"{#URL}":"https://b.example.com"}]}"#;
+
+        let values = get_macro_values(json, "{#URL}").unwrap();
+
+        assert_eq!(values, vec!["https://a.example.com", "https://b.example.com"]);
+    }
+
+    #[test]
+    fn macro_values_should_be_extracted_from_plain_array() {
+        let json = r#"[{"{#URL}":"https://a.example.com","{#PORT}":"443"},{"{#NAME}":"b"}]"#;
+
+        let values = get_macro_values(json, "{#URL}").unwrap();
+
+        assert_eq!(values, vec!["https://a.example.com"]);
+    }
+
+    #[test]
+    fn invalid_discovery_data_should_be_rejected() {
+        assert!(get_macro_values("not a json", "{#URL}").is_none());
+        assert!(get_macro_values(r#"{"rows":[]}"#, "{#URL}").is_none());
+    }
+
+    fn get_url(source: &str, hostid: &str, url: &str) -> DiscoveredUrl {
+        DiscoveredUrl { source: source.to_string(), key: String::new(), itemid: String::new(),
+                        hostid: hostid.to_string(), url: url.to_string(), overrides: UrlOverrides::default(),
+                        groups: HashMap::new() }
+    }
+
+    #[test]
+    fn invalid_urls_should_be_returned_as_failures() {
+        let urls = vec![
+            get_url("item 1", "10001", "2001:db8::1"),
+            get_url("item 2", "10001", "example.com:99999")
+        ];
+
+        let (valid_urls, failures) = normalize_urls(urls);
+
+        assert_eq!(valid_urls.len(), 1);
+        assert_eq!(valid_urls[0].url, "[2001:db8::1]");
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].item_name, "item 2");
+        assert_eq!(failures[0].url, "example.com:99999");
+    }
+
+    #[test]
+    fn same_url_on_same_host_should_be_deduplicated() {
+        let urls = vec![
+            get_url("item 1", "10001", "example.com"),
+            get_url("item 2", "10001", "example.com"),
+            get_url("item 3", "10002", "example.com")
+        ];
+
+        let (unique_urls, duplicates) = deduplicate_urls(urls, &DedupScope::Host, &mut HashMap::new());
+
+        assert_eq!(unique_urls.len(), 2);
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].item_name, "item 2");
+    }
+
+    #[test]
+    fn same_url_on_different_hosts_should_be_deduplicated_in_global_scope() {
+        let mut seen_urls = HashMap::new();
+
+        let (unique_urls, _) = deduplicate_urls(vec![get_url("item 1", "10001", "example.com")],
+                                                &DedupScope::Global, &mut seen_urls);
+        assert_eq!(unique_urls.len(), 1);
+
+        let (unique_urls, duplicates) = deduplicate_urls(vec![get_url("item 3", "10002", "example.com")],
+                                                         &DedupScope::Global, &mut seen_urls);
+        assert!(unique_urls.is_empty());
+        assert_eq!(duplicates.len(), 1);
+    }
+
+    #[test]
+    fn unsupported_and_outdated_items_should_be_stale() {
+        let policy = StaleItemPolicy { skip_unsupported: true, max_age_secs: Some(3600), now: 10000 };
+
+        assert!(get_stale_reason("0", "9000", &policy).is_none());
+        assert_eq!(get_stale_reason("1", "9000", &policy).unwrap(), "item isn't supported");
+        assert_eq!(get_stale_reason("0", "5000", &policy).unwrap(), "item hasn't been updated for 5000s");
+        assert_eq!(get_stale_reason("0", "0", &policy).unwrap(), "item has no data");
+
+        let policy = StaleItemPolicy { skip_unsupported: false, max_age_secs: None, now: 10000 };
+
+        assert!(get_stale_reason("1", "0", &policy).is_none());
+    }
+
+    #[test]
+    fn items_with_higher_id_should_be_new() {
+        assert!(is_new_item("101", 100));
+        assert!(!is_new_item("100", 100));
+        assert!(!is_new_item("99", 100));
+        assert!(is_new_item("", 100));
+
+        assert_eq!(get_last_item_id(vec!["99", "1000", "", "101"].into_iter()), Some(1000));
+        assert_eq!(get_last_item_id(vec![""].into_iter()), None);
+    }
+}
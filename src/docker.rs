@@ -0,0 +1,146 @@
+pub mod docker {
+    use std::collections::BTreeMap;
+
+    use regex::Regex;
+    use serde::Deserialize;
+
+    use crate::config::config::DockerDiscoveryConfig;
+    use crate::discovery::discovery::ExternalUrl;
+    use crate::errors::errors::OperationError;
+    use crate::types::types::OperationResult;
+
+    const CONTAINERS_PATH: &str = "/containers/json";
+
+    const TRAEFIK_ROUTER_LABEL_PREFIX: &str = "traefik.http.routers.";
+    const TRAEFIK_RULE_LABEL_SUFFIX: &str = ".rule";
+    const TRAEFIK_TLS_LABEL_SUFFIX: &str = ".tls";
+
+    #[derive(Deserialize)]
+    struct DockerContainer {
+        #[serde(rename = "Names")]
+        names: Vec<String>,
+        #[serde(rename = "Labels")]
+        labels: Option<BTreeMap<String, String>>
+    }
+
+    /// Urls from Traefik router rules (`Host(...)`) of running containers,
+    /// all of them are mapped to Zabbix host from config.
+    pub fn get_urls_from_docker(docker_config: &DockerDiscoveryConfig) ->
+                                                            OperationResult<Vec<ExternalUrl>> {
+        info!("searching urls in docker container labels..");
+
+        let response = send_docker_request(&docker_config.socket, CONTAINERS_PATH)?;
+
+        match serde_json::from_str::<Vec<DockerContainer>>(&response) {
+            Ok(containers) => {
+                let mut urls = vec![];
+
+                for container in containers {
+                    let container_name = container.names.first()
+                                            .map(|name| name.trim_start_matches('/').to_string())
+                                            .unwrap_or_default();
+
+                    if let Some(labels) = &container.labels {
+                        for url in get_urls_from_traefik_labels(labels, &docker_config.scheme) {
+                            debug!("container '{}', url '{}'", container_name, url);
+
+                            urls.push(ExternalUrl {
+                                source: format!("docker container '{}'", container_name),
+                                host: docker_config.host.to_string(),
+                                url
+                            });
+                        }
+                    }
+                }
+
+                info!("urls found in docker labels: {}", urls.len());
+
+                Ok(urls)
+            }
+            Err(e) => {
+                error!("unsupported docker api response: {}", e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Router with `tls=true` label gets https scheme, others get default one.
+    pub fn get_urls_from_traefik_labels(labels: &BTreeMap<String, String>,
+                                        default_scheme: &str) -> Vec<String> {
+        let host_rule_pattern = Regex::new(r"Host\(([^)]*)\)").unwrap();
+        let host_name_pattern = Regex::new(r"`([^`]+)`").unwrap();
+
+        let mut urls = vec![];
+
+        for (label, rule) in labels {
+            if !label.starts_with(TRAEFIK_ROUTER_LABEL_PREFIX) || !label.ends_with(TRAEFIK_RULE_LABEL_SUFFIX) {
+                continue;
+            }
+
+            let router = &label[..label.len() - TRAEFIK_RULE_LABEL_SUFFIX.len()];
+
+            let tls_label = format!("{}{}", router, TRAEFIK_TLS_LABEL_SUFFIX);
+
+            let scheme = match labels.get(&tls_label).map(|value| value.as_str()) {
+                Some("true") => "https",
+                _ => default_scheme
+            };
+
+            for host_rule in host_rule_pattern.captures_iter(rule) {
+                for host_name in host_name_pattern.captures_iter(&host_rule[1]) {
+                    let url = format!("{}://{}", scheme, &host_name[1]);
+
+                    if !urls.contains(&url) {
+                        urls.push(url);
+                    }
+                }
+            }
+        }
+
+        urls
+    }
+
+    /// Minimal HTTP/1.0 client for Docker Engine API on unix socket.
+    #[cfg(unix)]
+    fn send_docker_request(socket_path: &str, path: &str) -> OperationResult<String> {
+        use std::io::{Read, Write};
+        use std::os::unix::net::UnixStream;
+
+        debug!("send request '{}' to docker socket '{}'", path, socket_path);
+
+        let mut stream = UnixStream::connect(socket_path)?;
+
+        stream.write_all(format!("GET {} HTTP/1.0\r\nHost: docker\r\n\r\n", path).as_bytes())?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+
+        get_http_response_body(&response)
+    }
+
+    #[cfg(not(unix))]
+    fn send_docker_request(_socket_path: &str, _path: &str) -> OperationResult<String> {
+        error!("docker socket discovery is supported on unix systems only");
+        Err(OperationError::Error)
+    }
+
+    pub fn get_http_response_body(response: &str) -> OperationResult<String> {
+        match response.split_once("\r\n\r\n") {
+            Some((headers, body)) => {
+                let status_line = headers.lines().next().unwrap_or_default();
+
+                if status_line.split_whitespace().nth(1) == Some("200") {
+                    Ok(body.to_string())
+
+                } else {
+                    error!("unexpected docker api response '{}'", status_line);
+                    Err(OperationError::Error)
+                }
+            }
+            None => {
+                error!("unsupported docker api response");
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
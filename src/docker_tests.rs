@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod docker_tests {
+    use std::collections::BTreeMap;
+
+    use crate::docker::docker::{get_http_response_body, get_urls_from_traefik_labels};
+
+    #[test]
+    fn urls_should_be_taken_from_traefik_host_rules() {
+        let mut labels = BTreeMap::new();
+        labels.insert("traefik.http.routers.shop.rule".to_string(),
+                      "Host(`shop.example.com`, `www.shop.example.com`) && PathPrefix(`/`)".to_string());
+        labels.insert("traefik.http.routers.shop.tls".to_string(), "true".to_string());
+        labels.insert("traefik.http.routers.api.rule".to_string(), "Host(`api.example.com`)".to_string());
+        labels.insert("com.docker.compose.service".to_string(), "shop".to_string());
+
+        let urls = get_urls_from_traefik_labels(&labels, "http");
+
+        assert_eq!(urls, vec![
+            "http://api.example.com", "https://shop.example.com", "https://www.shop.example.com"
+        ]);
+    }
+
+    #[test]
+    fn body_should_be_returned_for_successful_response() {
+        let response = "HTTP/1.0 200 OK\r\nContent-Type: application/json\r\n\r\n[]";
+
+        assert_eq!(get_http_response_body(response).unwrap(), "[]");
+        assert!(get_http_response_body("HTTP/1.0 404 Not Found\r\n\r\n{}").is_err());
+    }
+}
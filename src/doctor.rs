@@ -0,0 +1,226 @@
+pub mod doctor {
+    use reqwest::blocking::Client;
+    use serde::{Deserialize, Serialize};
+
+    use crate::auth::auth::get_session_user_type;
+    use crate::config::config::Config;
+    use crate::errors::errors::OperationError;
+    use crate::hosts::hosts::find_host_ids;
+    use crate::http::http::send_post_request;
+    use crate::items::items::{find_lld_data_items, find_zabbix_items};
+    use crate::output::output::{Color, OutputStyle, paint};
+    use crate::patterns::patterns::{get_item_key_patterns, ItemKeyPattern};
+    use crate::session::session::{close_api_session, open_api_session};
+    use crate::types::types::StringResult;
+    use crate::zabbix::zabbix::{JSONRPC, log_zabbix_error, ZabbixError};
+
+    /// Web scenario tags, which mark managed objects, are supported since this version
+    const MIN_API_VERSION: (u32, u32) = (5, 4);
+
+    /// Admins can create web scenarios and triggers on hosts of their groups with write permission
+    const ADMIN_USER_TYPE: u8 = 2;
+
+    /// `apiinfo.version` must be called without auth
+    #[derive(Serialize)]
+    struct ApiVersionRequest {
+        jsonrpc: String,
+        method: String,
+        params: Vec<String>,
+        id: i8
+    }
+
+    #[derive(Deserialize)]
+    struct ApiVersionResponse {
+        result: Option<String>,
+        error: Option<ZabbixError>
+    }
+
+    /// One line of `wszl doctor` checklist
+    pub struct DoctorCheck {
+        pub name: String,
+        pub passed: bool,
+        pub details: String
+    }
+
+    impl DoctorCheck {
+        pub fn pass(name: &str, details: &str) -> DoctorCheck {
+            DoctorCheck { name: name.to_string(), passed: true, details: details.to_string() }
+        }
+
+        pub fn fail(name: &str, details: &str) -> DoctorCheck {
+            DoctorCheck { name: name.to_string(), passed: false, details: details.to_string() }
+        }
+    }
+
+    /// Checks preconditions of `gen` one by one, checks depending on a failed one are left out
+    pub fn run_api_checks(client: &Client, config: &Config) -> Vec<DoctorCheck> {
+        let api_config = &config.zabbix.api;
+
+        let mut checks = vec![];
+
+        match get_api_version(client, &api_config.endpoint) {
+            Ok(version) => {
+                checks.push(DoctorCheck::pass("api reachability", &api_config.endpoint));
+                checks.push(get_api_version_check(&version));
+            }
+            Err(_) => {
+                checks.push(DoctorCheck::fail("api reachability",
+                                              &format!("no valid response from '{}'", api_config.endpoint)));
+                return checks
+            }
+        }
+
+        let auth_token = match open_api_session(client, api_config) {
+            Ok(auth_token) => {
+                checks.push(DoctorCheck::pass("authentication", &format!("logged in as '{}'", api_config.username)));
+                auth_token
+            }
+            Err(_) => {
+                checks.push(DoctorCheck::fail("authentication",
+                                              &format!("unable to login as '{}'", api_config.username)));
+                return checks
+            }
+        };
+
+        match get_session_user_type(client, &api_config.endpoint, &auth_token) {
+            Ok(user_type) => checks.push(get_user_type_check(user_type)),
+            Err(_) => checks.push(DoctorCheck::fail("write permissions", "unable to get user type"))
+        }
+
+        checks.append(&mut get_item_checks(client, config, &auth_token));
+
+        if close_api_session(client, api_config, &auth_token).is_err() {
+            warn!("unable to close api session");
+        }
+
+        checks
+    }
+
+    fn get_api_version(client: &Client, api_endpoint: &str) -> StringResult {
+        let request = ApiVersionRequest {
+            jsonrpc: JSONRPC.to_string(),
+            method: "apiinfo.version".to_string(),
+            params: vec![],
+            id: 1
+        };
+
+        let response = send_post_request(client, api_endpoint, request)?;
+
+        match serde_json::from_str::<ApiVersionResponse>(&response) {
+            Ok(ApiVersionResponse { result: Some(version), .. }) => Ok(version),
+            Ok(version_response) => {
+                log_zabbix_error(&version_response.error);
+                Err(OperationError::Error)
+            }
+            Err(_) => {
+                error!("unsupported api version response");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    pub fn get_api_version_check(version: &str) -> DoctorCheck {
+        let mut parts = version.split('.').map(|part| part.parse::<u32>().unwrap_or(0));
+        let major_minor = (parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+
+        if major_minor >= MIN_API_VERSION {
+            DoctorCheck::pass("api version", version)
+        } else {
+            DoctorCheck::fail("api version", &format!("{}, Zabbix {}.{} or newer is required",
+                                                       version, MIN_API_VERSION.0, MIN_API_VERSION.1))
+        }
+    }
+
+    pub fn get_user_type_check(user_type: u8) -> DoctorCheck {
+        if user_type >= ADMIN_USER_TYPE {
+            DoctorCheck::pass("write permissions", "user is admin, hosts need read-write permission of its groups")
+        } else {
+            DoctorCheck::fail("write permissions", "user type 'User' can't create web scenarios, admin is required")
+        }
+    }
+
+    fn get_item_checks(client: &Client, config: &Config, auth_token: &str) -> Vec<DoctorCheck> {
+        let api_endpoint = &config.zabbix.api.endpoint;
+        let monitored_only = config.processing.skip_disabled;
+
+        let host_ids = match find_host_ids(client, api_endpoint, auth_token, monitored_only) {
+            Ok(host_ids) => host_ids,
+            Err(_) => return vec![DoctorCheck::fail("matching items", "unable to get hosts")]
+        };
+
+        if let Some(lld_config) = &config.discovery.lld {
+            return match find_lld_data_items(client, api_endpoint, auth_token, &lld_config.item_key,
+                                             &host_ids, monitored_only, &config.items.tags) {
+                Ok(items) if !items.is_empty() => vec![DoctorCheck::pass("matching items",
+                    &format!("{} discovery data items '{}'", items.len(), lld_config.item_key))],
+                Ok(_) => vec![DoctorCheck::fail("matching items",
+                    &format!("no discovery data items '{}' found", lld_config.item_key))],
+                Err(_) => vec![DoctorCheck::fail("matching items", "unable to get discovery data items")]
+            }
+        }
+
+        let patterns = match get_item_key_patterns(&config.items.patterns) {
+            Ok(patterns) => patterns,
+            Err(_) => return vec![DoctorCheck::fail("matching items", "invalid item key patterns")]
+        };
+
+        let search_masks: Vec<String> = patterns.iter().map(|pattern| pattern.search.to_string()).collect();
+
+        match find_zabbix_items(client, api_endpoint, auth_token, &search_masks, &host_ids, monitored_only,
+                                &config.items.tags) {
+            Ok(items) => {
+                let item_keys: Vec<&str> = items.iter().map(|item| item.key_.as_str()).collect();
+                get_pattern_checks(&patterns, &item_keys)
+            }
+            Err(_) => vec![DoctorCheck::fail("matching items", "unable to get items")]
+        }
+    }
+
+    /// Matching items overall and hit count of every pattern, unused pattern is likely a typo
+    pub fn get_pattern_checks(patterns: &[ItemKeyPattern], item_keys: &[&str]) -> Vec<DoctorCheck> {
+        let matching_items = item_keys.iter()
+            .filter(|item_key| patterns.iter().any(|pattern| pattern.capture(item_key).is_some())).count();
+
+        let mut checks = vec![
+            if matching_items > 0 {
+                DoctorCheck::pass("matching items", &format!("{} items", matching_items))
+            } else {
+                DoctorCheck::fail("matching items", "no items match item key patterns")
+            }
+        ];
+
+        for pattern in patterns {
+            let hits = item_keys.iter().filter(|item_key| pattern.capture(item_key).is_some()).count();
+            let name = format!("pattern '{}'", pattern.search);
+
+            checks.push(if hits > 0 {
+                DoctorCheck::pass(&name, &format!("{} items", hits))
+            } else {
+                DoctorCheck::fail(&name, "no items")
+            });
+        }
+
+        checks
+    }
+
+    pub fn render_checklist(checks: &[DoctorCheck], style: OutputStyle) -> String {
+        let mut content = String::new();
+
+        for check in checks {
+            let (symbol, color) = if check.passed { ("✔", Color::Green) } else { ("✘", Color::Red) };
+
+            let row = format!("{} {:<35} {}", symbol, check.name, check.details);
+            content.push_str(&format!("{}\n", paint(&row, color, style)));
+        }
+
+        let failed = checks.iter().filter(|check| !check.passed).count();
+
+        if failed == 0 {
+            content.push_str("all checks have passed\n");
+        } else {
+            content.push_str(&format!("{} of {} checks have failed\n", failed, checks.len()));
+        }
+
+        content
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod doctor_tests {
+    use crate::doctor::doctor::{DoctorCheck, get_api_version_check, get_pattern_checks, get_user_type_check,
+                                render_checklist};
+    use crate::output::output::OutputStyle;
+    use crate::patterns::patterns::ItemKeyPattern;
+
+    #[test]
+    fn api_version_should_be_at_least_5_4() {
+        assert!(get_api_version_check("5.4.0").passed);
+        assert!(get_api_version_check("6.0.21").passed);
+        assert!(get_api_version_check("7.0.0").passed);
+
+        let check = get_api_version_check("5.0.30");
+        assert!(!check.passed);
+        assert_eq!(check.details, "5.0.30, Zabbix 5.4 or newer is required");
+    }
+
+    #[test]
+    fn admin_user_type_should_be_required() {
+        assert!(!get_user_type_check(1).passed);
+        assert!(get_user_type_check(2).passed);
+        assert!(get_user_type_check(3).passed);
+    }
+
+    #[test]
+    fn hit_count_of_every_pattern_should_be_checked() {
+        let patterns = [ItemKeyPattern::from_mask("vhost.item[*]").unwrap(),
+                        ItemKeyPattern::from_mask("web.site[*]").unwrap()];
+
+        let checks = get_pattern_checks(&patterns, &["vhost.item[https://a.example.com]",
+                                                     "vhost.item[https://b.example.com]", "vhost.items"]);
+
+        assert_eq!(checks.len(), 3);
+        assert!(checks[0].passed);
+        assert_eq!(checks[0].details, "2 items");
+        assert_eq!(checks[1].name, "pattern 'vhost.item[*]'");
+        assert!(checks[1].passed);
+        assert_eq!(checks[2].name, "pattern 'web.site[*]'");
+        assert!(!checks[2].passed);
+    }
+
+    #[test]
+    fn no_matching_items_should_fail() {
+        let patterns = [ItemKeyPattern::from_mask("vhost.item[*]").unwrap()];
+
+        assert!(!get_pattern_checks(&patterns, &[])[0].passed);
+    }
+
+    #[test]
+    fn checklist_should_show_failed_count() {
+        let checks = [DoctorCheck::pass("config", "wszl.yml"),
+                      DoctorCheck::fail("api reachability", "no valid response from 'http://zabbix'")];
+
+        assert_eq!(render_checklist(&checks, OutputStyle::Plain),
+                   format!("✔ {:<35} wszl.yml\n✘ {:<35} no valid response from 'http://zabbix'\n\
+                            1 of 2 checks have failed\n", "config", "api reachability"));
+    }
+}
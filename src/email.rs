@@ -0,0 +1,104 @@
+pub mod email {
+    use lettre::message::header::ContentType;
+    use lettre::message::Mailbox;
+    use lettre::transport::smtp::authentication::Credentials;
+    use lettre::{Message, SmtpTransport, Transport};
+
+    use crate::config::config::{EmailConfig, SmtpSecurity};
+    use crate::errors::errors::OperationError;
+    use crate::notifications::notifications::RunSummary;
+    use crate::types::types::EmptyResult;
+
+    pub fn send_email_report(email_config: &EmailConfig, summary: &RunSummary, message: &str) -> EmptyResult {
+        let email = build_email(email_config, summary, message)?;
+
+        let transport_builder = match email_config.security {
+            SmtpSecurity::StartTls => SmtpTransport::starttls_relay(&email_config.smtp_host),
+            SmtpSecurity::Tls => SmtpTransport::relay(&email_config.smtp_host),
+            SmtpSecurity::None => Ok(SmtpTransport::builder_dangerous(&email_config.smtp_host))
+        };
+
+        match transport_builder {
+            Ok(mut transport_builder) => {
+                transport_builder = transport_builder.port(email_config.smtp_port);
+
+                if let (Some(username), Some(password)) = (&email_config.username, &email_config.password) {
+                    transport_builder = transport_builder.credentials(
+                        Credentials::new(username.to_string(), password.to_string()));
+                }
+
+                match transport_builder.build().send(&email) {
+                    Ok(_) => Ok(()),
+                    Err(e) => {
+                        error!("unable to send email report via '{}': {}", email_config.smtp_host, e);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(e) => {
+                error!("unable to connect to smtp server '{}': {}", email_config.smtp_host, e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn build_email(email_config: &EmailConfig, summary: &RunSummary, message: &str) -> Result<Message, OperationError> {
+        let mut builder = Message::builder()
+            .from(parse_mailbox(&email_config.from)?)
+            .subject(get_email_subject(summary))
+            .header(ContentType::TEXT_PLAIN);
+
+        for recipient in &email_config.to {
+            builder = builder.to(parse_mailbox(recipient)?);
+        }
+
+        match builder.body(get_email_body(summary, message)) {
+            Ok(email) => Ok(email),
+            Err(e) => {
+                error!("unable to build email report: {}", e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn parse_mailbox(address: &str) -> Result<Mailbox, OperationError> {
+        match address.parse::<Mailbox>() {
+            Ok(mailbox) => Ok(mailbox),
+            Err(e) => {
+                error!("invalid email address '{}': {}", address, e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    pub fn get_email_subject(summary: &RunSummary) -> String {
+        match &summary.counts {
+            Some(counts) => format!("wszl run {}: {} created, {} failed",
+                                    summary.status, counts.scenarios_created, counts.failed),
+            None => format!("wszl run {}", summary.status)
+        }
+    }
+
+    /// Summary message followed by failed and created urls
+    pub fn get_email_body(summary: &RunSummary, message: &str) -> String {
+        let mut body = format!("{}\n\nDuration: {:.1}s\n", message, summary.duration_seconds);
+
+        if !summary.failed.is_empty() {
+            body.push_str("\nFailed:\n");
+
+            for failed in &summary.failed {
+                body.push_str(&format!("- {} {} (item '{}'): {}\n", failed.host, failed.url, failed.item, failed.reason));
+            }
+        }
+
+        if !summary.created.is_empty() {
+            body.push_str("\nCreated:\n");
+
+            for created in &summary.created {
+                body.push_str(&format!("- {} {}\n", created.host, created.url));
+            }
+        }
+
+        body
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod email_tests {
+    use std::time::Duration;
+
+    use crate::email::email::{get_email_body, get_email_subject};
+    use crate::notifications::notifications::{get_run_summary, get_summary_message};
+    use crate::report::report::{ItemOutcome, RunReport};
+
+    #[test]
+    fn email_should_list_failed_and_created_urls() {
+        let mut report = RunReport::new();
+
+        let mut created = ItemOutcome::new("vhost item", "web-01");
+        created.url = "https://a.example.com".to_string();
+        created.scenario_created = true;
+        report.outcomes.push(created);
+
+        let mut failed = ItemOutcome::new("vhost item", "web-02");
+        failed.url = "https://b.example.com".to_string();
+        failed.fail("unable to create trigger");
+        report.outcomes.push(failed);
+
+        let summary = get_run_summary(Some(&report), Duration::from_secs(2));
+        let message = get_summary_message(&summary);
+
+        assert_eq!(get_email_subject(&summary), "wszl run failed: 1 created, 1 failed");
+
+        assert_eq!(get_email_body(&summary, &message), format!(
+            "{}\n\nDuration: 2.0s\n\
+             \nFailed:\n- web-02 https://b.example.com (item 'vhost item'): unable to create trigger\n\
+             \nCreated:\n- web-01 https://a.example.com\n", message));
+    }
+}
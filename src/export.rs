@@ -0,0 +1,267 @@
+pub mod export {
+    use std::collections::BTreeMap;
+
+    use regex::Regex;
+    use serde::Serialize;
+
+    use crate::plan::plan::PlannedChange;
+    use crate::report::report::{ItemStatus, RunReport};
+    use crate::triggers::triggers::TriggerParams;
+    use crate::webscenarios::webscenarios::{HttpField, WebScenarioParams};
+    use crate::zabbix::zabbix::{MANAGED_TAG_NAME, ZabbixTag};
+
+    /// Zabbix 5.4 and newer import this format, trigger expressions use the syntax of Zabbix 5.4
+    const EXPORT_VERSION: &str = "5.4";
+
+    const TEMPLATE_GROUP: &str = "Templates";
+
+    /// Uuid of the built-in group, so import doesn't try to create it
+    const TEMPLATE_GROUP_UUID: &str = "7df96b18c230490a9a0a9e2307226338";
+
+    #[derive(Serialize)]
+    struct ZabbixExportFile {
+        zabbix_export: ZabbixExport
+    }
+
+    #[derive(Serialize)]
+    struct ZabbixExport {
+        version: String,
+        date: String,
+        groups: Vec<ExportGroup>,
+        templates: Vec<ExportTemplate>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        triggers: Vec<ExportTrigger>
+    }
+
+    #[derive(Serialize)]
+    struct ExportGroup {
+        #[serde(skip_serializing_if = "String::is_empty")]
+        uuid: String,
+        name: String
+    }
+
+    #[derive(Serialize)]
+    struct ExportTemplate {
+        uuid: String,
+        template: String,
+        name: String,
+        groups: Vec<ExportGroup>,
+        httptests: Vec<ExportHttpTest>
+    }
+
+    #[derive(Serialize)]
+    struct ExportHttpTest {
+        uuid: String,
+        name: String,
+        delay: String,
+        retries: String,
+        #[serde(skip_serializing_if = "String::is_empty")]
+        http_proxy: String,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        variables: Vec<ExportField>,
+        steps: Vec<ExportHttpStep>,
+        /// Managed tag is left out, imported objects aren't managed by wszl
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<ZabbixTag>
+    }
+
+    #[derive(Serialize)]
+    struct ExportHttpStep {
+        name: String,
+        url: String,
+        #[serde(skip_serializing_if = "String::is_empty")]
+        posts: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        post_type: Option<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        headers: Vec<ExportField>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        variables: Vec<ExportField>,
+        timeout: String,
+        #[serde(skip_serializing_if = "String::is_empty")]
+        required: String,
+        status_codes: String,
+        retrieve_mode: String
+    }
+
+    #[derive(Serialize)]
+    struct ExportField {
+        name: String,
+        value: String
+    }
+
+    #[derive(Serialize)]
+    struct ExportTrigger {
+        uuid: String,
+        expression: String,
+        name: String,
+        #[serde(skip_serializing_if = "String::is_empty")]
+        url: String,
+        priority: String,
+        #[serde(skip_serializing_if = "String::is_empty")]
+        description: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        correlation_mode: Option<String>,
+        #[serde(skip_serializing_if = "String::is_empty")]
+        correlation_tag: String,
+        /// Managed tag is left out, imported objects aren't managed by wszl
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<ZabbixTag>
+    }
+
+    fn get_template_name(host: &str) -> String {
+        format!("WSZL {}", host)
+    }
+
+    /// Renders web scenarios and triggers planned in dry-run and existing managed ones (see `ItemOutcome::existing`)
+    /// as Zabbix template (YAML), one template per host.
+    pub fn render_zabbix_template(report: &RunReport, date: &str) -> String {
+        let mut changes: BTreeMap<&str, Vec<&PlannedChange>> = BTreeMap::new();
+
+        for outcome in &report.outcomes {
+            let change = match (&outcome.status, &outcome.existing) {
+                (ItemStatus::Planned(change), _) => change,
+                (_, Some(existing)) => existing,
+                _ => continue
+            };
+
+            changes.entry(&change.host).or_default().push(change);
+        }
+
+        let mut templates = vec![];
+        let mut triggers = vec![];
+
+        for (host, host_changes) in changes {
+            let template_name = get_template_name(host);
+            let host_reference = get_host_reference_regex(host);
+
+            templates.push(ExportTemplate {
+                uuid: get_uuid(&template_name),
+                template: template_name.to_string(),
+                name: template_name.to_string(),
+                groups: vec![get_template_group()],
+                httptests: host_changes.iter().map(|change| get_http_test(&template_name, &change.scenario)).collect()
+            });
+
+            for change in host_changes {
+                triggers.push(get_trigger(&change.trigger, &host_reference, &template_name));
+
+                if let Some(latency_trigger) = &change.latency_trigger {
+                    triggers.push(get_trigger(latency_trigger, &host_reference, &template_name));
+                }
+            }
+        }
+
+        let export_file = ZabbixExportFile {
+            zabbix_export: ZabbixExport {
+                version: EXPORT_VERSION.to_string(),
+                date: date.to_string(),
+                groups: vec![ExportGroup { uuid: TEMPLATE_GROUP_UUID.to_string(), name: TEMPLATE_GROUP.to_string() }],
+                templates,
+                triggers
+            }
+        };
+
+        serde_yaml::to_string(&export_file).expect("unable to serialize zabbix template")
+    }
+
+    fn get_template_group() -> ExportGroup {
+        ExportGroup { uuid: String::new(), name: TEMPLATE_GROUP.to_string() }
+    }
+
+    /// Objects of templates need UUIDv4, they are derived from names, so repeated exports update the same objects
+    pub fn get_uuid(name: &str) -> String {
+        let high = get_name_hash(name, 0xcbf29ce484222325);
+        let low = get_name_hash(name, 0x84222325cbf29ce4);
+
+        let high = (high & 0xffffffff_ffff0fff) | 0x4000;
+        let low = (low & 0x3fffffff_ffffffff) | 0x80000000_00000000;
+
+        format!("{:016x}{:016x}", high, low)
+    }
+
+    /// FNV-1a, uuids have to be the same in every run and build
+    fn get_name_hash(name: &str, offset_basis: u64) -> u64 {
+        name.bytes().fold(offset_basis, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+    }
+
+    fn get_http_test(template_name: &str, scenario: &WebScenarioParams) -> ExportHttpTest {
+        ExportHttpTest {
+            uuid: get_uuid(&format!("{}/{}", template_name, scenario.name)),
+            name: scenario.name.to_string(),
+            delay: scenario.delay.to_string(),
+            retries: scenario.retries.to_string(),
+            http_proxy: scenario.http_proxy.to_string(),
+            variables: get_fields(&scenario.variables),
+            steps: scenario.steps.iter().map(|step| ExportHttpStep {
+                name: step.name.to_string(),
+                url: step.url.to_string(),
+                posts: step.posts.to_string(),
+                post_type: if step.posts.is_empty() { None } else { Some("RAW".to_string()) },
+                headers: get_fields(&step.headers),
+                variables: get_fields(&step.variables),
+                timeout: step.timeout.to_string(),
+                required: step.required.to_string(),
+                status_codes: step.status_codes.to_string(),
+                retrieve_mode: get_retrieve_mode_name(step.retrieve_mode).to_string()
+            }).collect(),
+            tags: get_unmanaged_tags(&scenario.tags)
+        }
+    }
+
+    fn get_fields(fields: &[HttpField]) -> Vec<ExportField> {
+        fields.iter().map(|field| ExportField { name: field.name.to_string(), value: field.value.to_string() })
+              .collect()
+    }
+
+    fn get_unmanaged_tags(tags: &[ZabbixTag]) -> Vec<ZabbixTag> {
+        tags.iter().filter(|tag| tag.tag != MANAGED_TAG_NAME).cloned().collect()
+    }
+
+    fn get_trigger(trigger: &TriggerParams, host_reference: &Regex, template_name: &str) -> ExportTrigger {
+        let expression = get_template_expression(&trigger.expression, host_reference, template_name);
+
+        ExportTrigger {
+            uuid: get_uuid(&format!("{}/{}", expression, trigger.description)),
+            expression,
+            name: trigger.description.to_string(),
+            url: trigger.url.to_string(),
+            priority: get_priority_name(&trigger.priority).to_string(),
+            description: trigger.comments.to_string(),
+            correlation_mode: trigger.correlation_mode.map(|_| "TAG_VALUE".to_string()),
+            correlation_tag: trigger.correlation_tag.to_string(),
+            tags: get_unmanaged_tags(&trigger.tags)
+        }
+    }
+
+    /// Item references of host (`func(/host/key)`, spaces are allowed before them)
+    pub fn get_host_reference_regex(host: &str) -> Regex {
+        Regex::new(&format!(r"(\(\s*)/{}/", regex::escape(host))).expect("escaped host name is a valid regex")
+    }
+
+    /// Item references of host are pointed to the template
+    pub fn get_template_expression(expression: &str, host_reference: &Regex, template_name: &str) -> String {
+        host_reference.replace_all(expression, |captures: &regex::Captures| {
+            format!("{}/{}/", &captures[1], template_name)
+        }).to_string()
+    }
+
+    fn get_retrieve_mode_name(retrieve_mode: u8) -> &'static str {
+        match retrieve_mode {
+            1 => "HEADERS",
+            2 => "BOTH",
+            _ => "BODY"
+        }
+    }
+
+    pub fn get_priority_name(priority: &str) -> &'static str {
+        match priority {
+            "1" => "INFO",
+            "2" => "WARNING",
+            "3" => "AVERAGE",
+            "4" => "HIGH",
+            "5" => "DISASTER",
+            _ => "NOT_CLASSIFIED"
+        }
+    }
+}
@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod export_tests {
+    use std::collections::HashMap;
+
+    use crate::config::config::{LatencyConfig, RetrieveMode, TemplatesConfig, WebScenarioConfig};
+    use crate::export::export::{get_host_reference_regex, get_priority_name, get_template_expression, get_uuid,
+                                render_zabbix_template};
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::latency::latency::{get_latency_trigger_params, LatencyThresholds};
+    use crate::plan::plan::PlannedChange;
+    use crate::report::report::{ItemOutcome, ItemStatus, RunReport};
+    use crate::templates::templates::NameTemplates;
+    use crate::triggers::triggers::{apply_correlation_tag, get_trigger_params};
+    use crate::webscenarios::webscenarios::get_web_scenario_params;
+
+    fn get_change(url: &str, correlation_tag: Option<&str>) -> PlannedChange {
+        let scenario_config = WebScenarioConfig {
+            response_timeout: "15s".to_string(),
+            expected_status_code: "200".to_string(),
+            attempts: 3,
+            update_interval: "5m".to_string(),
+            retrieve_mode: RetrieveMode::Headers,
+            variables: vec![]
+        };
+
+        let host = ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(),
+                                maintenance_status: "0".to_string(), status: "0".to_string(),
+                                proxy_hostid: "0".to_string() };
+
+        let names = NameTemplates::new(&TemplatesConfig::default()).unwrap()
+                        .get_object_names(&host, url, &HashMap::new(), None).unwrap();
+
+        let mut trigger = get_trigger_params(&names, url);
+
+        if let Some(correlation_tag) = correlation_tag {
+            apply_correlation_tag(&mut trigger, correlation_tag);
+        }
+
+        PlannedChange {
+            host: "web-01".to_string(),
+            scenario: get_web_scenario_params(&scenario_config, &names, url, "10001"),
+            trigger,
+            latency_trigger: None,
+            renamed_from: None,
+            adopted_from: None
+        }
+    }
+
+    fn get_planned_report(correlation_tag: Option<&str>) -> RunReport {
+        let mut report = RunReport::new();
+
+        let mut planned = ItemOutcome::new("vhost item", "web-01");
+        planned.url = "https://example.com".to_string();
+        planned.status = ItemStatus::Planned(Box::new(get_change("https://example.com", correlation_tag)));
+        report.outcomes.push(planned);
+
+        let mut skipped = ItemOutcome::new("vhost item", "web-01");
+        skipped.status = ItemStatus::SkippedExisting;
+        report.outcomes.push(skipped);
+
+        report
+    }
+
+    #[test]
+    fn template_should_contain_planned_scenarios_and_triggers() {
+        let template = render_zabbix_template(&get_planned_report(None), "2024-01-01T00:00:00Z");
+
+        let export: serde_yaml::Value = serde_yaml::from_str(&template).unwrap();
+        let export = &export["zabbix_export"];
+
+        assert_eq!(export["version"].as_str(), Some("5.4"));
+        assert_eq!(export["templates"].as_sequence().unwrap().len(), 1);
+
+        let template = &export["templates"][0];
+        assert_eq!(template["template"].as_str(), Some("WSZL web-01"));
+        assert_eq!(template["uuid"].as_str(), Some(get_uuid("WSZL web-01").as_str()));
+        assert_eq!(template["groups"][0]["name"].as_str(), Some("Templates"));
+
+        let http_test = &template["httptests"][0];
+        assert_eq!(http_test["name"].as_str(), Some("Check index page 'https://example.com'"));
+        assert_eq!(http_test["delay"].as_str(), Some("5m"));
+        assert_eq!(http_test["retries"].as_str(), Some("3"));
+        assert_eq!(http_test["steps"][0]["url"].as_str(), Some("https://example.com"));
+        assert_eq!(http_test["steps"][0]["timeout"].as_str(), Some("15s"));
+        assert_eq!(http_test["steps"][0]["retrieve_mode"].as_str(), Some("HEADERS"));
+        assert!(http_test["tags"].is_null());
+
+        let trigger = &export["triggers"][0];
+        assert_eq!(trigger["name"].as_str(), Some("Site 'https://example.com' is unavailable"));
+        assert_eq!(trigger["expression"].as_str(),
+                   Some("last(/WSZL web-01/web.test.fail[Check index page 'https://example.com'])<>0"));
+        assert_eq!(trigger["priority"].as_str(), Some("HIGH"));
+        assert!(trigger["tags"].is_null());
+    }
+
+    #[test]
+    fn correlation_tag_should_be_exported_without_managed_tag() {
+        let template = render_zabbix_template(&get_planned_report(Some("url")), "2024-01-01T00:00:00Z");
+
+        let export: serde_yaml::Value = serde_yaml::from_str(&template).unwrap();
+        let trigger = &export["zabbix_export"]["triggers"][0];
+
+        assert_eq!(trigger["correlation_mode"].as_str(), Some("TAG_VALUE"));
+        assert_eq!(trigger["correlation_tag"].as_str(), Some("url"));
+        assert_eq!(trigger["tags"].as_sequence().unwrap().len(), 1);
+        assert_eq!(trigger["tags"][0]["tag"].as_str(), Some("url"));
+        assert_eq!(trigger["tags"][0]["value"].as_str(), Some("https://example.com"));
+    }
+
+    #[test]
+    fn existing_managed_objects_and_latency_triggers_should_be_exported() {
+        let thresholds = LatencyThresholds::new(&LatencyConfig { threshold_ms: 3000, priority: 2, rules: vec![] })
+                            .unwrap();
+
+        let host = ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(),
+                                maintenance_status: "0".to_string(), status: "0".to_string(),
+                                proxy_hostid: "0".to_string() };
+
+        let url = "https://example.com/shop";
+
+        let names = NameTemplates::new(&TemplatesConfig::default()).unwrap()
+                        .get_object_names(&host, url, &HashMap::new(), None).unwrap();
+
+        let mut change = get_change(url, None);
+        change.latency_trigger = Some(get_latency_trigger_params("web-01", url, &names, &thresholds));
+
+        let mut report = get_planned_report(None);
+
+        let mut existing = ItemOutcome::new("vhost item", "web-01");
+        existing.url = url.to_string();
+        existing.status = ItemStatus::SkippedExisting;
+        existing.existing = Some(Box::new(change));
+        report.outcomes.push(existing);
+
+        let template = render_zabbix_template(&report, "2024-01-01T00:00:00Z");
+
+        let export: serde_yaml::Value = serde_yaml::from_str(&template).unwrap();
+        let export = &export["zabbix_export"];
+
+        let http_tests = export["templates"][0]["httptests"].as_sequence().unwrap();
+        assert_eq!(http_tests.len(), 2);
+        assert_eq!(http_tests[1]["name"].as_str(), Some("Check index page 'https://example.com/shop'"));
+        assert_ne!(http_tests[0]["uuid"], http_tests[1]["uuid"]);
+
+        let triggers = export["triggers"].as_sequence().unwrap();
+        assert_eq!(triggers.len(), 3);
+        assert_eq!(triggers[2]["name"].as_str(), Some("Site 'https://example.com/shop' responds slower than 3000ms"));
+        assert!(triggers[2]["expression"].as_str().unwrap().starts_with("min(/WSZL web-01/web.test.time["));
+        assert_eq!(triggers[2]["priority"].as_str(), Some("WARNING"));
+        assert_eq!(triggers[2]["tags"][0]["tag"].as_str(), Some("check"));
+    }
+
+    #[test]
+    fn expression_host_should_be_replaced_with_template_in_every_item_reference() {
+        let host_reference = get_host_reference_regex("web-01.example.com");
+
+        assert_eq!(get_template_expression("last(/web-01.example.com/web.test.fail[Check])<>0", &host_reference,
+                                           "WSZL web-01"),
+                   "last(/WSZL web-01/web.test.fail[Check])<>0");
+        assert_eq!(get_template_expression("min( /web-01.example.com/web.test.time[Check,Check,resp],#3)>1 and \
+                                            last(/web-01.example.com/web.test.fail[Check])<>0", &host_reference,
+                                           "WSZL web-01"),
+                   "min( /WSZL web-01/web.test.time[Check,Check,resp],#3)>1 and \
+                                            last(/WSZL web-01/web.test.fail[Check])<>0");
+
+        // Other hosts and urls with the host name in item keys are left alone
+        assert_eq!(get_template_expression("last(/web-01xexample.com/web.test.fail[https://web-01.example.com/])<>0",
+                                           &host_reference, "WSZL web-01"),
+                   "last(/web-01xexample.com/web.test.fail[https://web-01.example.com/])<>0");
+    }
+
+    #[test]
+    fn uuid_should_be_stable_uuid_v4() {
+        let uuid = get_uuid("WSZL web-01");
+
+        assert_eq!(uuid, get_uuid("WSZL web-01"));
+        assert_ne!(uuid, get_uuid("WSZL web-02"));
+        assert_eq!(uuid.len(), 32);
+        assert!(uuid.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+        assert_eq!(&uuid[12..13], "4");
+        assert!("89ab".contains(&uuid[16..17]));
+    }
+
+    #[test]
+    fn unknown_priority_should_be_not_classified() {
+        assert_eq!(get_priority_name("5"), "DISASTER");
+        assert_eq!(get_priority_name("x"), "NOT_CLASSIFIED");
+    }
+}
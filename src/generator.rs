@@ -0,0 +1,1102 @@
+pub mod generator {
+    use std::collections::{HashMap, HashSet};
+    use std::path::Path;
+    use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::thread;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use reqwest::blocking::Client;
+
+    use crate::adoption::adoption::{find_adoptable_web_scenario, find_legacy_web_scenario};
+    use crate::aggregate::aggregate::create_missing_aggregates;
+    use crate::blocklist::blocklist::Blocklist;
+    use crate::centralhost::centralhost::CentralHost;
+    use crate::circuit::circuit::is_api_circuit_open;
+    use crate::config::config::{Config, DiscoveryConfig, LldDiscoveryConfig};
+    use crate::discovery::discovery::{deduplicate_urls, DiscoveredUrl, ExternalUrl, get_last_item_id,
+                                      get_urls_from_items, get_urls_from_lld_items, is_new_item, normalize_urls,
+                                      resolve_external_urls, skip_stale_items, StaleItemPolicy};
+    use crate::docker::docker::get_urls_from_docker;
+    use crate::errors::errors::OperationError;
+    use crate::hosts::hosts::{find_host_ids, find_hosts, find_hosts_by_names, find_proxy_names, get_proxy_name,
+                              ZabbixHost};
+    use crate::graphs::graphs::create_graph;
+    use crate::hooks::hooks::{ACTION_CREATE, get_hook_env, HookEvent, RESULT_FAILED, RESULT_SUCCESS, run_hook};
+    use crate::hostgroups::hostgroups::add_hosts_to_group;
+    use crate::httpproxy::httpproxy::HttpProxies;
+    use crate::items::items::{find_lld_data_items, find_zabbix_items};
+    use crate::latency::latency::{get_latency_trigger_params, is_latency_trigger, LatencyThresholds};
+    use crate::limits::limits::ScenarioLimits;
+    use crate::maintenance::maintenance::{create_maintenance, get_grace_maintenance_params};
+    use crate::patterns::patterns::{get_item_key_patterns, ItemKeyPattern};
+    use crate::plan::plan::PlannedChange;
+    use crate::probe::probe::UrlProber;
+    use crate::renames::renames::{find_url_renames, get_partial_host_ids, UrlRename};
+    use crate::report::report::{ItemOutcome, ItemStatus, RunReport};
+    use crate::services::services::ServiceTree;
+    use crate::runstate::runstate::RunState;
+    use crate::scripting::scripting::{apply_scenario_overrides, apply_trigger_overrides, ScriptItem, UrlOverrides,
+                                      UrlScript};
+    use crate::session::session::{close_api_session, open_api_session};
+    use crate::shutdown::shutdown::{is_run_deadline_exceeded, is_shutdown_requested};
+    use crate::templates::templates::{NameTemplates, ObjectNames};
+    use crate::telemetry::telemetry::{get_current_span_id, start_child_span, start_span};
+    use crate::triggers::triggers::{apply_correlation_tag, create_trigger, find_legacy_url_triggers, find_url_triggers,
+                                    get_trigger_params, tag_trigger, TriggerParams, update_trigger};
+    use crate::types::types::OperationResult;
+    use crate::urlsfile::urlsfile::load_urls_from_file;
+    use crate::urlfilter::urlfilter::UrlFilter;
+    use crate::urltransform::urltransform::UrlTransformer;
+    use crate::vhosts::vhosts::get_urls_from_vhost_configs;
+    use crate::webscenarios::webscenarios::{adopt_web_scenario, create_web_scenario, find_unmanaged_web_scenarios,
+                                            find_web_scenarios, get_web_scenario_params, rename_web_scenario,
+                                            tag_web_scenario, WebScenarioParams, ZabbixWebScenario};
+
+    /// Where urls are taken from on Zabbix side
+    enum UrlSource<'a> {
+        ItemKeys(Vec<ItemKeyPattern>),
+        LldData(&'a LldDiscoveryConfig)
+    }
+
+    /// State shared by all chunks of the run
+    struct RunContext<'a> {
+        client: &'a Client,
+        config: &'a Config,
+        auth_token: String,
+        url_source: UrlSource<'a>,
+        url_filter: UrlFilter,
+        /// Urls which are never managed
+        blocklist: Blocklist,
+        url_transformer: UrlTransformer,
+        url_prober: Option<UrlProber>,
+        service_tree: Option<ServiceTree<'a>>,
+        name_templates: NameTemplates,
+        url_script: Option<UrlScript>,
+        latency_thresholds: Option<LatencyThresholds>,
+        http_proxies: Option<HttpProxies>,
+        /// Checks of matching urls are created on this host instead of the host of item
+        central_host: Option<CentralHost>,
+        scenario_limits: ScenarioLimits,
+        /// Names of proxies by ids for reports
+        proxy_names: HashMap<String, String>,
+        /// Only plan changes, nothing is created
+        dry_run: bool,
+        /// Hand-made web scenarios of urls are taken over instead of creating duplicates
+        adopt: bool,
+        /// Definitions of urls with existing managed web scenarios are collected as well
+        export: bool,
+        /// Only failed items of the previous run are processed
+        retry_state: Option<&'a RunState>,
+        /// New items only run, only items with higher ids (created later) are processed
+        since_item_id: Option<u64>,
+        /// Highest id of items matching patterns, for the next new items only run
+        last_item_id: AtomicU64
+    }
+
+    impl<'a> RunContext<'a> {
+        fn api_endpoint(&self) -> &str {
+            &self.config.zabbix.api.endpoint
+        }
+    }
+
+    /// Zabbix objects related to one chunk of hosts
+    struct ZabbixObjects {
+        urls: Vec<DiscoveredUrl>,
+        /// Items which urls couldn't be obtained from
+        failures: Vec<ItemOutcome>,
+        web_scenarios: Vec<ZabbixWebScenario>,
+        /// Web scenarios without wszl tag: legacy ones of versions before tagging and hand-made ones
+        unmanaged_web_scenarios: Vec<ZabbixWebScenario>,
+        hosts: Vec<ZabbixHost>
+    }
+
+    /// With `dry_run` objects are only planned (see `ItemStatus::Planned`), Zabbix isn't changed.
+    /// With `retry_state` only failed items of the previous run are processed.
+    /// With `adopt` hand-made web scenarios of urls get wszl tag and definition from config.
+    /// With `since_item_id` only items created after that item are processed (urls of item keys only).
+    /// With `export` urls with existing managed web scenarios get their definition from config
+    /// (see `ItemOutcome::existing`).
+    pub fn create_web_scenarios_and_triggers(client: &Client, config: &Config, dry_run: bool,
+                                             retry_state: Option<&RunState>, adopt: bool,
+                                             since_item_id: Option<u64>, export: bool) -> OperationResult<RunReport> {
+        let zabbix_config = &config.zabbix;
+
+        let url_source = match &config.discovery.lld {
+            Some(lld_config) => {
+                info!("urls will be taken from discovery data of items '{}'", lld_config.item_key);
+                UrlSource::LldData(lld_config)
+            }
+            None => UrlSource::ItemKeys(get_item_key_patterns(&config.items.patterns)?)
+        };
+
+        let url_filter = UrlFilter::new(&config.urls)?;
+        let blocklist = Blocklist::load(Path::new(&config.urls.blocklist_file))?;
+        let url_transformer = UrlTransformer::new(&config.urls)?;
+        let url_prober = config.probe.as_ref().map(UrlProber::new).transpose()?;
+        let name_templates = NameTemplates::new(&config.templates)?;
+        let latency_thresholds = config.latency.as_ref().map(LatencyThresholds::new).transpose()?;
+        let http_proxies = config.http_proxy.as_ref().map(HttpProxies::new).transpose()?;
+        let url_script = config.script_file.as_ref().map(|file_path| UrlScript::load(Path::new(file_path)))
+                                                    .transpose()?;
+
+        let external_urls = load_external_urls(&config.discovery)?;
+
+        let login_result = {
+            let _span = start_span("login");
+            open_api_session(client, &zabbix_config.api)
+        };
+
+        match login_result {
+            Ok(auth_token) => {
+                debug!("login success: token '{}'", auth_token);
+
+                let proxy_names = find_proxy_names(client, &zabbix_config.api.endpoint, &auth_token);
+
+                let result = find_central_host(client, config, &auth_token).and_then(|central_host| {
+                    let context = RunContext {
+                        client,
+                        config,
+                        auth_token: auth_token.to_string(),
+                        url_source,
+                        url_filter,
+                        blocklist,
+                        url_transformer,
+                        url_prober,
+                        service_tree: config.services.as_ref().map(ServiceTree::new),
+                        name_templates,
+                        url_script,
+                        latency_thresholds,
+                        http_proxies,
+                        central_host,
+                        scenario_limits: ScenarioLimits::new(config.processing.max_scenarios_per_host,
+                                                             config.processing.max_scenarios_per_run),
+                        proxy_names,
+                        dry_run,
+                        adopt,
+                        export,
+                        retry_state,
+                        since_item_id,
+                        last_item_id: AtomicU64::new(0)
+                    };
+
+                    process_hosts_in_chunks(&context, &external_urls)
+                });
+
+                if close_api_session(client, &zabbix_config.api, &auth_token).is_err() {
+                    warn!("unable to close api session");
+                }
+
+                result
+            },
+            Err(_) => {
+                error!("unable to login");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn find_central_host(client: &Client, config: &Config,
+                         auth_token: &str) -> OperationResult<Option<CentralHost>> {
+        match &config.central_host {
+            Some(central_host_config) => {
+                let hosts = find_hosts_by_names(client, &config.zabbix.api.endpoint, auth_token,
+                                                vec![central_host_config.host.to_string()])?;
+
+                match hosts.into_iter().find(|host| host.host == central_host_config.host) {
+                    Some(host) => {
+                        info!("checks of urls will be created on central host '{}'", host.host);
+                        Ok(Some(CentralHost::new(central_host_config, host)?))
+                    }
+                    None => {
+                        error!("central host '{}' wasn't found", central_host_config.host);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            None => Ok(None)
+        }
+    }
+
+    /// Urls from sources outside of Zabbix
+    fn load_external_urls(discovery_config: &DiscoveryConfig) -> OperationResult<Vec<ExternalUrl>> {
+        let mut urls = vec![];
+
+        if let Some(urls_file) = &discovery_config.urls_file {
+            urls.append(&mut load_urls_from_file(Path::new(urls_file))?);
+        }
+
+        if let Some(docker_config) = &discovery_config.docker {
+            urls.append(&mut get_urls_from_docker(docker_config)?);
+        }
+
+        if let Some(vhosts_config) = &discovery_config.vhosts {
+            urls.append(&mut get_urls_from_vhost_configs(vhosts_config)?);
+        }
+
+        Ok(urls)
+    }
+
+    fn resolve_external_url_hosts(context: &RunContext, external_urls: &[ExternalUrl]) ->
+                                        OperationResult<(Vec<DiscoveredUrl>, Vec<ItemOutcome>)> {
+        if external_urls.is_empty() {
+            return Ok((vec![], vec![]))
+        }
+
+        let host_names: HashSet<String> = external_urls.iter()
+                                            .map(|url| url.host.to_string()).collect();
+
+        let hosts = find_hosts_by_names(context.client, context.api_endpoint(), &context.auth_token,
+                                        host_names.into_iter().collect())?;
+
+        Ok(resolve_external_urls(external_urls, &hosts))
+    }
+
+    /// Items, web scenarios and hosts are fetched and processed chunk by chunk,
+    /// so memory usage doesn't grow with the size of Zabbix installation.
+    fn process_hosts_in_chunks(context: &RunContext,
+                               external_urls: &[ExternalUrl]) -> OperationResult<RunReport> {
+        let host_ids_result = {
+            let _span = start_span("find hosts with items");
+            find_host_ids(context.client, context.api_endpoint(), &context.auth_token,
+                          context.config.processing.skip_disabled)
+        };
+
+        match host_ids_result {
+            Ok(mut host_ids) => {
+                let mut report = RunReport::new();
+
+                let (external_urls, mut failures) = resolve_external_url_hosts(context, external_urls)?;
+
+                report.outcomes.append(&mut failures);
+
+                for external_url in &external_urls {
+                    if !host_ids.contains(&external_url.hostid) {
+                        host_ids.push(external_url.hostid.to_string());
+                    }
+                }
+
+                if let Some(retry_state) = context.retry_state {
+                    let failed_host_ids = find_failed_host_ids(context, retry_state)?;
+                    host_ids.retain(|host_id| failed_host_ids.contains(host_id));
+
+                    info!("retry failed items of {} hosts", host_ids.len());
+                }
+
+                // Urls taken by previous chunks, needed for global deduplication scope
+                let mut seen_urls = HashMap::new();
+
+                let chunk_size = context.config.processing.hosts_per_chunk.max(1);
+                let chunks_total = host_ids.len().div_ceil(chunk_size);
+
+                for (chunk_index, chunk) in host_ids.chunks(chunk_size).enumerate() {
+                    if is_run_deadline_exceeded() {
+                        report.timed_out = true;
+                        break;
+                    }
+
+                    debug!("processing hosts chunk {}/{}", chunk_index + 1, chunks_total);
+
+                    match find_zabbix_objects(context, &external_urls, chunk) {
+                        Ok(mut zabbix_objects) => {
+                            let (urls, mut duplicates) = deduplicate_urls(
+                                zabbix_objects.urls, &context.config.urls.dedup_scope, &mut seen_urls);
+
+                            for duplicate in &mut duplicates {
+                                if let Some(host) = zabbix_objects.hosts.iter()
+                                                        .find(|host| host.hostid == duplicate.host) {
+                                    duplicate.host = host.host.to_string();
+                                }
+                            }
+
+                            zabbix_objects.urls = urls;
+                            report.outcomes.append(&mut duplicates);
+
+                            process_zabbix_objects(context, zabbix_objects, &mut report)
+                        }
+                        Err(_) if is_api_circuit_open(context.api_endpoint()) => report.aborted = true,
+                        Err(_) => {
+                            error!("unable to get zabbix objects");
+                            return Err(OperationError::Error)
+                        }
+                    }
+
+                    if report.interrupted || report.aborted || report.error_limit_reached || report.timed_out {
+                        break;
+                    }
+                }
+
+                report.last_item_id = match context.last_item_id.load(Ordering::SeqCst) {
+                    0 => None,
+                    last_item_id => Some(last_item_id)
+                };
+
+                Ok(report)
+            }
+            Err(_) => {
+                error!("unable to get zabbix hosts");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn process_zabbix_objects(context: &RunContext, mut zabbix_objects: ZabbixObjects,
+                              report: &mut RunReport) {
+        let _span = start_span("create objects");
+        let parent_span_id = get_current_span_id();
+
+        let renames = if context.config.processing.detect_renames {
+            // Urls of new items only or retry runs are just a part of urls of hosts
+            let all_items = context.since_item_id.is_none() && context.retry_state.is_none();
+            let mut partial_host_ids = get_partial_host_ids(&zabbix_objects.urls, &zabbix_objects.failures,
+                                                            all_items);
+
+            // Checks of other hosts' urls on central host would look renamed to its own urls
+            if let Some(central_host) = &context.central_host {
+                partial_host_ids.insert(central_host.host.hostid.to_string());
+            }
+
+            find_url_renames(&zabbix_objects.web_scenarios, &zabbix_objects.urls, &partial_host_ids)
+        } else { vec![] };
+        let renames = &renames;
+
+        report.outcomes.append(&mut zabbix_objects.failures);
+
+        let zabbix_objects = &zabbix_objects;
+        let urls = &zabbix_objects.urls;
+
+        let next_url_index = AtomicUsize::new(0);
+        let interrupted = AtomicBool::new(false);
+        let aborted = AtomicBool::new(false);
+        let error_limit_reached = AtomicBool::new(false);
+        let timed_out = AtomicBool::new(false);
+        // Failed items of the whole run, previous chunks included
+        let failed_items = AtomicUsize::new(report.outcomes.iter().filter(|outcome| outcome.is_failed()).count());
+        let max_errors = context.config.processing.max_errors;
+        let outcomes: Mutex<Vec<(usize, ItemOutcome)>> = Mutex::new(Vec::with_capacity(urls.len()));
+
+        // Every worker makes API calls one by one, so the number of workers
+        // limits simultaneous calls to Zabbix API.
+        let workers = context.config.processing.concurrency.max(1).min(urls.len());
+
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| {
+                    loop {
+                        let index = next_url_index.fetch_add(1, Ordering::SeqCst);
+
+                        if index >= urls.len() {
+                            break;
+                        }
+
+                        if is_shutdown_requested() {
+                            interrupted.store(true, Ordering::SeqCst);
+                            break;
+                        }
+
+                        // Items in progress of other workers are finished
+                        if is_run_deadline_exceeded() {
+                            timed_out.store(true, Ordering::SeqCst);
+                            break;
+                        }
+
+                        if is_api_circuit_open(context.api_endpoint()) {
+                            aborted.store(true, Ordering::SeqCst);
+                            break;
+                        }
+
+                        if error_limit_reached.load(Ordering::SeqCst) {
+                            break;
+                        }
+
+                        let outcome = process_url(context, zabbix_objects, renames, &urls[index], &parent_span_id);
+
+                        if outcome.is_failed() && max_errors > 0 &&
+                            failed_items.fetch_add(1, Ordering::SeqCst) + 1 > max_errors {
+                            error_limit_reached.store(true, Ordering::SeqCst);
+                        }
+
+                        outcomes.lock().unwrap().push((index, outcome));
+                    }
+                });
+            }
+        });
+
+        let mut outcomes = outcomes.into_inner().unwrap();
+        outcomes.sort_by_key(|(index, _)| *index);
+
+        if !context.dry_run {
+            // Hosts with managed web scenarios, existing or just created
+            let monitored_hosts: Vec<&ZabbixHost> = zabbix_objects.hosts.iter()
+                .filter(|host| {
+                    zabbix_objects.web_scenarios.iter().any(|web_scenario| web_scenario.hostid == host.hostid) ||
+                    outcomes.iter().any(|(_, outcome)| outcome.scenario_created &&
+                                                       outcome.get_check_host() == host.host)
+                })
+                .collect();
+
+            if let Some(host_group) = &context.config.zabbix.host_group {
+                if add_hosts_to_group(context.client, context.api_endpoint(), &context.auth_token,
+                                      host_group, &monitored_hosts).is_err() {
+                    error!("unable to add hosts to group '{}'", host_group);
+                }
+            }
+
+            if let Some(grace_config) = &context.config.grace {
+                let onboarded_hosts: Vec<&ZabbixHost> = zabbix_objects.hosts.iter()
+                    .filter(|host| outcomes.iter().any(|(_, outcome)| outcome.scenario_created &&
+                                                                      outcome.get_check_host() == host.host))
+                    .collect();
+
+                if !onboarded_hosts.is_empty() {
+                    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+                    if create_maintenance(context.client, context.api_endpoint(), &context.auth_token,
+                                          get_grace_maintenance_params(grace_config, &onboarded_hosts, now)).is_err() {
+                        error!("unable to create grace period maintenance");
+                    }
+                }
+            }
+
+            if let Some(aggregate_config) = &context.config.aggregate {
+                if create_missing_aggregates(context.client, context.api_endpoint(), &context.auth_token,
+                                             aggregate_config, &context.config.zabbix.scenario.update_interval,
+                                             &monitored_hosts).is_err() {
+                    error!("unable to create aggregated availability items");
+                }
+            }
+        }
+
+        report.outcomes.extend(outcomes.into_iter().map(|(_, outcome)| outcome));
+
+        if interrupted.load(Ordering::SeqCst) {
+            warn!("run has been interrupted");
+            report.interrupted = true;
+        }
+
+        if aborted.load(Ordering::SeqCst) {
+            report.aborted = true;
+        }
+
+        if error_limit_reached.load(Ordering::SeqCst) {
+            error!("more than {} items have failed, the rest of the run is aborted", max_errors);
+            report.error_limit_reached = true;
+        }
+
+        if timed_out.load(Ordering::SeqCst) {
+            report.timed_out = true;
+        }
+    }
+
+    fn process_url(context: &RunContext, zabbix_objects: &ZabbixObjects, renames: &[UrlRename],
+                   discovered_url: &DiscoveredUrl, parent_span_id: &str) -> ItemOutcome {
+        debug!("item '{}'", discovered_url.source);
+
+        let mut item_span = start_child_span("process item", parent_span_id);
+        item_span.set_attribute("item.name", &discovered_url.source);
+        item_span.set_attribute("url", &discovered_url.url);
+
+        let outcome = create_scenario_and_trigger_for_url(context, zabbix_objects, renames, discovered_url);
+
+        if outcome.is_failed() { item_span.set_error() }
+
+        outcome
+    }
+
+    fn find_failed_host_ids(context: &RunContext, retry_state: &RunState) -> OperationResult<Vec<String>> {
+        let failed_hosts = retry_state.get_failed_hosts();
+
+        if failed_hosts.is_empty() {
+            info!("previous run has no failed items");
+            return Ok(vec![])
+        }
+
+        let hosts = find_hosts_by_names(context.client, context.api_endpoint(), &context.auth_token, failed_hosts)?;
+
+        Ok(hosts.into_iter().map(|host| host.hostid).collect())
+    }
+
+    fn find_zabbix_objects(context: &RunContext, external_urls: &[DiscoveredUrl],
+                           host_ids: &[String]) -> OperationResult<ZabbixObjects> {
+        let _span = start_span("find zabbix objects");
+
+        match find_urls(context, host_ids) {
+            Ok((mut urls, mut failures)) => {
+                urls.extend(external_urls.iter()
+                                .filter(|url| host_ids.contains(&url.hostid)).cloned());
+
+                urls.retain(|discovered_url| context.url_filter.is_accepted(&discovered_url.url));
+
+                for discovered_url in &mut urls {
+                    discovered_url.url = context.url_transformer.transform(&discovered_url.url);
+                }
+
+                let (mut urls, invalid_urls) = normalize_urls(urls);
+                failures.extend(invalid_urls);
+
+                urls.retain(|discovered_url| !context.blocklist.is_blocked(&discovered_url.url));
+
+                debug!("received urls: {}", urls.len());
+
+                // New items only run doesn't need web scenarios and hosts of chunk without new items
+                if context.since_item_id.is_some() && urls.is_empty() && failures.is_empty() {
+                    return Ok(ZabbixObjects { urls, failures, web_scenarios: vec![], unmanaged_web_scenarios: vec![],
+                                              hosts: vec![] })
+                }
+
+                let host_ids = &get_check_host_ids(context, host_ids);
+
+                match find_web_scenarios(context.client, context.api_endpoint(), &context.auth_token, host_ids) {
+                    Ok(web_scenarios) => {
+                        debug!("web scenarios have been obtained");
+
+                        match find_hosts(context.client, context.api_endpoint(), &context.auth_token, host_ids.to_vec()) {
+                            Ok(hosts) => {
+                                if let Some(retry_state) = context.retry_state {
+                                    urls.retain(|discovered_url| {
+                                        hosts.iter().find(|host| host.hostid == discovered_url.hostid)
+                                            .map(|host| retry_state.is_failed(&host.host, &discovered_url.source,
+                                                                              &discovered_url.url))
+                                            .unwrap_or(false)
+                                    });
+                                }
+
+                                let unmanaged_web_scenarios = find_unmanaged_web_scenarios(
+                                    context.client, context.api_endpoint(), &context.auth_token, host_ids)?;
+
+                                Ok(
+                                    ZabbixObjects {
+                                        urls,
+                                        failures,
+                                        web_scenarios,
+                                        unmanaged_web_scenarios,
+                                        hosts
+                                    }
+                                )
+
+                            }
+                            Err(_) => {
+                                error!("unable to get zabbix hosts by ids");
+                                Err(OperationError::Error)
+                            }
+                        }
+                    }
+                    Err(_) => {
+                        error!("unable to get zabbix web scenarios");
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to get zabbix items");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Hosts of chunk and central host, which has checks of their urls
+    fn get_check_host_ids(context: &RunContext, host_ids: &[String]) -> Vec<String> {
+        let mut check_host_ids = host_ids.to_vec();
+
+        if let Some(central_host) = &context.central_host {
+            if !check_host_ids.contains(&central_host.host.hostid) {
+                check_host_ids.push(central_host.host.hostid.to_string());
+            }
+        }
+
+        check_host_ids
+    }
+
+    fn find_urls(context: &RunContext, host_ids: &[String]) ->
+                                    OperationResult<(Vec<DiscoveredUrl>, Vec<ItemOutcome>)> {
+        match &context.url_source {
+            UrlSource::ItemKeys(item_key_patterns) => {
+                let item_key_search_masks: Vec<String> = item_key_patterns.iter()
+                                                .map(|pattern| pattern.search.to_string()).collect();
+
+                let mut items = find_zabbix_items(context.client, context.api_endpoint(), &context.auth_token,
+                                                  &item_key_search_masks, host_ids,
+                                                  context.config.processing.skip_disabled,
+                                                  &context.config.items.tags)?;
+
+                // Retry sees items of failed hosts only, items of other hosts may be newer
+                if context.retry_state.is_none() {
+                    if let Some(last_item_id) = get_last_item_id(items.iter().map(|item| item.itemid.as_str())) {
+                        context.last_item_id.fetch_max(last_item_id, Ordering::SeqCst);
+                    }
+                }
+
+                if let Some(since_item_id) = context.since_item_id {
+                    let items_total = items.len();
+                    items.retain(|item| is_new_item(&item.itemid, since_item_id));
+                    debug!("new items since the last successful run: {} of {}", items.len(), items_total);
+                }
+
+                let (items, mut stale_items) = skip_stale_items(items, &get_stale_item_policy(context),
+                    |item| (&item.itemid, &item.name, &item.hostid, &item.state, &item.lastclock));
+
+                let (urls, mut failures) = get_urls_from_items(&items, item_key_patterns);
+                failures.append(&mut stale_items);
+
+                Ok((urls, failures))
+            }
+            UrlSource::LldData(lld_config) => {
+                let items = find_lld_data_items(context.client, context.api_endpoint(), &context.auth_token,
+                                                &lld_config.item_key, host_ids,
+                                                context.config.processing.skip_disabled, &context.config.items.tags)?;
+
+                let (items, mut stale_items) = skip_stale_items(items, &get_stale_item_policy(context),
+                    |item| (&item.itemid, &item.name, &item.hostid, &item.state, &item.lastclock));
+
+                let (urls, mut failures) = get_urls_from_lld_items(&items, &lld_config.url_macro);
+                failures.append(&mut stale_items);
+
+                Ok((urls, failures))
+            }
+        }
+    }
+
+    fn get_stale_item_policy(context: &RunContext) -> StaleItemPolicy {
+        StaleItemPolicy {
+            skip_unsupported: context.config.items.skip_unsupported,
+            max_age_secs: context.config.items.stale_after_secs,
+            now: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+        }
+    }
+
+    fn create_scenario_and_trigger_for_url(context: &RunContext, zabbix_objects: &ZabbixObjects,
+                                           renames: &[UrlRename], discovered_url: &DiscoveredUrl) -> ItemOutcome {
+        let mut outcome = ItemOutcome::new(&discovered_url.source, &discovered_url.hostid);
+        outcome.itemid = discovered_url.itemid.to_string();
+
+        let url = &discovered_url.url;
+
+        debug!("---------------------------");
+        debug!("item: {}", discovered_url.source);
+        debug!("- url '{}'", url);
+        outcome.url = url.to_string();
+
+        match zabbix_objects.hosts.iter().find(|host| host.hostid == discovered_url.hostid) {
+            Some(origin_host) => {
+                let central_host = context.central_host.as_ref()
+                    .filter(|central_host| central_host.is_routed(url, &origin_host.hostid))
+                    .map(|central_host| &central_host.host);
+
+                // Checks are created on central host, item and its outcome stay with the host of item
+                let host = central_host.unwrap_or(origin_host);
+                outcome.central_host = central_host.map(|central_host| central_host.host.to_string());
+
+                if central_host.is_some() {
+                    debug!("- url is checked from central host '{}'", host.host);
+                }
+
+                outcome.proxy = get_proxy_name(host, &context.proxy_names);
+
+                if let Some(proxy) = &outcome.proxy {
+                    debug!("- host '{}' is monitored by proxy '{}'", host.host, proxy);
+                }
+
+                let overrides = match &context.url_script {
+                    Some(url_script) => {
+                        let item = ScriptItem {
+                            key: &discovered_url.key, source: &discovered_url.source, host: &origin_host.host, url
+                        };
+
+                        match url_script.get_overrides(&item) {
+                            Ok(overrides) => discovered_url.overrides.clone().merge(overrides),
+                            Err(_) => {
+                                outcome.fail(&format!("url script has failed for url '{}'", url));
+                                return outcome
+                            }
+                        }
+                    }
+                    None => discovered_url.overrides.clone()
+                };
+
+                if overrides.skip {
+                    info!("url '{}' is skipped by script or item overrides", url);
+                    outcome.host = origin_host.host.to_string();
+                    outcome.status = ItemStatus::SkippedByScript;
+                    return outcome
+                }
+
+                let scenario_name = overrides.scenario_name.as_deref();
+
+                let names_result = match central_host {
+                    Some(central_host) => context.name_templates.get_central_object_names(
+                                            central_host, origin_host, url, &discovered_url.groups, scenario_name),
+                    None => context.name_templates.get_object_names(
+                                            host, url, &discovered_url.groups, scenario_name)
+                };
+
+                let names = match names_result {
+                    Ok(names) => names,
+                    Err(_) => {
+                        outcome.fail(&format!("unable to render object names for url '{}'", url));
+                        return outcome
+                    }
+                };
+
+                match zabbix_objects.web_scenarios.iter()
+                                .find(|entity| entity.hostid == host.hostid && entity.name == names.scenario) {
+                    Some(_) => {
+                        debug!("web scenario has been found for url '{}', skip", url);
+                        outcome.status = ItemStatus::SkippedExisting;
+
+                        if context.export {
+                            outcome.existing = Some(Box::new(get_url_definition(context, host, url, &names,
+                                                                                &overrides)));
+                        }
+                    }
+                    None => {
+                        let legacy = find_legacy_web_scenario(&zabbix_objects.unmanaged_web_scenarios,
+                                                              &host.hostid, url, &names.scenario);
+
+                        if let Some(web_scenario) = legacy {
+                            tag_legacy_objects_for_url(context, host, url, &names, web_scenario, &mut outcome);
+
+                            if context.export && !outcome.is_failed() {
+                                outcome.existing = Some(Box::new(get_url_definition(context, host, url, &names,
+                                                                                    &overrides)));
+                            }
+                            return outcome
+                        }
+
+                        debug!("web scenario wasn't found for url '{}', creating..", url);
+
+                        outcome.host = origin_host.host.to_string();
+
+                        // Hosts of external urls are resolved by names, so they may be disabled
+                        if context.config.processing.skip_disabled && !host.is_monitored() {
+                            info!("host '{}' isn't monitored, url '{}' is skipped", host.host, url);
+                            outcome.status = ItemStatus::SkippedDisabled;
+                            return outcome
+                        }
+
+                        if context.config.processing.skip_maintenance && host.is_in_maintenance() {
+                            info!("host '{}' is in maintenance, url '{}' is deferred", host.host, url);
+                            outcome.status = ItemStatus::Deferred;
+                            return outcome
+                        }
+
+                        if let Some(url_prober) = &context.url_prober {
+                            if let Err(reason) = url_prober.probe(url) {
+                                warn!("url '{}' is unreachable: {}", url, reason);
+                                outcome.probe_error = Some(reason);
+
+                                if url_prober.skip_unreachable {
+                                    outcome.status = ItemStatus::Unreachable;
+                                    return outcome
+                                }
+                            }
+                        }
+
+                        let adoptable = if context.adopt {
+                            find_adoptable_web_scenario(&zabbix_objects.unmanaged_web_scenarios,
+                                                        &host.hostid, url, &names.scenario)
+                        } else { None };
+
+                        if let Some(web_scenario) = adoptable {
+                            adopt_objects_for_url(context, host, url, &names, &overrides, web_scenario, &mut outcome);
+                            return outcome
+                        }
+
+                        match renames.iter().find(|rename| rename.web_scenario.hostid == host.hostid &&
+                                                           rename.new_url == *url) {
+                            Some(rename) => rename_objects_for_url(context, host, url, &names, &overrides,
+                                                                   rename, &mut outcome),
+                            None => {
+                                let existing = zabbix_objects.web_scenarios.iter()
+                                                .filter(|web_scenario| web_scenario.hostid == host.hostid).count();
+
+                                if let Err(reason) = context.scenario_limits.reserve(&host.host, existing) {
+                                    outcome.fail(&format!("url '{}' is skipped, {}", url, reason));
+                                    return outcome
+                                }
+
+                                create_objects_for_url(context, host, url, &names, &overrides, &mut outcome);
+
+                                if outcome.is_failed() && !outcome.scenario_created {
+                                    context.scenario_limits.release(&host.host);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            None => outcome.fail(&format!("host wasn't found by id {}", discovered_url.hostid))
+        }
+
+        outcome
+    }
+
+    fn get_url_params(context: &RunContext, host: &ZabbixHost, url: &str, names: &ObjectNames,
+                      overrides: &UrlOverrides) -> (WebScenarioParams, TriggerParams) {
+        let scenario_config = match &context.config.zabbix.proxy_scenario {
+            Some(proxy_scenario_config) if host.is_proxy_monitored() => proxy_scenario_config,
+            _ => &context.config.zabbix.scenario
+        };
+
+        let mut scenario_params = get_web_scenario_params(scenario_config, names, url, &host.hostid);
+        apply_scenario_overrides(&mut scenario_params, overrides);
+
+        if let Some(http_proxies) = &context.http_proxies {
+            scenario_params.http_proxy = http_proxies.get_http_proxy(url).to_string();
+        }
+
+        let mut trigger_params = get_trigger_params(names, url);
+        apply_trigger_overrides(&mut trigger_params, overrides);
+
+        if let Some(correlation_tag) = &context.config.zabbix.correlation_tag {
+            apply_correlation_tag(&mut trigger_params, correlation_tag);
+        }
+
+        (scenario_params, trigger_params)
+    }
+
+    /// Objects of url as wszl creates them
+    fn get_url_definition(context: &RunContext, host: &ZabbixHost, url: &str, names: &ObjectNames,
+                          overrides: &UrlOverrides) -> PlannedChange {
+        let (scenario_params, trigger_params) = get_url_params(context, host, url, names, overrides);
+        let latency_trigger_params = get_latency_trigger(context, host, url,
+                                                         &get_first_step_names(names, &scenario_params));
+
+        PlannedChange {
+            host: host.host.to_string(),
+            scenario: scenario_params,
+            trigger: trigger_params,
+            latency_trigger: latency_trigger_params,
+            renamed_from: None,
+            adopted_from: None
+        }
+    }
+
+    /// Items of scenario are named after the first step, which may come from url script
+    fn get_first_step_names(names: &ObjectNames, scenario_params: &WebScenarioParams) -> ObjectNames {
+        let mut names = names.clone();
+        if let Some(step) = scenario_params.steps.first() {
+            names.step = step.name.to_string();
+        }
+        names
+    }
+
+    fn get_latency_trigger(context: &RunContext, host: &ZabbixHost, url: &str,
+                           names: &ObjectNames) -> Option<TriggerParams> {
+        let mut trigger_params = context.latency_thresholds.as_ref()
+            .map(|thresholds| get_latency_trigger_params(&host.host, url, names, thresholds))?;
+
+        if let Some(correlation_tag) = &context.config.zabbix.correlation_tag {
+            apply_correlation_tag(&mut trigger_params, correlation_tag);
+        }
+
+        Some(trigger_params)
+    }
+
+    /// Web scenario and trigger of disappeared url get names and url of the new one, history is kept
+    fn rename_objects_for_url(context: &RunContext, host: &ZabbixHost, url: &str, names: &ObjectNames,
+                              overrides: &UrlOverrides, rename: &UrlRename, outcome: &mut ItemOutcome) {
+        let (scenario_params, trigger_params) = get_url_params(context, host, url, names, overrides);
+        let latency_trigger_params = get_latency_trigger(context, host, url,
+                                                         &get_first_step_names(names, &scenario_params));
+
+        if context.dry_run {
+            outcome.status = ItemStatus::Planned(Box::new(PlannedChange {
+                host: host.host.to_string(),
+                scenario: scenario_params,
+                trigger: trigger_params,
+                latency_trigger: latency_trigger_params,
+                renamed_from: Some(rename.old_url.to_string()),
+                adopted_from: None
+            }));
+            return
+        }
+
+        info!("url '{}' of host '{}' looks renamed to '{}'", rename.old_url, host.host, url);
+
+        if rename_web_scenario(context.client, context.api_endpoint(), &context.auth_token,
+                               rename.web_scenario, scenario_params).is_err() {
+            outcome.fail(&format!("unable to rename web scenario of url '{}'", rename.old_url));
+            return
+        }
+
+        match find_url_triggers(context.client, context.api_endpoint(), &context.auth_token,
+                                &host.hostid, &rename.old_url) {
+            Ok(triggers) => {
+                for trigger in triggers {
+                    let params = if is_latency_trigger(&trigger.tags) {
+                        match &latency_trigger_params {
+                            Some(latency_trigger_params) => latency_trigger_params,
+                            None => continue
+                        }
+                    } else {
+                        &trigger_params
+                    };
+
+                    if update_trigger(context.client, context.api_endpoint(), &context.auth_token,
+                                      &trigger.triggerid, params).is_err() {
+                        outcome.fail(&format!("unable to rename trigger of url '{}'", rename.old_url));
+                        return
+                    }
+                }
+
+                info!("web scenario and trigger of url '{}' have been renamed", url);
+                outcome.status = ItemStatus::Renamed(rename.old_url.to_string());
+            }
+            Err(_) => outcome.fail(&format!("unable to find trigger of url '{}'", rename.old_url))
+        }
+    }
+
+    /// Web scenario and triggers created by versions before tagging get wszl tag, so they are managed again
+    fn tag_legacy_objects_for_url(context: &RunContext, host: &ZabbixHost, url: &str, names: &ObjectNames,
+                                  web_scenario: &ZabbixWebScenario, outcome: &mut ItemOutcome) {
+        outcome.status = ItemStatus::SkippedExisting;
+
+        if context.dry_run {
+            info!("web scenario '{}' of host '{}' has no wszl tag, it would be tagged", web_scenario.name, host.host);
+            return
+        }
+
+        info!("web scenario '{}' of host '{}' has no wszl tag, tagging..", web_scenario.name, host.host);
+
+        if tag_web_scenario(context.client, context.api_endpoint(), &context.auth_token, web_scenario).is_err() {
+            outcome.fail(&format!("unable to tag web scenario of url '{}'", url));
+            return
+        }
+
+        match find_legacy_url_triggers(context.client, context.api_endpoint(), &context.auth_token,
+                                       &host.hostid, url, &names.trigger) {
+            Ok(triggers) => {
+                for trigger in triggers {
+                    if tag_trigger(context.client, context.api_endpoint(), &context.auth_token, &trigger).is_err() {
+                        outcome.fail(&format!("unable to tag trigger of url '{}'", url));
+                        return
+                    }
+                }
+            }
+            Err(_) => outcome.fail(&format!("unable to find trigger of url '{}'", url))
+        }
+    }
+
+    /// Hand-made web scenario of url gets definition from config and wszl tag, triggers are created
+    fn adopt_objects_for_url(context: &RunContext, host: &ZabbixHost, url: &str, names: &ObjectNames,
+                             overrides: &UrlOverrides, web_scenario: &ZabbixWebScenario, outcome: &mut ItemOutcome) {
+        let (scenario_params, trigger_params) = get_url_params(context, host, url, names, overrides);
+        let names = &get_first_step_names(names, &scenario_params);
+
+        if context.dry_run {
+            outcome.status = ItemStatus::Planned(Box::new(PlannedChange {
+                host: host.host.to_string(),
+                scenario: scenario_params,
+                trigger: trigger_params,
+                latency_trigger: get_latency_trigger(context, host, url, names),
+                renamed_from: None,
+                adopted_from: Some(web_scenario.name.to_string())
+            }));
+            return
+        }
+
+        info!("url '{}' of host '{}' has hand-made web scenario '{}', adopting..", url, host.host, web_scenario.name);
+
+        if adopt_web_scenario(context.client, context.api_endpoint(), &context.auth_token,
+                              web_scenario, scenario_params).is_err() {
+            outcome.fail(&format!("unable to adopt web scenario '{}' of url '{}'", web_scenario.name, url));
+            return
+        }
+
+        create_url_triggers(context, host, url, names, trigger_params, outcome);
+
+        if !outcome.is_failed() {
+            outcome.status = ItemStatus::Adopted(web_scenario.name.to_string());
+        }
+    }
+
+    fn create_objects_for_url(context: &RunContext, host: &ZabbixHost, url: &str, names: &ObjectNames,
+                              overrides: &UrlOverrides, outcome: &mut ItemOutcome) {
+        let (scenario_params, trigger_params) = get_url_params(context, host, url, names, overrides);
+
+        // Graph and latency trigger are built from items of the first step
+        let names = &get_first_step_names(names, &scenario_params);
+
+        if context.dry_run {
+            outcome.status = ItemStatus::Planned(Box::new(PlannedChange {
+                host: host.host.to_string(),
+                scenario: scenario_params,
+                trigger: trigger_params,
+                latency_trigger: get_latency_trigger(context, host, url, names),
+                renamed_from: None,
+                adopted_from: None
+            }));
+            return
+        }
+
+        let hooks_config = &context.config.hooks;
+        let hook_timeout = Duration::from_secs(hooks_config.timeout_secs);
+
+        if let Some(pre_create_command) = &hooks_config.pre_create {
+            let event = HookEvent { action: ACTION_CREATE, host: &host.host, url, result: "" };
+
+            if run_hook(pre_create_command, &get_hook_env(&event), hook_timeout).is_err() {
+                outcome.fail(&format!("pre-create hook has failed for url '{}'", url));
+                return
+            }
+        }
+
+        create_scenario_objects(context, host, url, names, scenario_params, trigger_params, outcome);
+
+        if let Some(post_create_command) = &hooks_config.post_create {
+            let result = if outcome.is_failed() { RESULT_FAILED } else { RESULT_SUCCESS };
+            let event = HookEvent { action: ACTION_CREATE, host: &host.host, url, result };
+
+            if run_hook(post_create_command, &get_hook_env(&event), hook_timeout).is_err() {
+                error!("post-create hook has failed for url '{}'", url);
+            }
+        }
+    }
+
+    fn create_scenario_objects(context: &RunContext, host: &ZabbixHost, url: &str, names: &ObjectNames,
+                               scenario_params: WebScenarioParams, trigger_params: TriggerParams,
+                               outcome: &mut ItemOutcome) {
+        match create_web_scenario(context.client, context.api_endpoint(), &context.auth_token, scenario_params) {
+            Ok(_) => {
+                info!("web scenario has been created for '{}'", url);
+                outcome.scenario_created = true;
+
+                create_url_triggers(context, host, url, names, trigger_params, outcome);
+            },
+            Err(_) => outcome.fail(&format!("unable to create web scenario for url '{}'", url))
+        }
+    }
+
+    /// Trigger, its service, latency trigger and graph of web scenario
+    fn create_url_triggers(context: &RunContext, host: &ZabbixHost, url: &str, names: &ObjectNames,
+                           trigger_params: TriggerParams, outcome: &mut ItemOutcome) {
+        match create_trigger(context.client, context.api_endpoint(), &context.auth_token, trigger_params) {
+            Ok(triggerid) => {
+                info!("trigger has been created");
+                outcome.trigger_created = true;
+
+                if let Some(service_tree) = &context.service_tree {
+                    if service_tree.add_url_service(context.client, context.api_endpoint(), &context.auth_token,
+                                                    &host.host, url, &triggerid).is_err() {
+                        outcome.fail(&format!("unable to create service for url '{}'", url))
+                    }
+                }
+
+                if let Some(latency_trigger_params) = get_latency_trigger(context, host, url, names) {
+                    if create_trigger(context.client, context.api_endpoint(), &context.auth_token,
+                                      latency_trigger_params).is_err() {
+                        outcome.fail(&format!("unable to create latency trigger for url '{}'", url))
+                    }
+                }
+
+                if let Some(graph_config) = &context.config.zabbix.graph {
+                    if create_graph(context.client, context.api_endpoint(), &context.auth_token,
+                                    graph_config, host, url, names).is_err() {
+                        outcome.fail(&format!("unable to create graph for url '{}'", url))
+                    }
+                }
+            }
+            Err(_) => outcome.fail(&format!("unable to create trigger for url '{}'", url))
+        }
+    }
+}
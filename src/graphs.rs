@@ -0,0 +1,132 @@
+pub mod graphs {
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    use crate::config::config::GraphConfig;
+    use crate::errors::errors::OperationError;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::http::http::send_post_request;
+    use crate::items::items::{find_web_items, ZabbixWebItem};
+    use crate::templates::templates::ObjectNames;
+    use crate::types::types::EmptyResult;
+    use crate::zabbix::zabbix;
+    use crate::zabbix::zabbix::{log_zabbix_error, quote_key_param, ZabbixError, ZabbixRequest};
+
+    const RESPONSE_TIME_COLOR: &str = "1A7C11";
+    const DOWNLOAD_SPEED_COLOR: &str = "F63100";
+
+    /// Y axis side: 0 - left, 1 - right
+    const LEFT_Y_AXIS: u8 = 0;
+    const RIGHT_Y_AXIS: u8 = 1;
+
+    #[derive(Serialize)]
+    pub struct GraphParams {
+        pub name: String,
+        pub width: u32,
+        pub height: u32,
+        pub gitems: Vec<GraphItem>
+    }
+
+    #[derive(Serialize)]
+    pub struct GraphItem {
+        pub itemid: String,
+        pub color: String,
+        pub yaxisside: u8
+    }
+
+    #[derive(Deserialize)]
+    struct CreateGraphResponse {
+        error: Option<ZabbixError>
+    }
+
+    /// Keys of response time and download speed items of the web scenario step
+    pub fn get_graph_item_keys(names: &ObjectNames) -> (String, String) {
+        let step = format!("{},{}", quote_key_param(&names.scenario), quote_key_param(&names.step));
+
+        (format!("web.test.time[{},resp]", step), format!("web.test.in[{},bps]", step))
+    }
+
+    /// Graph name from template, `{host}` and `{url}` are replaced
+    pub fn get_graph_name(name_template: &str, host: &str, url: &str) -> String {
+        name_template.replace("{host}", host).replace("{url}", url)
+    }
+
+    /// Response time on the left axis, download speed on the right one.
+    /// `None` when scenario items of the host weren't found.
+    pub fn get_graph_params(graph_config: &GraphConfig, host: &str, hostid: &str, url: &str, names: &ObjectNames,
+                            web_items: &[ZabbixWebItem]) -> Option<GraphParams> {
+        let (response_time_key, download_speed_key) = get_graph_item_keys(names);
+
+        let find_item_id = |key: &str| web_items.iter()
+            .find(|item| item.hostid == hostid && item.key_ == key)
+            .map(|item| item.itemid.to_string());
+
+        let response_time_item_id = find_item_id(&response_time_key)?;
+        let download_speed_item_id = find_item_id(&download_speed_key)?;
+
+        Some(GraphParams {
+            name: get_graph_name(&graph_config.name, host, url),
+            width: graph_config.width,
+            height: graph_config.height,
+            gitems: vec![
+                GraphItem {
+                    itemid: response_time_item_id,
+                    color: RESPONSE_TIME_COLOR.to_string(),
+                    yaxisside: LEFT_Y_AXIS
+                },
+                GraphItem {
+                    itemid: download_speed_item_id,
+                    color: DOWNLOAD_SPEED_COLOR.to_string(),
+                    yaxisside: RIGHT_Y_AXIS
+                }
+            ]
+        })
+    }
+
+    /// Graph for web scenario of url, the scenario must already exist
+    pub fn create_graph(client: &reqwest::blocking::Client,
+                        api_endpoint: &str, auth_token: &str, graph_config: &GraphConfig,
+                        host: &ZabbixHost, url: &str, names: &ObjectNames) -> EmptyResult {
+        debug!("create graph for '{}', url '{}'", host.host, url);
+
+        let (response_time_key, download_speed_key) = get_graph_item_keys(names);
+
+        let web_items = find_web_items(client, api_endpoint, auth_token,
+                                       vec![response_time_key, download_speed_key])?;
+
+        let params = match get_graph_params(graph_config, &host.host, &host.hostid, url, names, &web_items) {
+            Some(params) => params,
+            None => {
+                error!("web scenario items weren't found for url '{}'", url);
+                return Err(OperationError::Error)
+            }
+        };
+
+        let request: ZabbixRequest<GraphParams> = ZabbixRequest::new(
+            "graph.create", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let create_response: CreateGraphResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match create_response.error {
+                    Some(_) => {
+                        log_zabbix_error(&create_response.error);
+                        error!("unable to create graph for '{}'", url);
+                        Err(OperationError::Error)
+                    }
+                    None => {
+                        info!("graph has been created for url '{}'", url);
+                        Ok(())
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to create graph for '{}'", url);
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod graphs_tests {
+    use std::collections::HashMap;
+
+    use crate::config::config::{GraphConfig, TemplatesConfig};
+    use crate::graphs::graphs::{get_graph_item_keys, get_graph_name, get_graph_params};
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::items::items::ZabbixWebItem;
+    use crate::templates::templates::{NameTemplates, ObjectNames};
+
+    fn get_web_item(itemid: &str, hostid: &str, key: &str) -> ZabbixWebItem {
+        ZabbixWebItem {
+            itemid: itemid.to_string(),
+            hostid: hostid.to_string(),
+            key_: key.to_string(),
+            lastvalue: "0".to_string(),
+            lastclock: "0".to_string()
+        }
+    }
+
+    fn get_graph_config() -> GraphConfig {
+        GraphConfig { name: "Web performance '{url}' on {host}".to_string(), width: 900, height: 200 }
+    }
+
+    fn get_names(url: &str) -> ObjectNames {
+        let host = ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(),
+                                maintenance_status: "0".to_string(), status: "0".to_string(),
+                                proxy_hostid: "0".to_string() };
+
+        NameTemplates::new(&TemplatesConfig::default()).unwrap().get_object_names(&host, url, &HashMap::new(), None).unwrap()
+    }
+
+    #[test]
+    fn graph_name_should_be_rendered_from_template() {
+        assert_eq!(get_graph_name("Web performance '{url}' on {host}", "web-01", "https://example.com"),
+                   "Web performance 'https://example.com' on web-01");
+    }
+
+    #[test]
+    fn graph_should_combine_response_time_and_download_speed_items() {
+        let url = "https://example.com";
+        let names = get_names(url);
+        let (response_time_key, download_speed_key) = get_graph_item_keys(&names);
+
+        assert_eq!(response_time_key, "web.test.time[Check index page 'https://example.com',Get page,resp]");
+        assert_eq!(download_speed_key, "web.test.in[Check index page 'https://example.com',Get page,bps]");
+
+        let web_items = vec![
+            get_web_item("101", "10002", &response_time_key),
+            get_web_item("201", "10001", &response_time_key),
+            get_web_item("202", "10001", &download_speed_key)
+        ];
+
+        let params = get_graph_params(&get_graph_config(), "web-01", "10001", url, &names, &web_items).unwrap();
+
+        assert_eq!(params.name, "Web performance 'https://example.com' on web-01");
+        assert_eq!(params.gitems.len(), 2);
+        assert_eq!(params.gitems[0].itemid, "201");
+        assert_eq!(params.gitems[0].yaxisside, 0);
+        assert_eq!(params.gitems[1].itemid, "202");
+        assert_eq!(params.gitems[1].yaxisside, 1);
+    }
+
+    #[test]
+    fn graph_should_not_be_created_without_scenario_items() {
+        let web_items = vec![
+            get_web_item("201", "10001", "web.test.time[Check index page 'https://example.com',Get page,resp]")
+        ];
+
+        let url = "https://example.com";
+
+        assert!(get_graph_params(&get_graph_config(), "web-01", "10001", url, &get_names(url), &web_items).is_none());
+    }
+}
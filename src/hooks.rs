@@ -0,0 +1,117 @@
+pub mod hooks {
+    use std::process::Command;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use crate::errors::errors::OperationError;
+    use crate::notifications::notifications::get_run_summary;
+    use crate::report::report::RunReport;
+    use crate::shutdown::shutdown::is_run_deadline_exceeded;
+    use crate::types::types::EmptyResult;
+
+    pub const ACTION_CREATE: &str = "create";
+    pub const ACTION_DELETE: &str = "delete";
+    pub const ACTION_RUN_END: &str = "run-end";
+
+    pub const RESULT_SUCCESS: &str = "success";
+    pub const RESULT_FAILED: &str = "failed";
+
+    /// What happened, passed to hook command with `WSZL_*` environment variables
+    pub struct HookEvent<'a> {
+        pub action: &'a str,
+        pub host: &'a str,
+        pub url: &'a str,
+        /// Empty for `pre-*` hooks
+        pub result: &'a str
+    }
+
+    pub fn get_hook_env(event: &HookEvent) -> Vec<(String, String)> {
+        vec![
+            ("WSZL_ACTION".to_string(), event.action.to_string()),
+            ("WSZL_HOST".to_string(), event.host.to_string()),
+            ("WSZL_URL".to_string(), event.url.to_string()),
+            ("WSZL_RESULT".to_string(), event.result.to_string())
+        ]
+    }
+
+    const HOOK_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+    /// Runs command with shell, non-zero exit code is an error.
+    /// Hook isn't started once `--timeout` of the run has passed.
+    pub fn run_hook(command: &str, env: &[(String, String)], timeout: Duration) -> EmptyResult {
+        if is_run_deadline_exceeded() {
+            warn!("hook '{}' hasn't been started, run timeout has been exceeded", command);
+            return Err(OperationError::Error)
+        }
+
+        run_command(command, env, timeout)
+    }
+
+    /// Command is killed after `timeout`
+    fn run_command(command: &str, env: &[(String, String)], timeout: Duration) -> EmptyResult {
+        debug!("run hook '{}'", command);
+
+        let mut child = match Command::new("sh").arg("-c").arg(command)
+                                     .envs(env.iter().map(|(name, value)| (name, value))).spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                error!("unable to run hook '{}': {}", command, e);
+                return Err(OperationError::Error)
+            }
+        };
+
+        let started_at = Instant::now();
+
+        loop {
+            match child.try_wait() {
+                Ok(Some(status)) => {
+                    if status.success() {
+                        return Ok(())
+
+                    } else {
+                        error!("hook '{}' has failed: {}", command, status);
+                        return Err(OperationError::Error)
+                    }
+                }
+                Ok(None) => {
+                    if started_at.elapsed() >= timeout {
+                        error!("hook '{}' has been killed after timeout {}s", command, timeout.as_secs());
+
+                        if let Err(e) = child.kill() {
+                            error!("unable to kill hook '{}': {}", command, e);
+                        }
+
+                        let _ = child.wait();
+                        return Err(OperationError::Error)
+                    }
+
+                    thread::sleep(HOOK_POLL_INTERVAL);
+                }
+                Err(e) => {
+                    error!("unable to wait for hook '{}': {}", command, e);
+                    return Err(OperationError::Error)
+                }
+            }
+        }
+    }
+
+    /// Hook at the end of run gets run status and counts, it reports timed out runs as well
+    pub fn run_end_hook(command: &str, report: Option<&RunReport>, run_duration: Duration, timeout: Duration) {
+        let summary = get_run_summary(report, run_duration);
+
+        let mut env = get_hook_env(&HookEvent {
+            action: ACTION_RUN_END, host: "", url: "", result: &summary.status
+        });
+
+        if let Some(counts) = &summary.counts {
+            env.push(("WSZL_SCENARIOS_CREATED".to_string(), counts.scenarios_created.to_string()));
+            env.push(("WSZL_FAILED".to_string(), counts.failed.to_string()));
+        }
+
+        env.push(("WSZL_DURATION".to_string(), format!("{:.3}", summary.duration_seconds)));
+
+        if run_command(command, &env, timeout).is_err() {
+            error!("run-end hook has failed");
+        }
+    }
+}
@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod hooks_tests {
+    use std::time::{Duration, Instant};
+
+    use crate::hooks::hooks::{ACTION_CREATE, get_hook_env, HookEvent, RESULT_SUCCESS, run_hook};
+
+    const HOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn get_event() -> HookEvent<'static> {
+        HookEvent { action: ACTION_CREATE, host: "web-01", url: "https://example.com", result: RESULT_SUCCESS }
+    }
+
+    #[test]
+    fn hook_env_should_describe_event() {
+        let env = get_hook_env(&get_event());
+
+        assert!(env.contains(&("WSZL_ACTION".to_string(), "create".to_string())));
+        assert!(env.contains(&("WSZL_HOST".to_string(), "web-01".to_string())));
+        assert!(env.contains(&("WSZL_URL".to_string(), "https://example.com".to_string())));
+        assert!(env.contains(&("WSZL_RESULT".to_string(), "success".to_string())));
+    }
+
+    #[test]
+    fn hook_should_get_env_and_fail_on_non_zero_exit_code() {
+        let env = get_hook_env(&get_event());
+
+        assert!(run_hook("test \"$WSZL_URL\" = https://example.com", &env, HOOK_TIMEOUT).is_ok());
+        assert!(run_hook("test \"$WSZL_HOST\" = web-02", &env, HOOK_TIMEOUT).is_err());
+    }
+
+    #[test]
+    fn hook_should_be_killed_after_timeout() {
+        let started_at = Instant::now();
+
+        assert!(run_hook("sleep 10", &get_hook_env(&get_event()), Duration::from_millis(200)).is_err());
+        assert!(started_at.elapsed() < Duration::from_secs(5));
+    }
+}
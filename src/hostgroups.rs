@@ -0,0 +1,205 @@
+pub mod hostgroups {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    use crate::errors::errors::OperationError;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::http::http::send_post_request;
+    use crate::types::types::{EmptyResult, OperationResult, StringResult};
+    use crate::zabbix::zabbix;
+    use crate::zabbix::zabbix::{log_zabbix_error, ZabbixError, ZabbixRequest};
+
+    #[derive(Serialize)]
+    struct GetHostGroupParams {
+        output: Vec<String>,
+        filter: HashMap<String, String>,
+        #[serde(rename = "selectHosts")]
+        select_hosts: Vec<String>
+    }
+
+    #[derive(Deserialize)]
+    pub struct ZabbixHostGroup {
+        pub groupid: String,
+        pub hosts: Vec<ZabbixHostId>
+    }
+
+    #[derive(Deserialize)]
+    pub struct ZabbixHostId {
+        pub hostid: String
+    }
+
+    #[derive(Deserialize)]
+    struct GetHostGroupResponse {
+        result: Option<Vec<ZabbixHostGroup>>,
+        error: Option<ZabbixError>
+    }
+
+    #[derive(Serialize)]
+    struct CreateHostGroupParams {
+        name: String
+    }
+
+    #[derive(Deserialize)]
+    struct CreateHostGroupResult {
+        groupids: Vec<String>
+    }
+
+    #[derive(Deserialize)]
+    struct CreateHostGroupResponse {
+        result: Option<CreateHostGroupResult>,
+        error: Option<ZabbixError>
+    }
+
+    #[derive(Serialize)]
+    struct MassAddParams {
+        groups: Vec<GroupId>,
+        hosts: Vec<HostId>
+    }
+
+    #[derive(Serialize)]
+    struct GroupId {
+        groupid: String
+    }
+
+    #[derive(Serialize)]
+    struct HostId {
+        hostid: String
+    }
+
+    #[derive(Deserialize)]
+    struct MassAddResponse {
+        error: Option<ZabbixError>
+    }
+
+    /// Adds hosts to the group, group is created when missing
+    pub fn add_hosts_to_group(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                              group_name: &str, hosts: &[&ZabbixHost]) -> EmptyResult {
+        if hosts.is_empty() {
+            return Ok(())
+        }
+
+        let (groupid, host_ids) = match find_host_group(client, api_endpoint, auth_token, group_name)? {
+            Some(host_group) => {
+                let missing_host_ids = get_missing_host_ids(&host_group, hosts);
+                (host_group.groupid, missing_host_ids)
+            }
+            None => {
+                let groupid = create_host_group(client, api_endpoint, auth_token, group_name)?;
+                (groupid, hosts.iter().map(|host| host.hostid.to_string()).collect())
+            }
+        };
+
+        if host_ids.is_empty() {
+            return Ok(())
+        }
+
+        info!("adding {} host(s) to group '{}'", host_ids.len(), group_name);
+
+        let params = MassAddParams {
+            groups: vec![GroupId { groupid }],
+            hosts: host_ids.into_iter().map(|hostid| HostId { hostid }).collect()
+        };
+
+        let request: ZabbixRequest<MassAddParams> = ZabbixRequest::new(
+            "hostgroup.massadd", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let massadd_response: MassAddResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match massadd_response.error {
+                    Some(_) => {
+                        log_zabbix_error(&massadd_response.error);
+                        error!("unable to add hosts to group '{}'", group_name);
+                        Err(OperationError::Error)
+                    }
+                    None => Ok(())
+                }
+            }
+            Err(_) => {
+                error!("unable to add hosts to group '{}'", group_name);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Ids of hosts which aren't members of the group yet
+    pub fn get_missing_host_ids(host_group: &ZabbixHostGroup, hosts: &[&ZabbixHost]) -> Vec<String> {
+        hosts.iter()
+            .filter(|host| !host_group.hosts.iter().any(|member| member.hostid == host.hostid))
+            .map(|host| host.hostid.to_string())
+            .collect()
+    }
+
+    fn find_host_group(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                       name: &str) -> OperationResult<Option<ZabbixHostGroup>> {
+        let mut filter = HashMap::new();
+        filter.insert("name".to_string(), name.to_string());
+
+        let params = GetHostGroupParams {
+            output: vec!["groupid".to_string()],
+            filter,
+            select_hosts: vec!["hostid".to_string()]
+        };
+
+        let request: ZabbixRequest<GetHostGroupParams> = ZabbixRequest::new(
+            "hostgroup.get", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: GetHostGroupResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match search_response.result {
+                    Some(host_groups) => Ok(host_groups.into_iter().next()),
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        error!("unable to find host group '{}'", name);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find host group '{}'", name);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn create_host_group(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                         name: &str) -> StringResult {
+        let params = CreateHostGroupParams { name: name.to_string() };
+
+        let request: ZabbixRequest<CreateHostGroupParams> = ZabbixRequest::new(
+            "hostgroup.create", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let create_response: CreateHostGroupResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match create_response.result.and_then(|result| result.groupids.into_iter().next()) {
+                    Some(groupid) => {
+                        info!("host group '{}' has been created", name);
+                        Ok(groupid)
+                    }
+                    None => {
+                        log_zabbix_error(&create_response.error);
+                        error!("unable to create host group '{}'", name);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to create host group '{}'", name);
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
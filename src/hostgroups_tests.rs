@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod hostgroups_tests {
+    use crate::hostgroups::hostgroups::{get_missing_host_ids, ZabbixHostGroup, ZabbixHostId};
+    use crate::hosts::hosts::ZabbixHost;
+
+    fn get_host(hostid: &str) -> ZabbixHost {
+        ZabbixHost { hostid: hostid.to_string(), host: format!("web-{}", hostid),
+                     maintenance_status: "0".to_string(), status: "0".to_string(),
+                     proxy_hostid: "0".to_string() }
+    }
+
+    #[test]
+    fn only_hosts_outside_of_group_should_be_added() {
+        let host_group = ZabbixHostGroup {
+            groupid: "21".to_string(),
+            hosts: vec![ZabbixHostId { hostid: "10001".to_string() }]
+        };
+
+        let first_host = get_host("10001");
+        let second_host = get_host("10002");
+
+        assert_eq!(get_missing_host_ids(&host_group, &[&first_host, &second_host]), vec!["10002"]);
+        assert!(get_missing_host_ids(&host_group, &[&first_host]).is_empty());
+    }
+}
@@ -1,4 +1,6 @@
 pub mod hosts {
+    use std::collections::HashMap;
+
     use serde::Deserialize;
     use serde::Serialize;
 
@@ -8,9 +10,14 @@ pub mod hosts {
     use crate::zabbix::zabbix;
     use crate::zabbix::zabbix::{log_zabbix_error, ZabbixError, ZabbixRequest};
 
+    const HOST_MAINTENANCE_STATUS_ON: &str = "1";
+    const HOST_STATUS_MONITORED: &str = "0";
+    const NO_PROXY_ID: &str = "0";
+
     #[derive(Serialize)]
     struct SearchRequestParams {
-        hostids: Vec<String>
+        hostids: Vec<String>,
+        output: Vec<String>
     }
 
     #[derive(Deserialize)]
@@ -19,10 +26,82 @@ pub mod hosts {
         error: Option<ZabbixError>
     }
 
+    #[derive(Serialize)]
+    struct SearchByNamesRequestParams {
+        output: Vec<String>,
+        filter: HashMap<String, Vec<String>>
+    }
+
+    #[derive(Serialize)]
+    struct HostIdsRequestParams {
+        output: Vec<String>,
+        with_items: bool,
+        /// Flag parameter, only monitored hosts when present
+        #[serde(skip_serializing_if = "Option::is_none")]
+        monitored_hosts: Option<bool>
+    }
+
+    #[derive(Deserialize)]
+    struct HostIdsResponse {
+        result: Option<Vec<HostId>>,
+        error: Option<ZabbixError>
+    }
+
+    #[derive(Deserialize)]
+    struct HostId {
+        hostid: String
+    }
+
+    #[derive(Serialize)]
+    struct ProxySearchRequestParams {
+        output: Vec<String>
+    }
+
+    #[derive(Deserialize)]
+    struct ZabbixProxy {
+        proxyid: String,
+        host: String
+    }
+
+    #[derive(Deserialize)]
+    struct ProxySearchResponse {
+        result: Option<Vec<ZabbixProxy>>,
+        error: Option<ZabbixError>
+    }
+
     #[derive(Deserialize)]
     pub struct ZabbixHost {
         pub hostid: String,
-        pub host: String
+        pub host: String,
+        /// "1" - host is in maintenance, absent when not requested
+        #[serde(default)]
+        pub maintenance_status: String,
+        /// "0" - monitored host, absent when not requested
+        #[serde(default)]
+        pub status: String,
+        /// Proxy which monitors the host, "0" - monitored by server, absent when not requested
+        #[serde(default)]
+        pub proxy_hostid: String
+    }
+
+    impl ZabbixHost {
+        pub fn is_in_maintenance(&self) -> bool {
+            self.maintenance_status == HOST_MAINTENANCE_STATUS_ON
+        }
+
+        pub fn is_monitored(&self) -> bool {
+            self.status == HOST_STATUS_MONITORED
+        }
+
+        pub fn is_proxy_monitored(&self) -> bool {
+            !self.proxy_hostid.is_empty() && self.proxy_hostid != NO_PROXY_ID
+        }
+    }
+
+    /// Only fields of `ZabbixHost`, full host objects are large on servers with many hosts
+    fn get_host_output() -> Vec<String> {
+        vec!["hostid".to_string(), "host".to_string(),
+             "maintenance_status".to_string(), "status".to_string(), "proxy_hostid".to_string()]
     }
 
     pub fn find_hosts(client: &reqwest::blocking::Client,
@@ -30,7 +109,10 @@ pub mod hosts {
                       ids: Vec<String>) -> OperationResult<Vec<ZabbixHost>> {
         info!("find hosts by ids..");
 
-        let params = SearchRequestParams { hostids: ids };
+        let params = SearchRequestParams {
+            hostids: ids,
+            output: get_host_output()
+        };
 
         let request: ZabbixRequest<SearchRequestParams> = ZabbixRequest::new(
             "host.get", params, api_token
@@ -56,4 +138,122 @@ pub mod hosts {
             }
         }
     }
+
+    /// Returns ids of all hosts which have items.
+    pub fn find_host_ids(client: &reqwest::blocking::Client,
+                         api_endpoint: &str, api_token: &str,
+                         monitored_only: bool) -> OperationResult<Vec<String>> {
+        info!("find hosts with items..");
+
+        let params = HostIdsRequestParams {
+            output: vec!["hostid".to_string()],
+            with_items: true,
+            monitored_hosts: if monitored_only { Some(true) } else { None }
+        };
+
+        let request: ZabbixRequest<HostIdsRequestParams> = ZabbixRequest::new(
+            "host.get", params, api_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: HostIdsResponse = serde_json::from_str(&response)
+                                                .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match search_response.result {
+                    Some(host_ids) => {
+                        debug!("hosts with items found: {}", host_ids.len());
+                        Ok(host_ids.into_iter().map(|host| host.hostid).collect())
+                    }
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        error!("unable to find zabbix hosts");
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find zabbix hosts");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Finds hosts by technical names.
+    pub fn find_hosts_by_names(client: &reqwest::blocking::Client,
+                               api_endpoint: &str, api_token: &str,
+                               names: Vec<String>) -> OperationResult<Vec<ZabbixHost>> {
+        info!("find hosts by names..");
+
+        let mut filter_params = HashMap::new();
+        filter_params.insert("host".to_string(), names);
+
+        let params = SearchByNamesRequestParams { output: get_host_output(), filter: filter_params };
+
+        let request: ZabbixRequest<SearchByNamesRequestParams> = ZabbixRequest::new(
+            "host.get", params, api_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: SearchResponse = serde_json::from_str(&response)
+                                                .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match search_response.result {
+                    Some(hosts) => Ok(hosts),
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        error!("unable to find zabbix hosts");
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find zabbix hosts");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Names of proxies by ids. Reading proxies requires admin role, empty map otherwise.
+    pub fn find_proxy_names(client: &reqwest::blocking::Client,
+                            api_endpoint: &str, api_token: &str) -> HashMap<String, String> {
+        let params = ProxySearchRequestParams {
+            output: vec!["proxyid".to_string(), "host".to_string()]
+        };
+
+        let request: ZabbixRequest<ProxySearchRequestParams> = ZabbixRequest::new(
+            "proxy.get", params, api_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: ProxySearchResponse = serde_json::from_str(&response)
+                                                .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match search_response.result {
+                    Some(proxies) => proxies.into_iter().map(|proxy| (proxy.proxyid, proxy.host)).collect(),
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        warn!("unable to find zabbix proxies, proxies are reported by ids");
+                        HashMap::new()
+                    }
+                }
+            }
+            Err(_) => {
+                warn!("unable to find zabbix proxies, proxies are reported by ids");
+                HashMap::new()
+            }
+        }
+    }
+
+    /// Proxy name for reports, id when name is unknown
+    pub fn get_proxy_name(host: &ZabbixHost, proxy_names: &HashMap<String, String>) -> Option<String> {
+        if !host.is_proxy_monitored() {
+            return None
+        }
+
+        Some(proxy_names.get(&host.proxy_hostid).cloned()
+                .unwrap_or_else(|| format!("proxy #{}", host.proxy_hostid)))
+    }
 }
@@ -1,8 +1,17 @@
 pub mod http {
+    use std::time::Instant;
+
     use serde::Serialize;
 
+    use crate::audit::audit::{is_mutation, record_mutation};
+    use crate::circuit::circuit::{is_api_circuit_open, record_api_call_result};
     use crate::errors::errors::OperationError;
+    use crate::metrics::metrics::{record_api_call, record_api_error};
+    use crate::recording::recording::{is_replaying, record_response, replay_response};
+    use crate::telemetry::telemetry::start_client_span;
+    use crate::timings::timings::record_api_call_timing;
     use crate::types::types::StringResult;
+    use crate::zabbix::zabbix::get_response_error;
 
     const CONTENT_TYPE_HEADER: &str = "Content-Type";
     const CONTENT_TYPE_JSON: &str = "application/json";
@@ -11,30 +20,89 @@ pub mod http {
                                 url: &str, request: T) -> StringResult {
         debug!("send post request to '{}'", url);
 
-        let request_body = serde_json::to_string(&request).unwrap();
+        let request_value = serde_json::to_value(&request).unwrap();
+        let method = request_value["method"].as_str().unwrap_or_default().to_string();
+
+        if is_api_circuit_open(url) {
+            debug!("api circuit breaker is open, skip request '{}'", method);
+            return Err(OperationError::Error)
+        }
+
+        if is_replaying() {
+            debug!("replay response of '{}'", method);
+            return replay_response(&method, &request_value["params"])
+        }
+
+        let mut span = start_client_span(&format!("zabbix {}", method));
+        span.set_attribute("rpc.system", "jsonrpc");
+        span.set_attribute("rpc.method", &method);
 
-        match client.post(url)
+        let request_body = request_value.to_string();
+
+        let started_at = Instant::now();
+
+        let result = client.post(url)
             .body(request_body)
             .header(CONTENT_TYPE_HEADER, CONTENT_TYPE_JSON)
-            .send() {
+            .send();
+
+        let duration = started_at.elapsed();
+
+        record_api_call_timing(url, &method, started_at, duration);
+        record_api_call_result(url, matches!(&result, Ok(response) if response.status() == reqwest::StatusCode::OK));
+
+        if !matches!(&result, Ok(response) if response.status() == reqwest::StatusCode::OK) {
+            span.set_error();
+        }
+
+        let audited = is_mutation(&method);
+
+        match result {
             Ok(response) => {
                 let response_status = response.status();
                 let response_text = response.text().unwrap();
 
+                // Zabbix API reports errors in body of successful response
+                let response_error = get_response_error(&response_text);
+
+                record_api_call(url, duration, response_status == reqwest::StatusCode::OK && response_error.is_none());
+
+                if let Some(error) = &response_error {
+                    record_api_error(url, &format!("{}: error {}: {} {}", method, error.code, error.message, error.data));
+                }
+
+                if audited {
+                    let audit_response = if response_status == reqwest::StatusCode::OK {
+                        Ok(response_text.as_str())
+                    } else {
+                        Err(format!("unexpected server response code {}", response_status))
+                    };
+
+                    record_mutation(url, &method, &request_value["params"], audit_response);
+                }
+
                 debug!("---[HTTP RESPONSE]----");
                 debug!("{}", response_text);
                 debug!("---[/HTTP RESPONSE]----");
 
                 if response_status == reqwest::StatusCode::OK {
+                    record_response(&method, &request_value["params"], &response_text);
                     Ok(response_text)
 
                 } else {
                     error!("unexpected server response code {}", response_status);
+                    record_api_error(url, &format!("{}: unexpected server response code {}", method, response_status));
                     Err(OperationError::Error)
                 }
             }
             Err(e) => {
                 error!("unable to create trigger: '{}'", e);
+                record_api_call(url, duration, false);
+                record_api_error(url, &format!("{}: {}", method, e));
+
+                if audited {
+                    record_mutation(url, &method, &request_value["params"], Err(e.to_string()));
+                }
                 Err(OperationError::Error)
             }
         }
@@ -0,0 +1,39 @@
+pub mod httpproxy {
+    use regex::Regex;
+
+    use crate::config::config::HttpProxyConfig;
+    use crate::errors::errors::OperationError;
+    use crate::types::types::OperationResult;
+
+    /// Proxies of web scenarios, the first rule matching url wins
+    pub struct HttpProxies {
+        default_proxy: String,
+        rules: Vec<(Regex, String)>
+    }
+
+    impl HttpProxies {
+        pub fn new(http_proxy_config: &HttpProxyConfig) -> OperationResult<HttpProxies> {
+            let mut rules = vec![];
+
+            for rule in &http_proxy_config.rules {
+                match Regex::new(&rule.pattern) {
+                    Ok(pattern) => rules.push((pattern, rule.proxy.to_string())),
+                    Err(e) => {
+                        error!("invalid http-proxy rule pattern '{}': {}", rule.pattern, e);
+                        return Err(OperationError::Error)
+                    }
+                }
+            }
+
+            Ok(HttpProxies { default_proxy: http_proxy_config.proxy.to_string(), rules })
+        }
+
+        /// Empty for direct requests
+        pub fn get_http_proxy(&self, url: &str) -> &str {
+            self.rules.iter()
+                .find(|(pattern, _)| pattern.is_match(url))
+                .map(|(_, proxy)| proxy.as_str())
+                .unwrap_or(&self.default_proxy)
+        }
+    }
+}
@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod httpproxy_tests {
+    use crate::config::config::{HttpProxyConfig, HttpProxyRuleConfig};
+    use crate::httpproxy::httpproxy::HttpProxies;
+
+    #[test]
+    fn proxy_of_first_matching_rule_should_be_used() {
+        let proxies = HttpProxies::new(&HttpProxyConfig {
+            proxy: "http://egress.example.com:3128".to_string(),
+            rules: vec![
+                HttpProxyRuleConfig { pattern: "\\.internal/".to_string(), proxy: String::new() },
+                HttpProxyRuleConfig { pattern: "^https://legacy\\.".to_string(),
+                                      proxy: "http://legacy-proxy:8080".to_string() }
+            ]
+        }).unwrap();
+
+        assert_eq!(proxies.get_http_proxy("https://shop.example.com/"), "http://egress.example.com:3128");
+        assert_eq!(proxies.get_http_proxy("https://api.internal/health"), "");
+        assert_eq!(proxies.get_http_proxy("https://legacy.example.com/"), "http://legacy-proxy:8080");
+    }
+
+    #[test]
+    fn invalid_rule_pattern_should_be_rejected() {
+        let http_proxy_config = HttpProxyConfig {
+            proxy: String::new(),
+            rules: vec![HttpProxyRuleConfig { pattern: "(".to_string(), proxy: String::new() }]
+        };
+
+        assert!(HttpProxies::new(&http_proxy_config).is_err());
+    }
+}
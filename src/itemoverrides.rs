@@ -0,0 +1,89 @@
+pub mod itemoverrides {
+    use serde_json::{Map, Value};
+
+    use crate::scripting::scripting::UrlOverrides;
+    use crate::zabbix::zabbix::ZabbixTag;
+
+    /// Line of item description with JSON overrides, i.e. `wszl: {"status-codes": "200,401"}`
+    const DESCRIPTION_PREFIX: &str = "wszl:";
+
+    /// Item tags with overrides, i.e. `wszl-severity: 2`. Tags win over description.
+    const TAG_PREFIX: &str = "wszl-";
+
+    const STATUS_CODES_PROPERTY: &str = "status-codes";
+    const REQUIRED_PROPERTY: &str = "required";
+    const TIMEOUT_PROPERTY: &str = "timeout";
+    const SEVERITY_PROPERTY: &str = "severity";
+    const SKIP_PROPERTY: &str = "skip";
+
+    /// Overrides carried by the item itself, returns error message for unsupported values
+    pub fn get_item_overrides(description: &str, tags: &[ZabbixTag]) -> Result<UrlOverrides, String> {
+        let mut properties = get_description_properties(description)?;
+
+        for tag in tags {
+            if let Some(property) = tag.tag.strip_prefix(TAG_PREFIX) {
+                properties.insert(property.to_string(), Value::String(tag.value.to_string()));
+            }
+        }
+
+        let mut overrides = UrlOverrides::default();
+
+        for (property, value) in &properties {
+            match property.as_str() {
+                STATUS_CODES_PROPERTY => overrides.status_codes = Some(get_string(property, value)?),
+                REQUIRED_PROPERTY => overrides.required = Some(get_string(property, value)?),
+                TIMEOUT_PROPERTY => overrides.timeout = Some(get_string(property, value)?),
+                SEVERITY_PROPERTY => overrides.severity = Some(get_severity(value)?),
+                SKIP_PROPERTY => overrides.skip = get_bool(property, value)?,
+                _ => return Err(format!("unsupported override '{}'", property))
+            }
+        }
+
+        Ok(overrides)
+    }
+
+    fn get_description_properties(description: &str) -> Result<Map<String, Value>, String> {
+        let overrides_line = description.lines().map(|line| line.trim())
+                                        .find(|line| line.starts_with(DESCRIPTION_PREFIX));
+
+        match overrides_line {
+            Some(line) => {
+                match serde_json::from_str(line[DESCRIPTION_PREFIX.len()..].trim()) {
+                    Ok(Value::Object(properties)) => Ok(properties),
+                    _ => Err(format!("expected JSON object after '{}' in item description", DESCRIPTION_PREFIX))
+                }
+            }
+            None => Ok(Map::new())
+        }
+    }
+
+    fn get_string(property: &str, value: &Value) -> Result<String, String> {
+        match value {
+            Value::String(value) => Ok(value.to_string()),
+            Value::Number(value) => Ok(value.to_string()),
+            _ => Err(format!("invalid '{}' value '{}'", property, value))
+        }
+    }
+
+    fn get_severity(value: &Value) -> Result<u8, String> {
+        let severity = match value {
+            Value::Number(value) => value.as_u64(),
+            Value::String(value) => value.parse().ok(),
+            _ => None
+        };
+
+        match severity {
+            Some(severity) if severity <= 5 => Ok(severity as u8),
+            _ => Err(format!("invalid '{}' value '{}', expected 0-5", SEVERITY_PROPERTY, value))
+        }
+    }
+
+    fn get_bool(property: &str, value: &Value) -> Result<bool, String> {
+        match value {
+            Value::Bool(value) => Ok(*value),
+            Value::String(value) if value == "true" || value == "1" || value.is_empty() => Ok(true),
+            Value::String(value) if value == "false" || value == "0" => Ok(false),
+            _ => Err(format!("invalid '{}' value '{}', expected true or false", property, value))
+        }
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod itemoverrides_tests {
+    use crate::itemoverrides::itemoverrides::get_item_overrides;
+    use crate::zabbix::zabbix::ZabbixTag;
+
+    fn get_tag(tag: &str, value: &str) -> ZabbixTag {
+        ZabbixTag { tag: tag.to_string(), value: value.to_string() }
+    }
+
+    #[test]
+    fn overrides_should_be_read_from_description_json() {
+        let description = "Shop frontend\nwszl: {\"status-codes\": 401, \"required\": \"Login\", \"severity\": 2, \"timeout\": \"5s\"}";
+
+        let overrides = get_item_overrides(description, &[]).unwrap();
+
+        assert_eq!(overrides.status_codes.unwrap(), "401");
+        assert_eq!(overrides.required.unwrap(), "Login");
+        assert_eq!(overrides.severity, Some(2));
+        assert_eq!(overrides.timeout.unwrap(), "5s");
+        assert!(!overrides.skip);
+    }
+
+    #[test]
+    fn tags_should_win_over_description() {
+        let tags = vec![get_tag("wszl-severity", "5"), get_tag("wszl-skip", ""), get_tag("team", "web")];
+
+        let overrides = get_item_overrides("wszl: {\"severity\": 2}", &tags).unwrap();
+
+        assert_eq!(overrides.severity, Some(5));
+        assert!(overrides.skip);
+        assert!(overrides.status_codes.is_none());
+    }
+
+    #[test]
+    fn invalid_overrides_should_be_rejected() {
+        assert!(get_item_overrides("wszl: not a json", &[]).is_err());
+        assert!(get_item_overrides("", &[get_tag("wszl-severity", "7")]).is_err());
+        assert!(get_item_overrides("", &[get_tag("wszl-interval", "5m")]).is_err());
+        assert!(get_item_overrides("wszl: {\"skip\": \"maybe\"}", &[]).is_err());
+    }
+
+    #[test]
+    fn item_without_overrides_should_keep_defaults() {
+        let overrides = get_item_overrides("Shop frontend", &[get_tag("team", "web")]).unwrap();
+
+        assert_eq!(overrides, Default::default());
+    }
+}
@@ -4,16 +4,33 @@ pub mod items {
     use serde::Deserialize;
     use serde::Serialize;
 
+    use crate::config::config::ItemTagConfig;
     use crate::errors::errors::OperationError;
     use crate::http::http::send_post_request;
     use crate::types::types::OperationResult;
     use crate::zabbix::zabbix;
-    use crate::zabbix::zabbix::{log_zabbix_error, ZabbixError, ZabbixRequest};
+    use crate::zabbix::zabbix::{get_tag_filter, log_zabbix_error, ZabbixError, ZabbixRequest, ZabbixTag,
+                                ZabbixTagFilter};
 
     #[derive(Serialize)]
     struct ItemSearchParams {
+        hostids: Vec<String>,
+        output: Vec<String>,
         sortfield: String,
-        search: HashMap<String, String>,
+        search: HashMap<String, Vec<String>>,
+        #[serde(rename = "searchByAny")]
+        search_by_any: bool,
+        #[serde(rename = "searchWildcardsEnabled")]
+        search_wildcards_enabled: bool,
+        /// Item tags may carry url overrides
+        #[serde(rename = "selectTags")]
+        select_tags: Vec<String>,
+        /// Opt-in tags from `items.tags`
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<ZabbixTagFilter>,
+        /// Flag parameter, only enabled items of monitored hosts when present
+        #[serde(skip_serializing_if = "Option::is_none")]
+        monitored: Option<bool>
     }
 
     #[derive(Deserialize)]
@@ -22,25 +39,107 @@ pub mod items {
         error: Option<ZabbixError>
     }
 
+    #[derive(Serialize)]
+    struct LldDataItemSearchParams {
+        hostids: Vec<String>,
+        output: Vec<String>,
+        filter: HashMap<String, String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<ZabbixTagFilter>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        monitored: Option<bool>
+    }
+
+    #[derive(Deserialize)]
+    struct LldDataItemSearchResponse {
+        result: Option<Vec<ZabbixLldDataItem>>,
+        error: Option<ZabbixError>
+    }
+
+    /// Item which holds low level discovery JSON in its last value
+    #[derive(Deserialize)]
+    pub struct ZabbixLldDataItem {
+        /// Absent in responses recorded by older versions
+        #[serde(default)]
+        pub itemid: String,
+        pub name: String,
+        pub key_: String,
+        pub hostid: String,
+        pub lastvalue: String,
+        /// "1" if item isn't supported
+        #[serde(default)]
+        pub state: String,
+        /// Unix time of the last value, "0" if item has no data
+        #[serde(default)]
+        pub lastclock: String
+    }
+
+    #[derive(Serialize)]
+    struct WebItemSearchParams {
+        output: Vec<String>,
+        filter: HashMap<String, Vec<String>>,
+        webitems: bool
+    }
+
+    #[derive(Deserialize)]
+    struct WebItemSearchResponse {
+        result: Option<Vec<ZabbixWebItem>>,
+        error: Option<ZabbixError>
+    }
+
+    /// Item of web scenario, i.e. `web.test.fail[<scenario name>]`
+    #[derive(Deserialize)]
+    pub struct ZabbixWebItem {
+        pub itemid: String,
+        pub hostid: String,
+        pub key_: String,
+        pub lastvalue: String,
+        /// Unix time of the last check, "0" if scenario has never been executed
+        pub lastclock: String
+    }
+
     #[derive(Deserialize)]
     pub struct ZabbixItem {
+        /// Absent in responses recorded by older versions
+        #[serde(default)]
+        pub itemid: String,
         pub name: String,
         pub key_: String,
-        pub hostid: String
+        pub hostid: String,
+        /// May contain url overrides, see `get_item_overrides`
+        #[serde(default)]
+        pub description: String,
+        #[serde(default)]
+        pub tags: Vec<ZabbixTag>,
+        /// "1" if item isn't supported
+        #[serde(default)]
+        pub state: String,
+        /// Unix time of the last value, "0" if item has no data
+        #[serde(default)]
+        pub lastclock: String
     }
 
     pub fn find_zabbix_items(client: &reqwest::blocking::Client,
                              api_endpoint: &str,
-                             auth_token: &str, item_key_search_mask: &str) ->
-                                                                OperationResult<Vec<ZabbixItem>> {
+                             auth_token: &str, item_key_search_masks: &[String],
+                             host_ids: &[String], monitored_only: bool,
+                             item_tags: &[ItemTagConfig]) -> OperationResult<Vec<ZabbixItem>> {
         info!("searching items..");
 
         let mut search_params = HashMap::new();
-        search_params.insert("key_".to_string(), item_key_search_mask.to_string());
+        search_params.insert("key_".to_string(), item_key_search_masks.to_vec());
 
         let params = ItemSearchParams {
+            hostids: host_ids.to_vec(),
+            output: vec!["itemid".to_string(), "name".to_string(), "key_".to_string(), "hostid".to_string(),
+                         "description".to_string(), "state".to_string(), "lastclock".to_string()],
             sortfield: "name".to_string(),
-            search: search_params
+            search: search_params,
+            search_by_any: true,
+            search_wildcards_enabled: true,
+            select_tags: vec!["tag".to_string(), "value".to_string()],
+            tags: get_item_tag_filters(item_tags),
+            monitored: get_flag(monitored_only)
         };
 
         let request: ZabbixRequest<ItemSearchParams> = ZabbixRequest::new(
@@ -67,4 +166,98 @@ pub mod items {
             }
         }
     }
+
+    pub fn find_lld_data_items(client: &reqwest::blocking::Client,
+                               api_endpoint: &str, auth_token: &str, item_key: &str,
+                               host_ids: &[String], monitored_only: bool,
+                               item_tags: &[ItemTagConfig]) -> OperationResult<Vec<ZabbixLldDataItem>> {
+        info!("searching discovery data items '{}'..", item_key);
+
+        let mut filter_params = HashMap::new();
+        filter_params.insert("key_".to_string(), item_key.to_string());
+
+        let params = LldDataItemSearchParams {
+            hostids: host_ids.to_vec(),
+            output: vec!["itemid".to_string(), "name".to_string(), "key_".to_string(), "hostid".to_string(),
+                         "lastvalue".to_string(), "state".to_string(), "lastclock".to_string()],
+            filter: filter_params,
+            tags: get_item_tag_filters(item_tags),
+            monitored: get_flag(monitored_only)
+        };
+
+        let request: ZabbixRequest<LldDataItemSearchParams> = ZabbixRequest::new(
+            "item.get", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: LldDataItemSearchResponse = serde_json::from_str(&response)
+                                                .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match search_response.result {
+                    Some(items) => Ok(items),
+                    None => {
+                        error!("unable to find discovery data items");
+                        log_zabbix_error(&search_response.error);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find discovery data items");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Tag filters are combined by Zabbix: different tags must all match, same tags - any of them
+    fn get_item_tag_filters(item_tags: &[ItemTagConfig]) -> Vec<ZabbixTagFilter> {
+        item_tags.iter().map(|item_tag| get_tag_filter(&item_tag.tag, item_tag.value.as_deref())).collect()
+    }
+
+    /// Web scenario items with given keys on any host
+    pub fn find_web_items(client: &reqwest::blocking::Client,
+                          api_endpoint: &str, auth_token: &str,
+                          item_keys: Vec<String>) -> OperationResult<Vec<ZabbixWebItem>> {
+        info!("searching web scenario items..");
+
+        let mut filter_params = HashMap::new();
+        filter_params.insert("key_".to_string(), item_keys);
+
+        let params = WebItemSearchParams {
+            output: vec!["itemid".to_string(), "hostid".to_string(), "key_".to_string(),
+                         "lastvalue".to_string(), "lastclock".to_string()],
+            filter: filter_params,
+            webitems: true
+        };
+
+        let request: ZabbixRequest<WebItemSearchParams> = ZabbixRequest::new(
+            "item.get", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: WebItemSearchResponse = serde_json::from_str(&response)
+                                                .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match search_response.result {
+                    Some(items) => Ok(items),
+                    None => {
+                        error!("unable to find web scenario items");
+                        log_zabbix_error(&search_response.error);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find web scenario items");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Zabbix API flag parameters are enabled by presence, whatever the value is
+    fn get_flag(enabled: bool) -> Option<bool> {
+        if enabled { Some(true) } else { None }
+    }
 }
@@ -0,0 +1,83 @@
+pub mod latency {
+    use regex::Regex;
+
+    use crate::config::config::LatencyConfig;
+    use crate::errors::errors::OperationError;
+    use crate::graphs::graphs::get_graph_item_keys;
+    use crate::templates::templates::ObjectNames;
+    use crate::triggers::triggers::TriggerParams;
+    use crate::types::types::OperationResult;
+    use crate::zabbix::zabbix::{get_managed_tag, ZabbixTag};
+
+    pub const LATENCY_TAG_NAME: &str = "check";
+    pub const LATENCY_TAG_VALUE: &str = "latency";
+
+    /// Number of the last response times which all have to be above threshold
+    const LATENCY_SAMPLES: u8 = 3;
+
+    /// Response time thresholds of urls, the first rule matching url wins
+    pub struct LatencyThresholds {
+        default_threshold_ms: u64,
+        rules: Vec<(Regex, u64)>,
+        pub priority: u8
+    }
+
+    impl LatencyThresholds {
+        pub fn new(latency_config: &LatencyConfig) -> OperationResult<LatencyThresholds> {
+            let mut rules = vec![];
+
+            for rule in &latency_config.rules {
+                match Regex::new(&rule.pattern) {
+                    Ok(pattern) => rules.push((pattern, rule.threshold_ms)),
+                    Err(e) => {
+                        error!("invalid latency rule pattern '{}': {}", rule.pattern, e);
+                        return Err(OperationError::Error)
+                    }
+                }
+            }
+
+            Ok(LatencyThresholds {
+                default_threshold_ms: latency_config.threshold_ms,
+                rules,
+                priority: latency_config.priority
+            })
+        }
+
+        pub fn get_threshold_ms(&self, url: &str) -> u64 {
+            self.rules.iter()
+                .find(|(pattern, _)| pattern.is_match(url))
+                .map(|(_, threshold_ms)| *threshold_ms)
+                .unwrap_or(self.default_threshold_ms)
+        }
+    }
+
+    pub fn get_latency_tag() -> ZabbixTag {
+        ZabbixTag {
+            tag: LATENCY_TAG_NAME.to_string(),
+            value: LATENCY_TAG_VALUE.to_string()
+        }
+    }
+
+    pub fn is_latency_trigger(tags: &[ZabbixTag]) -> bool {
+        tags.iter().any(|tag| tag.tag == LATENCY_TAG_NAME && tag.value == LATENCY_TAG_VALUE)
+    }
+
+    /// Trigger on response time of the first step of web scenario, Zabbix 5.4+ expression syntax
+    pub fn get_latency_trigger_params(host: &str, url: &str, names: &ObjectNames,
+                                      thresholds: &LatencyThresholds) -> TriggerParams {
+        let (response_time_key, _) = get_graph_item_keys(names);
+        let threshold_ms = thresholds.get_threshold_ms(url);
+
+        TriggerParams {
+            description: format!("Site '{}' responds slower than {}ms", url, threshold_ms),
+            expression: format!("min(/{}/{},#{})>{}", host, response_time_key, LATENCY_SAMPLES,
+                                threshold_ms as f64 / 1000.0),
+            priority: thresholds.priority.to_string(),
+            url: url.to_string(),
+            comments: String::new(),
+            tags: vec![get_managed_tag(), get_latency_tag()],
+            correlation_mode: None,
+            correlation_tag: String::new()
+        }
+    }
+}
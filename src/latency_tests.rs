@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod latency_tests {
+    use std::collections::HashMap;
+
+    use crate::config::config::{LatencyConfig, LatencyRuleConfig, TemplatesConfig};
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::latency::latency::{get_latency_trigger_params, is_latency_trigger, LatencyThresholds};
+    use crate::templates::templates::NameTemplates;
+
+    fn get_thresholds() -> LatencyThresholds {
+        LatencyThresholds::new(&LatencyConfig {
+            threshold_ms: 3000,
+            priority: 2,
+            rules: vec![
+                LatencyRuleConfig { pattern: "/api/".to_string(), threshold_ms: 500 },
+                LatencyRuleConfig { pattern: "^https://api\\.".to_string(), threshold_ms: 800 }
+            ]
+        }).unwrap()
+    }
+
+    #[test]
+    fn threshold_of_first_matching_rule_should_be_used() {
+        let thresholds = get_thresholds();
+
+        assert_eq!(thresholds.get_threshold_ms("https://example.com/api/users"), 500);
+        assert_eq!(thresholds.get_threshold_ms("https://api.example.com/api/users"), 500);
+        assert_eq!(thresholds.get_threshold_ms("https://api.example.com/health"), 800);
+    }
+
+    #[test]
+    fn default_threshold_should_be_used_without_matching_rule() {
+        assert_eq!(get_thresholds().get_threshold_ms("https://www.example.com/pricing"), 3000);
+    }
+
+    #[test]
+    fn invalid_rule_pattern_should_be_rejected() {
+        let latency_config = LatencyConfig {
+            threshold_ms: 3000,
+            priority: 2,
+            rules: vec![LatencyRuleConfig { pattern: "(".to_string(), threshold_ms: 500 }]
+        };
+
+        assert!(LatencyThresholds::new(&latency_config).is_err());
+    }
+
+    #[test]
+    fn latency_trigger_should_use_threshold_of_url() {
+        let url = "https://example.com/api/users";
+
+        let host = ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(),
+                                maintenance_status: "0".to_string(), status: "0".to_string(),
+                                proxy_hostid: "0".to_string() };
+
+        let names = NameTemplates::new(&TemplatesConfig::default()).unwrap()
+                        .get_object_names(&host, url, &HashMap::new(), None).unwrap();
+
+        let params = get_latency_trigger_params("web-01", url, &names, &get_thresholds());
+
+        assert_eq!(params.description, "Site 'https://example.com/api/users' responds slower than 500ms");
+        assert_eq!(params.expression,
+                   "min(/web-01/web.test.time[Check index page 'https://example.com/api/users',Get page,resp],#3)>0.5");
+        assert_eq!(params.priority, "2");
+        assert!(is_latency_trigger(&params.tags));
+    }
+}
@@ -0,0 +1,59 @@
+pub mod limits {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// Guardrails against misconfigured discovery emitting thousands of urls.
+    /// Limits of 0 are disabled.
+    pub struct ScenarioLimits {
+        max_per_host: usize,
+        max_per_run: usize,
+        counts: Mutex<ScenarioCounts>
+    }
+
+    #[derive(Default)]
+    struct ScenarioCounts {
+        /// Managed scenarios of hosts, existing ones included
+        hosts: HashMap<String, usize>,
+        /// Scenarios created (or planned) by this run
+        run: usize
+    }
+
+    impl ScenarioLimits {
+        pub fn new(max_per_host: usize, max_per_run: usize) -> ScenarioLimits {
+            ScenarioLimits { max_per_host, max_per_run, counts: Mutex::new(ScenarioCounts::default()) }
+        }
+
+        /// Takes a slot for a new scenario of host which already has `existing` managed scenarios,
+        /// returns reason when a limit would be exceeded
+        pub fn reserve(&self, host: &str, existing: usize) -> Result<(), String> {
+            let mut counts = self.counts.lock().unwrap();
+
+            if self.max_per_run > 0 && counts.run >= self.max_per_run {
+                return Err(format!("limit of {} new scenarios per run has been reached", self.max_per_run))
+            }
+
+            let host_count = counts.hosts.entry(host.to_string()).or_insert(existing);
+
+            if self.max_per_host > 0 && *host_count >= self.max_per_host {
+                return Err(format!("limit of {} scenarios per host has been reached on host '{}'",
+                                   self.max_per_host, host))
+            }
+
+            *host_count += 1;
+            counts.run += 1;
+
+            Ok(())
+        }
+
+        /// Returns slot of scenario which hasn't been created
+        pub fn release(&self, host: &str) {
+            let mut counts = self.counts.lock().unwrap();
+
+            if let Some(host_count) = counts.hosts.get_mut(host) {
+                *host_count = host_count.saturating_sub(1);
+            }
+
+            counts.run = counts.run.saturating_sub(1);
+        }
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod limits_tests {
+    use crate::limits::limits::ScenarioLimits;
+
+    #[test]
+    fn existing_scenarios_should_count_to_host_limit() {
+        let limits = ScenarioLimits::new(3, 0);
+
+        assert!(limits.reserve("web-01", 2).is_ok());
+        assert_eq!(limits.reserve("web-01", 2).unwrap_err(),
+                   "limit of 3 scenarios per host has been reached on host 'web-01'");
+        assert!(limits.reserve("web-02", 0).is_ok());
+    }
+
+    #[test]
+    fn new_scenarios_should_count_to_run_limit() {
+        let limits = ScenarioLimits::new(0, 2);
+
+        assert!(limits.reserve("web-01", 50).is_ok());
+        assert!(limits.reserve("web-02", 0).is_ok());
+        assert_eq!(limits.reserve("web-03", 0).unwrap_err(), "limit of 2 new scenarios per run has been reached");
+    }
+
+    #[test]
+    fn released_slot_should_be_available_again() {
+        let limits = ScenarioLimits::new(1, 1);
+
+        assert!(limits.reserve("web-01", 0).is_ok());
+        limits.release("web-01");
+
+        assert!(limits.reserve("web-01", 0).is_ok());
+        assert!(limits.reserve("web-01", 0).is_err());
+    }
+
+    #[test]
+    fn zero_limits_should_be_disabled() {
+        let limits = ScenarioLimits::new(0, 0);
+
+        for _ in 0..100 {
+            assert!(limits.reserve("web-01", 1000).is_ok());
+        }
+    }
+}
@@ -0,0 +1,119 @@
+pub mod list {
+    use reqwest::blocking::Client;
+
+    use crate::config::config::Config;
+    use crate::errors::errors::OperationError;
+    use crate::session::session::{close_api_session, open_api_session};
+    use crate::triggers::triggers::{find_managed_triggers, ZabbixTrigger};
+    use crate::types::types::OperationResult;
+    use crate::webscenarios::webscenarios::{find_managed_web_scenarios, ZabbixManagedWebScenario};
+
+    const CSV_HEADER: &str = "host,url,scenario,trigger,status";
+
+    /// Web scenario managed by wszl and its trigger
+    pub struct ManagedCheck {
+        pub host: String,
+        pub url: String,
+        pub scenario_name: String,
+        pub trigger_name: Option<String>,
+        pub status: String
+    }
+
+    pub fn find_managed_checks(client: &Client, config: &Config) -> OperationResult<Vec<ManagedCheck>> {
+        let api_endpoint = &config.zabbix.api.endpoint;
+
+        match open_api_session(client, &config.zabbix.api) {
+            Ok(auth_token) => {
+                let result = find_managed_web_scenarios(client, api_endpoint, &auth_token)
+                    .and_then(|web_scenarios| {
+                        let triggers = find_managed_triggers(client, api_endpoint, &auth_token)?;
+                        Ok(get_managed_checks(&web_scenarios, &triggers))
+                    });
+
+                if close_api_session(client, &config.zabbix.api, &auth_token).is_err() {
+                    warn!("unable to close api session");
+                }
+
+                result
+            }
+            Err(_) => {
+                error!("unable to login");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Joins web scenarios with triggers by host and url, sorted by host and url
+    pub fn get_managed_checks(web_scenarios: &[ZabbixManagedWebScenario],
+                              triggers: &[ZabbixTrigger]) -> Vec<ManagedCheck> {
+        let mut checks = vec![];
+
+        for web_scenario in web_scenarios {
+            let url = web_scenario.steps.first().map(|step| step.url.to_string()).unwrap_or_default();
+
+            for host in &web_scenario.hosts {
+                let trigger = triggers.iter().find(|trigger|
+                    trigger.url == url && trigger.hosts.iter().any(|trigger_host| trigger_host.hostid == host.hostid)
+                );
+
+                let status = match (web_scenario.status.as_str(), trigger) {
+                    ("1", _) => "disabled",
+                    (_, None) => "no trigger",
+                    (_, Some(trigger)) if trigger.status == "1" => "trigger disabled",
+                    _ => "enabled"
+                };
+
+                checks.push(ManagedCheck {
+                    host: host.host.to_string(),
+                    url: url.to_string(),
+                    scenario_name: web_scenario.name.to_string(),
+                    trigger_name: trigger.map(|trigger| trigger.description.to_string()),
+                    status: status.to_string()
+                });
+            }
+        }
+
+        checks.sort_by(|a, b| (&a.host, &a.url).cmp(&(&b.host, &b.url)));
+
+        checks
+    }
+
+    pub fn render_csv(checks: &[ManagedCheck]) -> String {
+        let mut csv = format!("{}\n", CSV_HEADER);
+
+        for check in checks {
+            let fields = [
+                check.host.as_str(), check.url.as_str(), check.scenario_name.as_str(),
+                check.trigger_name.as_deref().unwrap_or_default(), check.status.as_str()
+            ];
+
+            let row: Vec<String> = fields.iter().map(|field| escape_csv_field(field)).collect();
+
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    pub fn render_table(checks: &[ManagedCheck]) -> String {
+        let mut table = format!("{:<30} {:<50} {:<16} TRIGGER\n", "HOST", "URL", "STATUS");
+
+        for check in checks {
+            table.push_str(&format!("{:<30} {:<50} {:<16} {}\n", check.host, check.url, check.status,
+                                    check.trigger_name.as_deref().unwrap_or("-")));
+        }
+
+        table
+    }
+
+    /// Quotes fields with separators, quotes or line breaks (RFC 4180)
+    fn escape_csv_field(field: &str) -> String {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+
+        } else {
+            field.to_string()
+        }
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod list_tests {
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::list::list::{get_managed_checks, render_csv};
+    use crate::triggers::triggers::ZabbixTrigger;
+    use crate::webscenarios::webscenarios::{ZabbixManagedWebScenario, ZabbixWebScenarioStep};
+
+    fn get_host(hostid: &str, host: &str) -> ZabbixHost {
+        ZabbixHost { hostid: hostid.to_string(), host: host.to_string(), maintenance_status: "0".to_string(),
+                     status: "0".to_string(), proxy_hostid: "0".to_string() }
+    }
+
+    fn get_web_scenario(url: &str, status: &str, host: ZabbixHost) -> ZabbixManagedWebScenario {
+        ZabbixManagedWebScenario {
+            httptestid: "301".to_string(),
+            name: format!("Check index page '{}'", url),
+            status: status.to_string(),
+            hosts: vec![host],
+            steps: vec![ZabbixWebScenarioStep { url: url.to_string() }]
+        }
+    }
+
+    #[test]
+    fn scenarios_should_be_joined_with_triggers() {
+        let web_scenarios = vec![
+            get_web_scenario("https://b.example.com", "0", get_host("10001", "web-01")),
+            get_web_scenario("https://a.example.com", "0", get_host("10001", "web-01")),
+            get_web_scenario("https://c.example.com", "1", get_host("10002", "web-02"))
+        ];
+
+        let triggers = vec![
+            ZabbixTrigger {
+                description: "Site 'https://a.example.com' is unavailable".to_string(),
+                url: "https://a.example.com".to_string(),
+                status: "0".to_string(),
+                value: "0".to_string(),
+                hosts: vec![get_host("10001", "web-01")]
+            }
+        ];
+
+        let checks = get_managed_checks(&web_scenarios, &triggers);
+
+        assert_eq!(checks.len(), 3);
+        assert_eq!(checks[0].url, "https://a.example.com");
+        assert_eq!(checks[0].status, "enabled");
+        assert_eq!(checks[0].trigger_name.as_ref().unwrap(), "Site 'https://a.example.com' is unavailable");
+        assert_eq!(checks[1].status, "no trigger");
+        assert_eq!(checks[2].status, "disabled");
+    }
+
+    #[test]
+    fn csv_fields_should_be_escaped() {
+        let web_scenarios = vec![get_web_scenario("https://example.com/?a=1,2", "0", get_host("10001", "web-01"))];
+
+        let csv = render_csv(&get_managed_checks(&web_scenarios, &[]));
+
+        assert_eq!(csv, "host,url,scenario,trigger,status\n\
+                         web-01,\"https://example.com/?a=1,2\",\"Check index page 'https://example.com/?a=1,2'\",,no trigger\n");
+    }
+}
@@ -5,19 +5,29 @@ pub mod logging {
     use log4rs::filter::threshold::ThresholdFilter;
     use log::LevelFilter;
 
+    use crate::syslog::syslog::SyslogAppender;
+
+    #[cfg(windows)]
+    use crate::winservice::winservice::{EventLogAppender, SERVICE_NAME};
+
     const FILE_APPENDER_NAME: &str = "file";
 
+    const SYSLOG_APPENDER_NAME: &str = "syslog";
+
+    #[cfg(windows)]
+    const EVENT_LOG_APPENDER_NAME: &str = "eventlog";
+
     const LOG_FILE_PATH: &str = "wszl.log";
 
-    fn get_logging_level_from_string(level: &str) -> LevelFilter {
-        return match level {
+    pub fn get_logging_level_from_string(level: &str) -> LevelFilter {
+        match level {
             "debug" => LevelFilter::Debug,
             "error" => LevelFilter::Error,
             "warn" => LevelFilter::Warn,
             "trace" => LevelFilter::Trace,
             "off" => LevelFilter::Off,
             _ => LevelFilter::Info
-        };
+        }
     }
 
     pub fn get_logging_config(logging_level: &str) -> Config {
@@ -25,14 +35,41 @@ pub mod logging {
 
         Config::builder()
             .appender(get_file_appender_definition(level))
+            .appender(Appender::builder().build(SYSLOG_APPENDER_NAME, Box::new(SyslogAppender)))
+            .logger(get_default_logger(level))
+            .logger(Logger::builder().build("hyper", LevelFilter::Info))
+            .logger(Logger::builder().build("http", LevelFilter::Info))
+            .build(
+            Root::builder()
+                .appender(FILE_APPENDER_NAME)
+                .appender(SYSLOG_APPENDER_NAME)
+                .build(level)
+            ).unwrap_or_else(|_| panic!("unable to create log file '{}'", LOG_FILE_PATH))
+    }
+
+    /// Service has no console, so warnings and errors also go to Windows event log
+    #[cfg(windows)]
+    pub fn get_service_logging_config(logging_level: &str) -> Config {
+        let level = get_logging_level_from_string(logging_level);
+
+        let event_log_appender = Appender::builder()
+            .filter(Box::new(ThresholdFilter::new(LevelFilter::Warn)))
+            .build(EVENT_LOG_APPENDER_NAME, Box::new(EventLogAppender::new(SERVICE_NAME)));
+
+        Config::builder()
+            .appender(get_file_appender_definition(level))
+            .appender(Appender::builder().build(SYSLOG_APPENDER_NAME, Box::new(SyslogAppender)))
+            .appender(event_log_appender)
             .logger(get_default_logger(level))
             .logger(Logger::builder().build("hyper", LevelFilter::Info))
             .logger(Logger::builder().build("http", LevelFilter::Info))
             .build(
             Root::builder()
                 .appender(FILE_APPENDER_NAME)
+                .appender(SYSLOG_APPENDER_NAME)
+                .appender(EVENT_LOG_APPENDER_NAME)
                 .build(level)
-            ).expect(&format!("unable to create log file '{}'", LOG_FILE_PATH))
+            ).unwrap_or_else(|_| panic!("unable to create log file '{}'", LOG_FILE_PATH))
     }
 
     fn get_file_appender_definition(level: LevelFilter) -> Appender {
@@ -1,24 +1,61 @@
+#![allow(clippy::module_inception)]
+
 #[macro_use]
 extern crate log;
 extern crate log4rs;
 
 use std::env;
-use std::path::Path;
+#[cfg(windows)]
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::exit;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-use clap::{App, Arg, SubCommand};
-use regex::Regex;
-use reqwest::blocking::Client;
+use chrono::{SecondsFormat, Utc};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
 
-use crate::auth::auth::login_to_zabbix_api;
-use crate::config::config::{load_config_from_file, ZabbixConfig};
-use crate::errors::errors::OperationError;
-use crate::hosts::hosts::{find_hosts, ZabbixHost};
-use crate::items::items::{find_zabbix_items, ZabbixItem};
+use crate::audit::audit::init_audit_log;
+use crate::blocklist::blocklist::append_to_blocklist;
+use crate::circuit::circuit::{ABORTED_EXIT_CODE, is_api_circuit_open, reset_api_circuit_breaker};
+use crate::clean::clean::clean_managed_checks;
+use crate::config::config::{Config, find_config_file, find_config_files, get_parallel_lanes, ItemKeyPatternConfig,
+                            load_config_from_file, LOCK_FILE_DEFAULT_VALUE, parse_duration_secs,
+                            STATE_FILE_DEFAULT_VALUE};
+use crate::daemon::daemon::{get_run_schedule, run_daemon};
+use crate::dashboard::dashboard::update_dashboard;
+use crate::doctor::doctor::{DoctorCheck, render_checklist, run_api_checks};
+use crate::export::export::render_zabbix_template;
+use crate::generator::generator::create_web_scenarios_and_triggers;
+use crate::hooks::hooks::run_end_hook;
+use crate::list::list::{find_managed_checks, render_csv, render_table};
 use crate::logging::logging::get_logging_config;
-use crate::triggers::triggers::create_trigger;
-use crate::types::types::{EmptyResult, OperationResult};
-use crate::webscenarios::webscenarios::{create_web_scenario, find_web_scenarios, ZabbixWebScenario};
+#[cfg(windows)]
+use crate::logging::logging::get_service_logging_config;
+use crate::metrics::metrics::{publish_metrics, reset_api_call_stats};
+use crate::migration::migration::migrate_names;
+use crate::notifications::notifications::send_notifications;
+use crate::output::output::{COLOR_ALWAYS, COLOR_AUTO, COLOR_NEVER, get_stdout_style, OutputStyle};
+use crate::plan::plan::render_diff;
+use crate::recording::recording::{start_recording, start_replay};
+use crate::report::report::{CHANGES_PENDING_EXIT_CODE, ERROR_LIMIT_EXIT_CODE, print_summary, render_counts,
+                            RunReport};
+use crate::reportfile::reportfile::{get_tenant_file_path, write_report_file};
+use crate::runlock::runlock::{acquire_run_lock, LOCKED_EXIT_CODE};
+use crate::runstate::runstate::{load_run_state, RunState, save_run_state};
+use crate::secrets::secrets::get_api_password;
+use crate::shutdown::shutdown::{INTERRUPTED_EXIT_CODE, register_shutdown_handler, set_run_deadline,
+                                TIMED_OUT_EXIT_CODE};
+use crate::status::status::{find_checks_health, render_status};
+use crate::syslog::syslog::init_syslog;
+use crate::telemetry::telemetry::{export_spans, start_span};
+use crate::timings::timings::{enable_timings, get_api_call_timings, get_phase_timings, is_timings_enabled,
+                              render_timings, reset_timings};
+use crate::types::types::OperationResult;
+use crate::vault::vault::get_vault_credentials;
+#[cfg(windows)]
+use crate::winservice::winservice::{install_service, run_as_service, uninstall_service};
 
 mod types;
 
@@ -31,20 +68,178 @@ mod auth;
 mod items;
 mod webscenarios;
 mod triggers;
+mod triggers_tests;
+mod graphs;
+mod graphs_tests;
+mod dashboard;
+mod dashboard_tests;
+mod services;
+mod services_tests;
+mod aggregate;
+mod aggregate_tests;
+mod hostgroups;
+mod hostgroups_tests;
+mod hooks;
+mod hooks_tests;
+mod secrets;
+mod secrets_tests;
+mod vault;
+mod vault_tests;
+mod runlock;
+mod runlock_tests;
+mod daemon;
+mod daemon_tests;
+mod systemd;
+mod systemd_tests;
+mod output;
+mod output_tests;
+mod syslog;
+mod syslog_tests;
+mod session;
+mod session_tests;
+mod runstate;
+mod runstate_tests;
+mod circuit;
+mod circuit_tests;
+mod limits;
+mod limits_tests;
+mod blocklist;
+mod blocklist_tests;
+mod clean;
+mod clean_tests;
+mod renames;
+mod renames_tests;
+mod adoption;
+mod adoption_tests;
+mod latency;
+mod latency_tests;
+mod maintenance;
+mod maintenance_tests;
+mod httpproxy;
+mod httpproxy_tests;
+mod centralhost;
+mod centralhost_tests;
+#[cfg(windows)]
+mod winservice;
+mod templates;
+mod templates_tests;
+mod scripting;
+mod scripting_tests;
+mod itemoverrides;
+mod itemoverrides_tests;
 mod hosts;
 mod logging;
 mod errors;
 mod http;
+mod report;
+mod metrics;
+mod metrics_tests;
+mod notifications;
+mod notifications_tests;
+mod email;
+mod email_tests;
+mod sender;
+mod sender_tests;
+mod list;
+mod list_tests;
+mod status;
+mod status_tests;
+mod doctor;
+mod doctor_tests;
+mod export;
+mod export_tests;
+mod probe;
+mod probe_tests;
+mod reportfile;
+mod reportfile_tests;
+mod plan;
+mod plan_tests;
+mod audit;
+mod audit_tests;
+mod recording;
+mod recording_tests;
+mod telemetry;
+mod timings;
+mod timings_tests;
+mod shutdown;
+mod generator;
+mod patterns;
+mod patterns_tests;
+mod discovery;
+mod discovery_tests;
+mod urlsfile;
+mod urlsfile_tests;
+mod docker;
+mod docker_tests;
+mod vhosts;
+mod vhosts_tests;
+mod urltransform;
+mod urltransform_tests;
+mod urlfilter;
+mod urlfilter_tests;
+mod urlcheck;
+mod urlcheck_tests;
+mod migration;
+mod migration_tests;
 
 const GENERATE_COMMAND: &str = "gen";
 const ITEM_KEY_SEARCH_MASK_ARG: &str = "item-key-starts-with";
 const ITEM_KEY_SEARCH_MASK_DEFAULT_VALUE: &str = "vhost.item";
 
+const CONCURRENCY_ARG: &str = "concurrency";
+const MAX_ERRORS_ARG: &str = "max-errors";
+const URLS_FILE_ARG: &str = "urls-file";
+const REPORT_ARG: &str = "report";
+const DRY_RUN_ARG: &str = "dry-run";
+const DIFF_ARG: &str = "diff";
+const RETRY_FAILED_ARG: &str = "retry-failed";
+const ADOPT_ARG: &str = "adopt";
+const FULL_ARG: &str = "full";
+const RECORD_ARG: &str = "record";
+const REPLAY_ARG: &str = "replay";
+const TIMINGS_ARG: &str = "timings";
+const CONFIG_DIR_ARG: &str = "config-dir";
+const PARALLEL_ARG: &str = "parallel";
+const TIMEOUT_ARG: &str = "timeout";
+
+const LIST_COMMAND: &str = "list";
+const FORMAT_ARG: &str = "format";
+const FORMAT_TABLE: &str = "table";
+const FORMAT_CSV: &str = "csv";
+const OUTPUT_ARG: &str = "output";
+
+const STATUS_COMMAND: &str = "status";
+
+const DOCTOR_COMMAND: &str = "doctor";
+
+const EXPORT_COMMAND: &str = "export";
+const FORMAT_ZABBIX_TEMPLATE: &str = "zabbix-template";
+
+const CLEAN_COMMAND: &str = "clean";
+const URL_ARG: &str = "url";
+const HOST_ARG: &str = "host";
+const BLOCK_ARG: &str = "block";
+
+const MIGRATE_NAMES_COMMAND: &str = "migrate-names";
+
+const DAEMON_COMMAND: &str = "daemon";
+
+const SERVICE_COMMAND: &str = "service";
+const SERVICE_INSTALL_COMMAND: &str = "install";
+const SERVICE_UNINSTALL_COMMAND: &str = "uninstall";
+const SERVICE_RUN_COMMAND: &str = "run";
+
 const WORK_DIR_ARGUMENT: &str = "work-dir";
 
+const CONFIG_ARGUMENT: &str = "config";
+
+const COLOR_ARGUMENT: &str = "color";
+
 const LOG_LEVEL_ARGUMENT: &str = "log-level";
 const LOG_LEVEL_DEFAULT_VALUE: &str = "info";
 
+const DEFAULT_CONFIG_FILE: &str = "wszl.yml";
+
 const ERROR_EXIT_CODE: i32 = 1;
 
 fn main() {
@@ -58,6 +253,19 @@ fn main() {
                 .help("set working directory")
                 .long(WORK_DIR_ARGUMENT).takes_value(true)
         )
+        .arg(
+            Arg::with_name(CONFIG_ARGUMENT)
+                .short("c")
+                .help("set config file. default: ./wszl.yml, ~/.config/wszl/wszl.yml, /etc/wszl/wszl.yml")
+                .long(CONFIG_ARGUMENT).takes_value(true)
+        )
+        .arg(
+            Arg::with_name(COLOR_ARGUMENT)
+                .help("color terminal output, auto - only for terminal without NO_COLOR environment variable")
+                .long(COLOR_ARGUMENT).takes_value(true)
+                .possible_values(&[COLOR_AUTO, COLOR_ALWAYS, COLOR_NEVER])
+                .default_value(COLOR_AUTO)
+        )
         .arg(
             Arg::with_name(LOG_LEVEL_ARGUMENT)
                 .help("set logging level. possible values: debug, info, error, warn, trace")
@@ -71,11 +279,177 @@ fn main() {
             .arg(
                 Arg::with_name(ITEM_KEY_SEARCH_MASK_ARG)
                     .short(ITEM_KEY_SEARCH_MASK_ARG)
-                    .help("set search mask for items.")
+                    .help("set search mask for items, '*' captures url. overrides patterns from config")
                     .default_value(ITEM_KEY_SEARCH_MASK_DEFAULT_VALUE)
                     .long(ITEM_KEY_SEARCH_MASK_ARG).takes_value(true)
                     .required(false)
             )
+            .arg(
+                Arg::with_name(CONCURRENCY_ARG)
+                    .help("set max number of simultaneous Zabbix API calls. overrides config value")
+                    .long(CONCURRENCY_ARG).takes_value(true)
+                    .required(false)
+            )
+            .arg(
+                Arg::with_name(TIMEOUT_ARG)
+                    .help("stop the whole run after duration (i.e. 90s, 15m), items in progress are finished")
+                    .long(TIMEOUT_ARG).takes_value(true).value_name("duration")
+                    .required(false)
+            )
+            .arg(
+                Arg::with_name(MAX_ERRORS_ARG)
+                    .help("abort the run after more failed items than this, 0 - never. overrides config value")
+                    .long(MAX_ERRORS_ARG).takes_value(true)
+                    .required(false)
+            )
+            .arg(
+                Arg::with_name(URLS_FILE_ARG)
+                    .help("set file with urls mapped to hosts. overrides config value")
+                    .long(URLS_FILE_ARG).takes_value(true)
+                    .required(false)
+            )
+            .arg(
+                Arg::with_name(REPORT_ARG)
+                    .help("write run report to file, YAML for .yml/.yaml extension, JSON otherwise")
+                    .long(REPORT_ARG).takes_value(true)
+                    .required(false)
+            )
+            .arg(
+                Arg::with_name(DRY_RUN_ARG)
+                    .help("plan changes without creating anything in Zabbix")
+                    .long(DRY_RUN_ARG)
+            )
+            .arg(
+                Arg::with_name(DIFF_ARG)
+                    .help("print planned changes as a diff, implies --dry-run")
+                    .long(DIFF_ARG)
+            )
+            .arg(
+                Arg::with_name(RETRY_FAILED_ARG)
+                    .help("process only items which failed in the previous run")
+                    .long(RETRY_FAILED_ARG)
+            )
+            .arg(
+                Arg::with_name(FULL_ARG)
+                    .help("process all items, not only items created since the last successful run")
+                    .long(FULL_ARG)
+            )
+            .arg(
+                Arg::with_name(ADOPT_ARG)
+                    .help("take over hand-made web scenarios of urls (same url or name) instead of creating duplicates")
+                    .long(ADOPT_ARG)
+            )
+            .arg(
+                Arg::with_name(RECORD_ARG)
+                    .help("record all Zabbix API responses of the run to directory")
+                    .long(RECORD_ARG).takes_value(true).value_name("dir")
+                    .required(false)
+            )
+            .arg(
+                Arg::with_name(REPLAY_ARG)
+                    .help("plan changes against API responses recorded with --record, without network. implies --dry-run")
+                    .long(REPLAY_ARG).takes_value(true).value_name("dir")
+                    .conflicts_with(RECORD_ARG)
+                    .required(false)
+            )
+            .arg(
+                Arg::with_name(TIMINGS_ARG)
+                    .help("print wall time and api latency percentiles per phase at the end of the run")
+                    .long(TIMINGS_ARG)
+            )
+            .arg(
+                Arg::with_name(CONFIG_DIR_ARG)
+                    .help("run independently for every config file (*.yml, *.yaml) of directory")
+                    .long(CONFIG_DIR_ARG).takes_value(true).value_name("dir")
+                    .required(false)
+            )
+            .arg(
+                Arg::with_name(PARALLEL_ARG)
+                    .help("run configs of --config-dir at once instead of one by one")
+                    .long(PARALLEL_ARG)
+                    .requires(CONFIG_DIR_ARG)
+                    .conflicts_with_all(&[RECORD_ARG, REPLAY_ARG, TIMINGS_ARG])
+            )
+        )
+        .subcommand(SubCommand::with_name(LIST_COMMAND)
+            .about("list web scenarios and triggers managed by wszl")
+            .arg(
+                Arg::with_name(FORMAT_ARG)
+                    .help("output format")
+                    .long(FORMAT_ARG).takes_value(true)
+                    .possible_values(&[FORMAT_TABLE, FORMAT_CSV])
+                    .default_value(FORMAT_TABLE)
+            )
+            .arg(
+                Arg::with_name(OUTPUT_ARG)
+                    .short("o")
+                    .help("export to file instead of stdout")
+                    .long(OUTPUT_ARG).takes_value(true)
+                    .required(false)
+            )
+        )
+        .subcommand(SubCommand::with_name(STATUS_COMMAND)
+            .about("show health of web scenarios managed by wszl: failing, never executed, last check time")
+        )
+        .subcommand(SubCommand::with_name(DOCTOR_COMMAND)
+            .about("check config, api reachability and version, auth, permissions and matching items")
+        )
+        .subcommand(SubCommand::with_name(EXPORT_COMMAND)
+            .about("export web scenarios and triggers which gen would manage as zabbix template for import")
+            .arg(
+                Arg::with_name(FORMAT_ARG)
+                    .help("output format")
+                    .long(FORMAT_ARG).takes_value(true)
+                    .possible_values(&[FORMAT_ZABBIX_TEMPLATE])
+                    .default_value(FORMAT_ZABBIX_TEMPLATE)
+            )
+            .arg(
+                Arg::with_name(OUTPUT_ARG)
+                    .short("o")
+                    .help("export to file instead of stdout")
+                    .long(OUTPUT_ARG).takes_value(true)
+                    .required(false)
+            )
+        )
+        .subcommand(SubCommand::with_name(CLEAN_COMMAND)
+            .about("delete web scenarios and triggers managed by wszl for urls")
+            .arg(
+                Arg::with_name(URL_ARG)
+                    .help("url of managed web scenario")
+                    .required(true).multiple(true)
+            )
+            .arg(
+                Arg::with_name(HOST_ARG)
+                    .help("delete only from host (technical name)")
+                    .long(HOST_ARG).takes_value(true)
+                    .required(false)
+            )
+            .arg(
+                Arg::with_name(BLOCK_ARG)
+                    .help("add urls to blocklist, so gen never creates them again (also for checks deleted by hand)")
+                    .long(BLOCK_ARG)
+            )
+        )
+        .subcommand(SubCommand::with_name(MIGRATE_NAMES_COMMAND)
+            .about("rename checks named by the old scheme (\"Check index page '...'\") after templates from config")
+            .arg(
+                Arg::with_name(DRY_RUN_ARG)
+                    .help("only list checks which would be renamed")
+                    .long(DRY_RUN_ARG)
+            )
+        )
+        .subcommand(SubCommand::with_name(DAEMON_COMMAND)
+            .about("run gen repeatedly on schedule from config until termination signal")
+        )
+        .subcommand(SubCommand::with_name(SERVICE_COMMAND)
+            .about("run daemon as windows service")
+            .setting(AppSettings::SubcommandRequiredElseHelp)
+            .subcommand(SubCommand::with_name(SERVICE_INSTALL_COMMAND)
+                .about("register windows service with current working directory and config"))
+            .subcommand(SubCommand::with_name(SERVICE_UNINSTALL_COMMAND)
+                .about("stop and remove windows service"))
+            .subcommand(SubCommand::with_name(SERVICE_RUN_COMMAND)
+                .about("entry point for service control manager"))
         )
         .get_matches();
 
@@ -85,204 +459,796 @@ fn main() {
 
     } else { Path::new("/etc/zabbix") };
 
+    // Relative to the directory wszl has been started from
+    let explicit_config_file = matches.value_of(CONFIG_ARGUMENT).map(|path| {
+        env::current_dir().map(|current_dir| current_dir.join(path)).unwrap_or_else(|_| PathBuf::from(path))
+    });
+
     env::set_current_dir(working_directory).expect("unable to set working directory");
 
     let logging_level: &str = if matches.is_present(LOG_LEVEL_ARGUMENT) {
         matches.value_of(LOG_LEVEL_ARGUMENT).unwrap()
     } else { LOG_LEVEL_DEFAULT_VALUE };
 
+    #[cfg(windows)]
+    let logging_config = match matches.subcommand_matches(SERVICE_COMMAND).and_then(|m| m.subcommand_name()) {
+        Some(SERVICE_RUN_COMMAND) => get_service_logging_config(logging_level),
+        _ => get_logging_config(logging_level)
+    };
+
+    #[cfg(not(windows))]
     let logging_config = get_logging_config(logging_level);
     log4rs::init_config(logging_config).unwrap();
 
 
+    let output_style = get_stdout_style(matches.value_of(COLOR_ARGUMENT).unwrap_or(COLOR_AUTO));
+
     let mut matched_command = false;
 
-    match matches.subcommand_matches(GENERATE_COMMAND) {
-        Some(_) => {
-            matched_command = true;
-            let config_file_path = Path::new("wszl.yml");
+    let config_file_path = match explicit_config_file.or_else(find_config_file) {
+        Some(path) => path,
+        None => PathBuf::from(DEFAULT_CONFIG_FILE)
+    };
 
-            match load_config_from_file(config_file_path) {
-                Ok(config) => {
-                    let client = reqwest::blocking::Client::new();
+    if let Some(generate_matches) = matches.subcommand_matches(GENERATE_COMMAND) {
+        matched_command = true;
 
-                    let item_key_search_mask: &str = if matches.is_present(ITEM_KEY_SEARCH_MASK_ARG) {
-                        matches.value_of(ITEM_KEY_SEARCH_MASK_ARG).unwrap()
-                    } else { ITEM_KEY_SEARCH_MASK_DEFAULT_VALUE };
+        register_shutdown_handler();
 
-                    match create_web_scenarios_and_triggers(&client, &config.zabbix, &item_key_search_mask) {
-                        Ok(_) => info!("web scenarios and triggers have been created"),
-                        Err(_) => exit(ERROR_EXIT_CODE)
-                    }
+        if let Some(timeout) = generate_matches.value_of(TIMEOUT_ARG) {
+            match parse_duration_secs(timeout) {
+                Some(timeout_secs) => set_run_deadline(Duration::from_secs(timeout_secs)),
+                None => {
+                    error!("invalid timeout value '{}', expected duration like 90s, 15m or 1h", timeout);
+                    exit(ERROR_EXIT_CODE)
                 }
-                Err(_) => error!("unable to load config from file")
             }
         }
-        None => {}
+
+        let exit_code = match generate_matches.value_of(CONFIG_DIR_ARG) {
+            Some(config_dir) => run_gen_for_config_dir(generate_matches, Path::new(config_dir), output_style),
+            None => run_gen_command(generate_matches, &config_file_path, None, output_style).exit_code
+        };
+
+        if exit_code != 0 {
+            exit(exit_code)
+        }
     }
 
-    if !matched_command {
-        matches.usage();
+    if matches.subcommand_matches(DAEMON_COMMAND).is_some() {
+        matched_command = true;
+
+        match load_config(&config_file_path) {
+            Ok(config) => {
+                let exit_code = start_daemon(&config, output_style);
+
+                if exit_code != 0 {
+                    exit(exit_code)
+                }
+            }
+            Err(_) => {
+                error!("unable to load config from file");
+                exit(ERROR_EXIT_CODE)
+            }
+        }
     }
-}
 
-fn create_web_scenarios_and_triggers(client: &Client, zabbix_config: &ZabbixConfig,
-                                     item_key_search_mask: &str) -> EmptyResult {
-    match login_to_zabbix_api(&client, &zabbix_config.api.endpoint,
-                              &zabbix_config.api.username, &zabbix_config.api.password) {
-        Ok(auth_token) => {
-            debug!("login success: token '{}'", auth_token);
+    if let Some(service_matches) = matches.subcommand_matches(SERVICE_COMMAND) {
+        matched_command = true;
 
-            match find_zabbix_objects(client, zabbix_config, &auth_token, &item_key_search_mask) {
-                Ok(zabbix_objects) => {
-                    let pattern_start = "^".to_string() + item_key_search_mask;
-                    let pattern = pattern_start + "\\[(.*)\\]$";
+        run_service_command(service_matches, &config_file_path, logging_level, output_style);
+    }
 
-                    let url_pattern = Regex::new(&pattern).unwrap();
+    if let Some(list_matches) = matches.subcommand_matches(LIST_COMMAND) {
+        matched_command = true;
 
-                    let mut has_errors = false;
+        match load_config(&config_file_path) {
+            Ok(config) => {
+                let client = reqwest::blocking::Client::new();
 
-                    for item in &zabbix_objects.items {
-                        debug!("item '{}'", item.name);
+                match find_managed_checks(&client, &config) {
+                    Ok(checks) => {
+                        let content = match list_matches.value_of(FORMAT_ARG) {
+                            Some(FORMAT_CSV) => render_csv(&checks),
+                            _ => render_table(&checks)
+                        };
 
-                        match create_scenario_and_trigger_for_item(zabbix_config, &auth_token,
-                                        client, &url_pattern, &zabbix_objects, item) {
-                            Ok(_) => {}
-                            Err(_) => has_errors = true
+                        match list_matches.value_of(OUTPUT_ARG) {
+                            Some(output_path) => {
+                                if let Err(e) = fs::write(output_path, content) {
+                                    error!("unable to write '{}': {}", output_path, e);
+                                    exit(ERROR_EXIT_CODE)
+                                }
+                                info!("managed checks have been exported to '{}'", output_path);
+                            }
+                            None => print!("{}", content)
                         }
                     }
+                    Err(_) => {
+                        error!("unable to get managed checks");
+                        exit(ERROR_EXIT_CODE)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to load config from file");
+                exit(ERROR_EXIT_CODE)
+            }
+        }
+    }
 
-                    if has_errors {
-                        Err(OperationError::Error)
+    if matches.subcommand_matches(STATUS_COMMAND).is_some() {
+        matched_command = true;
 
-                    } else {
-                        Ok(())
+        match load_config(&config_file_path) {
+            Ok(config) => {
+                let client = reqwest::blocking::Client::new();
+
+                match find_checks_health(&client, &config) {
+                    Ok(checks) => {
+                        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+                        print!("{}", render_status(&checks, now));
+                    }
+                    Err(_) => {
+                        error!("unable to get health of managed checks");
+                        exit(ERROR_EXIT_CODE)
                     }
-                }
-                Err(_) => {
-                    error!("unable to get zabbix objects");
-                    Err(OperationError::Error)
                 }
             }
+            Err(_) => {
+                error!("unable to load config from file");
+                exit(ERROR_EXIT_CODE)
+            }
+        }
+    }
 
-        },
-        Err(_) => {
-            error!("unable to login");
-            Err(OperationError::Error)
+    if matches.subcommand_matches(DOCTOR_COMMAND).is_some() {
+        matched_command = true;
+
+        let checks = match load_config(&config_file_path) {
+            Ok(config) => {
+                let client = reqwest::blocking::Client::new();
+
+                let mut checks = vec![DoctorCheck::pass("config", &config_file_path.display().to_string())];
+                checks.append(&mut run_api_checks(&client, &config));
+                checks
+            }
+            Err(_) => vec![DoctorCheck::fail("config", &format!("unable to load '{}', see log for details",
+                                                                 config_file_path.display()))]
+        };
+
+        print!("{}", render_checklist(&checks, output_style));
+
+        if checks.iter().any(|check| !check.passed) {
+            exit(ERROR_EXIT_CODE)
         }
     }
-}
 
-fn find_zabbix_objects(client: &Client, zabbix_config: &ZabbixConfig,
-                       auth_token: &str, item_key_search_mask: &str) ->
-                                                                OperationResult<ZabbixObjects> {
-    match find_zabbix_items(&client, &zabbix_config.api.endpoint,
-                            &auth_token, item_key_search_mask) {
-        Ok(items) => {
-            debug!("received items:");
-
-            match find_web_scenarios(&client, &zabbix_config.api.endpoint, &auth_token) {
-                Ok(web_scenarios) => {
-                    debug!("web scenarios have been obtained");
-
-                    let host_ids: Vec<String> = items.iter()
-                                    .map(|item| item.hostid.to_string()).collect();
-
-                    match find_hosts(&client, &zabbix_config.api.endpoint, &auth_token, host_ids) {
-                        Ok(hosts) => {
-
-                            Ok(
-                                ZabbixObjects {
-                                    items,
-                                    web_scenarios,
-                                    hosts
-                                }
-                            )
+    if let Some(export_matches) = matches.subcommand_matches(EXPORT_COMMAND) {
+        matched_command = true;
 
+        match load_config(&config_file_path) {
+            Ok(config) => {
+                let client = reqwest::blocking::Client::new();
+
+                // Dry-run only reads from Zabbix, so write permission isn't needed
+                match create_web_scenarios_and_triggers(&client, &config, true, None, false, None, true) {
+                    Ok(report) => {
+                        let date = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+                        let content = render_zabbix_template(&report, &date);
+
+                        match export_matches.value_of(OUTPUT_ARG) {
+                            Some(output_path) => {
+                                if let Err(e) = fs::write(output_path, content) {
+                                    error!("unable to write '{}': {}", output_path, e);
+                                    exit(ERROR_EXIT_CODE)
+                                }
+                                info!("zabbix template has been exported to '{}'", output_path);
+                            }
+                            None => print!("{}", content)
                         }
-                        Err(_) => {
-                            error!("unable to get zabbix hosts by ids");
-                            Err(OperationError::Error)
-                        }
+                    }
+                    Err(_) => {
+                        error!("unable to plan web scenarios and triggers for export");
+                        exit(ERROR_EXIT_CODE)
                     }
                 }
-                Err(_) => {
-                    error!("unable to get zabbix web scenarios");
-                    Err(OperationError::Error)
+            }
+            Err(_) => {
+                error!("unable to load config from file");
+                exit(ERROR_EXIT_CODE)
+            }
+        }
+    }
+
+    if let Some(clean_matches) = matches.subcommand_matches(CLEAN_COMMAND) {
+        matched_command = true;
+
+        match load_config(&config_file_path) {
+            Ok(config) => {
+                let client = reqwest::blocking::Client::new();
+
+                let urls: Vec<String> = clean_matches.values_of(URL_ARG)
+                                            .map(|values| values.map(String::from).collect()).unwrap_or_default();
+
+                init_audit_log(&config.zabbix.api.endpoint, config.audit.file_path.as_deref());
+
+                let result = clean_managed_checks(&client, &config, &urls, clean_matches.value_of(HOST_ARG));
+
+                if clean_matches.is_present(BLOCK_ARG) {
+                    let blocklist_path = Path::new(&config.urls.blocklist_file);
+
+                    if append_to_blocklist(blocklist_path, &urls).is_err() {
+                        error!("unable to add urls to blocklist '{}'", blocklist_path.display());
+                        exit(ERROR_EXIT_CODE)
+                    }
+                }
+
+                match result {
+                    Ok(deleted) => info!("managed checks have been deleted: {}", deleted),
+                    Err(_) => {
+                        error!("unable to delete managed checks");
+                        exit(ERROR_EXIT_CODE)
+                    }
                 }
             }
+            Err(_) => {
+                error!("unable to load config from file");
+                exit(ERROR_EXIT_CODE)
+            }
         }
+    }
+
+    if let Some(migrate_matches) = matches.subcommand_matches(MIGRATE_NAMES_COMMAND) {
+        matched_command = true;
+
+        match load_config(&config_file_path) {
+            Ok(config) => {
+                let client = reqwest::blocking::Client::new();
+
+                init_audit_log(&config.zabbix.api.endpoint, config.audit.file_path.as_deref());
+
+                let dry_run = migrate_matches.is_present(DRY_RUN_ARG);
+
+                match migrate_names(&client, &config, dry_run) {
+                    Ok(migrated) if dry_run => info!("managed checks to rename: {}", migrated),
+                    Ok(migrated) => info!("managed checks have been renamed: {}", migrated),
+                    Err(_) => {
+                        error!("unable to rename managed checks");
+                        exit(ERROR_EXIT_CODE)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to load config from file");
+                exit(ERROR_EXIT_CODE)
+            }
+        }
+    }
+
+    if !matched_command {
+        matches.usage();
+    }
+}
+
+struct GenOptions {
+    dry_run: bool,
+    show_diff: bool,
+    report_path: Option<String>,
+    /// Only failed items from `processing.state-file` are processed
+    retry_failed: bool,
+    /// Hand-made web scenarios of urls are taken over
+    adopt: bool,
+    /// All items are processed, not only items created since the last successful run
+    full: bool,
+    /// Run is one of `--config-dir --parallel` runs
+    parallel: bool,
+    output_style: OutputStyle
+}
+
+/// Exit code of `gen` run and its report, absent when the run failed before any item was processed
+struct GenResult {
+    exit_code: i32,
+    report: Option<RunReport>
+}
+
+impl GenResult {
+    fn failed(exit_code: i32) -> GenResult {
+        GenResult { exit_code, report: None }
+    }
+}
+
+/// `gen` with options from command line for one config file.
+/// `tenant` (name of config file in `--config-dir`) keeps reports and recordings of configs apart.
+fn run_gen_command(generate_matches: &ArgMatches, config_file_path: &Path, tenant: Option<&str>,
+                   output_style: OutputStyle) -> GenResult {
+    let mut config = match load_config(config_file_path) {
+        Ok(config) => config,
         Err(_) => {
-            error!("unable to get zabbix items");
-            Err(OperationError::Error)
+            error!("unable to load config from file");
+            return GenResult::failed(ERROR_EXIT_CODE)
+        }
+    };
+
+    if let Some(concurrency) = generate_matches.value_of(CONCURRENCY_ARG) {
+        match concurrency.parse::<usize>() {
+            Ok(value) => config.processing.concurrency = value,
+            Err(_) => {
+                error!("invalid concurrency value '{}'", concurrency);
+                return GenResult::failed(ERROR_EXIT_CODE)
+            }
+        }
+    }
+
+    if let Some(max_errors) = generate_matches.value_of(MAX_ERRORS_ARG) {
+        match max_errors.parse::<usize>() {
+            Ok(value) => config.processing.max_errors = value,
+            Err(_) => {
+                error!("invalid max errors value '{}'", max_errors);
+                return GenResult::failed(ERROR_EXIT_CODE)
+            }
+        }
+    }
+
+    let client = reqwest::blocking::Client::new();
+
+    if let Some(urls_file) = generate_matches.value_of(URLS_FILE_ARG) {
+        config.discovery.urls_file = Some(urls_file.to_string());
+    }
+
+    if generate_matches.occurrences_of(ITEM_KEY_SEARCH_MASK_ARG) > 0 {
+        let item_key_search_mask = generate_matches.value_of(ITEM_KEY_SEARCH_MASK_ARG).unwrap();
+        config.items.patterns = vec![ItemKeyPatternConfig::Mask(item_key_search_mask.to_string())];
+    }
+
+    let get_tenant_dir = |dir: &str| match tenant {
+        Some(tenant) => Path::new(dir).join(tenant),
+        None => PathBuf::from(dir)
+    };
+
+    if let Some(record_dir) = generate_matches.value_of(RECORD_ARG) {
+        if start_recording(&get_tenant_dir(record_dir)).is_err() {
+            return GenResult::failed(ERROR_EXIT_CODE)
+        }
+    }
+
+    let replay_dir = generate_matches.value_of(REPLAY_ARG);
+
+    if let Some(replay_dir) = replay_dir {
+        if start_replay(&get_tenant_dir(replay_dir)).is_err() {
+            return GenResult::failed(ERROR_EXIT_CODE)
         }
+
+        // Only Zabbix API is recorded, urls aren't requested and spans aren't exported
+        config.probe = None;
+        config.tracing.otlp_endpoint = None;
+    }
+
+    if generate_matches.is_present(TIMINGS_ARG) {
+        enable_timings();
     }
+
+    let show_diff = generate_matches.is_present(DIFF_ARG);
+    let dry_run = show_diff || replay_dir.is_some() || generate_matches.is_present(DRY_RUN_ARG);
+
+    // Configs of directory would share default lock and state files
+    if let Some(tenant) = tenant {
+        if config.processing.lock_file == LOCK_FILE_DEFAULT_VALUE {
+            config.processing.lock_file = get_tenant_file_path(LOCK_FILE_DEFAULT_VALUE, tenant);
+        }
+
+        if config.processing.state_file == STATE_FILE_DEFAULT_VALUE {
+            config.processing.state_file = get_tenant_file_path(STATE_FILE_DEFAULT_VALUE, tenant);
+        }
+    }
+
+    let lock_file_path = Path::new(&config.processing.lock_file);
+
+    let _run_lock = match acquire_run_lock(lock_file_path) {
+        Ok(Some(run_lock)) => run_lock,
+        Ok(None) => {
+            error!("another wszl run is in progress (lock '{}')", lock_file_path.display());
+            return GenResult::failed(LOCKED_EXIT_CODE)
+        }
+        Err(e) => {
+            error!("unable to acquire run lock '{}': {}", lock_file_path.display(), e);
+            return GenResult::failed(ERROR_EXIT_CODE)
+        }
+    };
+
+    init_audit_log(&config.zabbix.api.endpoint, config.audit.file_path.as_deref());
+
+    let report_path = generate_matches.value_of(REPORT_ARG).map(|report_path| match tenant {
+        Some(tenant) => get_tenant_file_path(report_path, tenant),
+        None => report_path.to_string()
+    });
+
+    let options = GenOptions {
+        dry_run,
+        show_diff,
+        report_path,
+        retry_failed: generate_matches.is_present(RETRY_FAILED_ARG),
+        adopt: generate_matches.is_present(ADOPT_ARG),
+        full: generate_matches.is_present(FULL_ARG),
+        parallel: generate_matches.is_present(PARALLEL_ARG),
+        output_style
+    };
+
+    run_gen(&client, &config, &options)
 }
 
-fn create_scenario_and_trigger_for_item(zabbix_config: &ZabbixConfig,
-                                        auth_token: &str, client: &Client,
-                                        url_pattern: &Regex, zabbix_objects: &ZabbixObjects,
-                                        zabbix_item: &ZabbixItem) -> EmptyResult {
-    let mut has_errors = false;
-
-    debug!("---------------------------");
-    debug!("item: {}", zabbix_item.name);
-
-    if url_pattern.is_match(&zabbix_item.key_) {
-        let groups = url_pattern.captures_iter(&zabbix_item.key_).next().unwrap();
-        let url = String::from(&groups[1]);
-        debug!("- url '{}'", url);
-
-        let scenario_name = format!("Check index page '{}'", url);
-
-        match zabbix_objects.web_scenarios.iter().find(|entity| entity.name == scenario_name) {
-            Some(_) => debug!("web scenario has been found for url '{}', skip", url),
-            None => {
-                debug!("web scenario wasn't found for url '{}', creating..", url);
-
-                match zabbix_objects.hosts.iter().find(|host| host.hostid == zabbix_item.hostid) {
-                    Some(host) => {
-                        match create_web_scenario(&client, &zabbix_config.api.endpoint, &auth_token, &zabbix_config.scenario, &url, &host.hostid) {
-                            Ok(_) => {
-                                info!("web scenario has been created for '{}'", url);
-
-                                match create_trigger(&client, &zabbix_config.api.endpoint, &auth_token, &host.host, &url) {
-                                    Ok(_) => info!("trigger has been created"),
-                                    Err(_) => {
-                                        error!("unable to create trigger for url '{}'", url);
-                                        has_errors = true;
-                                    }
-                                }
-                            },
-                            Err(_) => {
-                                error!("unable to create web scenario for url '{}'", url);
-                                has_errors = true;
-                            }
-                        }
-                    }
-                    None => {
-                        error!("host wasn't found by id {}", zabbix_item.hostid);
-                        has_errors = true;
-                    }
-                }
+/// Runs `gen` for every config file of directory one by one. Exit code is 0 when all runs succeeded,
+/// 130 when interrupted and 124 after `--timeout` (remaining configs are skipped), 1 otherwise.
+fn run_gen_for_config_dir(generate_matches: &ArgMatches, config_dir: &Path, output_style: OutputStyle) -> i32 {
+    let config_files = match find_config_files(config_dir) {
+        Ok(config_files) => config_files,
+        Err(e) => {
+            error!("unable to read config directory '{}': {}", config_dir.display(), e);
+            return ERROR_EXIT_CODE
+        }
+    };
+
+    if config_files.is_empty() {
+        error!("no config files (*.yml, *.yaml) in directory '{}'", config_dir.display());
+        return ERROR_EXIT_CODE
+    }
+
+    if generate_matches.is_present(PARALLEL_ARG) {
+        return run_gen_in_parallel(generate_matches, &config_files, output_style)
+    }
+
+    let mut failed_configs = vec![];
+    let mut changes_pending = false;
+
+    for config_file in &config_files {
+        let tenant = get_tenant(config_file);
+
+        println!("== {} ==", tenant);
+        info!("run gen for config '{}'", config_file.display());
+
+        let exit_code = run_gen_command(generate_matches, config_file, Some(&tenant), output_style).exit_code;
+
+        // Remaining configs would exceed the timeout as well
+        if exit_code == INTERRUPTED_EXIT_CODE || exit_code == TIMED_OUT_EXIT_CODE {
+            return exit_code
+        }
+
+        if exit_code == CHANGES_PENDING_EXIT_CODE {
+            changes_pending = true;
+
+        } else if exit_code != 0 {
+            error!("gen for config '{}' has failed with exit code {}", config_file.display(), exit_code);
+            failed_configs.push(tenant);
+        }
+
+        println!();
+    }
+
+    get_config_dir_exit_code(&failed_configs, changes_pending)
+}
+
+/// Runs `gen` for all config files of directory at once, every run with its own API session and circuit breaker.
+/// Configs of the same Zabbix API endpoint run one by one, state of the endpoint isn't shared by simultaneous runs.
+/// Reports are printed in order of configs when all runs have finished, followed by the total of all configs.
+fn run_gen_in_parallel(generate_matches: &ArgMatches, config_files: &[PathBuf], output_style: OutputStyle) -> i32 {
+    let api_endpoints: Vec<Option<String>> = config_files.iter()
+        .map(|config_file| load_config_from_file(config_file).ok().map(|config| config.zabbix.api.endpoint))
+        .collect();
+
+    let mut results: Vec<(usize, String, GenResult)> = thread::scope(|scope| {
+        let lanes: Vec<_> = get_parallel_lanes(&api_endpoints).into_iter().map(|lane| scope.spawn(move || {
+            lane.into_iter().map(|index| {
+                let config_file = &config_files[index];
+                let tenant = get_tenant(config_file);
+                info!("run gen for config '{}'", config_file.display());
+
+                let result = run_gen_command(generate_matches, config_file, Some(&tenant), output_style);
+                (index, tenant, result)
+            }).collect::<Vec<_>>()
+        })).collect();
+
+        lanes.into_iter().flat_map(|lane| lane.join().expect("gen run has panicked")).collect()
+    });
+
+    results.sort_by_key(|(index, _, _)| *index);
+
+    let mut total = RunReport::new();
+    let mut failed_configs = vec![];
+    let mut changes_pending = false;
+    let mut interrupted = false;
+    let mut timed_out = false;
+
+    for (_, tenant, result) in results {
+        println!("== {} ==", tenant);
+
+        match result.report {
+            Some(report) => {
+                print_report(&report, generate_matches.is_present(DIFF_ARG), output_style);
+                total.merge(report);
             }
+            None => println!("run has failed, see log for details")
+        }
+
+        if result.exit_code == INTERRUPTED_EXIT_CODE {
+            interrupted = true;
+
+        } else if result.exit_code == TIMED_OUT_EXIT_CODE {
+            timed_out = true;
+
+        } else if result.exit_code == CHANGES_PENDING_EXIT_CODE {
+            changes_pending = true;
+
+        } else if result.exit_code != 0 {
+            error!("gen for config '{}' has failed with exit code {}", tenant, result.exit_code);
+            failed_configs.push(tenant);
         }
 
+        println!();
+    }
+
+    println!("== total ==");
+    println!("{}", render_counts(&total.get_counts(), output_style));
+
+    if interrupted {
+        INTERRUPTED_EXIT_CODE
+    } else if timed_out {
+        TIMED_OUT_EXIT_CODE
     } else {
-        error!("unsupported item format");
-        has_errors = true;
+        get_config_dir_exit_code(&failed_configs, changes_pending)
     }
+}
+
+/// Any failed config fails `--config-dir` run, otherwise dry-run tells whether any config has pending changes
+fn get_config_dir_exit_code(failed_configs: &[String], changes_pending: bool) -> i32 {
+    if !failed_configs.is_empty() {
+        println!("failed configs: {}", failed_configs.join(", "));
+        ERROR_EXIT_CODE
 
-    if has_errors {
-        Err(OperationError::Error)
+    } else if changes_pending {
+        CHANGES_PENDING_EXIT_CODE
 
     } else {
-        Ok(())
+        0
+    }
+}
+
+/// Name of config file in `--config-dir`
+fn get_tenant(config_file: &Path) -> String {
+    config_file.file_stem().unwrap_or_default().to_string_lossy().to_string()
+}
+
+/// Single `gen` run with reporting
+fn run_gen(client: &reqwest::blocking::Client, config: &Config, options: &GenOptions) -> GenResult {
+    let api_endpoint = &config.zabbix.api.endpoint;
+
+    reset_api_call_stats(api_endpoint);
+    reset_timings(api_endpoint);
+    reset_api_circuit_breaker(api_endpoint, config.processing.max_consecutive_failures);
+
+    let state_file_path = Path::new(&config.processing.state_file);
+
+    let previous_state = match load_run_state(state_file_path) {
+        Ok(run_state) => run_state,
+        Err(_) if !options.retry_failed => {
+            warn!("unable to load run state from '{}', all items will be processed", state_file_path.display());
+            RunState::default()
+        }
+        Err(_) => {
+            error!("unable to load run state from '{}'", state_file_path.display());
+            return GenResult::failed(ERROR_EXIT_CODE)
+        }
+    };
+
+    let retry_state = if options.retry_failed { Some(&previous_state) } else { None };
+
+    // Retry has its own selection of items
+    let since_item_id = if options.full || options.retry_failed { None } else { previous_state.last_item_id };
+
+    if let Some(since_item_id) = since_item_id {
+        info!("new items only run, only items created since the last successful run (item id > {}) are processed",
+              since_item_id);
+    }
+
+    let started_at = Instant::now();
+
+    let result = {
+        let mut run_span = start_span("wszl gen");
+        let result = create_web_scenarios_and_triggers(client, config, options.dry_run, retry_state,
+                                                       options.adopt, since_item_id, false);
+        if !matches!(&result, Ok(report) if !report.has_failures()) {
+            run_span.set_error();
+        }
+        result
+    };
+
+    export_spans(client, &config.tracing);
+
+    if let Some(report_path) = &options.report_path {
+        let report = result.as_ref().ok();
+        if write_report_file(Path::new(report_path), config, report, started_at.elapsed()).is_err() {
+            error!("unable to write run report to '{}'", report_path);
+        }
+    }
+
+    match result {
+        Ok(report) => {
+            // Reports of parallel runs are printed together when all of them have finished
+            if !options.parallel {
+                print_report(&report, options.show_diff, options.output_style);
+            }
+
+            if is_timings_enabled() {
+                println!();
+                print!("{}", render_timings(&get_phase_timings(&get_api_call_timings(api_endpoint)),
+                                            started_at.elapsed()));
+            }
+
+            let success = !report.has_failures();
+
+            // Dry-run doesn't change anything, so it isn't reported as a run
+            if !options.dry_run {
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+                let run_state = RunState::from_report(&report, &previous_state, now);
+
+                if save_run_state(state_file_path, &run_state).is_err() {
+                    error!("unable to save run state to '{}'", state_file_path.display());
+                }
+
+                publish_metrics(client, &config.metrics, api_endpoint, Some(&report), started_at.elapsed(), success);
+                send_notifications(client, &config.notifications, Some(&report), started_at.elapsed());
+
+                if let Some(run_end_command) = &config.hooks.run_end {
+                    run_end_hook(run_end_command, Some(&report), started_at.elapsed(),
+                                 Duration::from_secs(config.hooks.timeout_secs));
+                }
+
+                if let Some(dashboard_config) = &config.dashboard {
+                    if update_dashboard(client, config, dashboard_config).is_err() {
+                        error!("unable to update dashboard '{}'", dashboard_config.name);
+                    }
+                }
+            }
+
+            let exit_code = if report.interrupted {
+                INTERRUPTED_EXIT_CODE
+
+            } else if report.timed_out {
+                TIMED_OUT_EXIT_CODE
+
+            } else if report.aborted {
+                ABORTED_EXIT_CODE
+
+            } else if report.error_limit_reached {
+                ERROR_LIMIT_EXIT_CODE
+
+            } else if success && options.dry_run && report.has_planned_changes() {
+                info!("changes have been planned, nothing was created");
+                CHANGES_PENDING_EXIT_CODE
+
+            } else if success && options.dry_run {
+                info!("no changes are pending");
+                0
+
+            } else if success {
+                info!("web scenarios and triggers have been created");
+                0
+
+            } else {
+                ERROR_EXIT_CODE
+            };
+
+            GenResult { exit_code, report: Some(report) }
+        }
+        Err(_) => {
+            if !options.dry_run {
+                publish_metrics(client, &config.metrics, api_endpoint, None, started_at.elapsed(), false);
+                send_notifications(client, &config.notifications, None, started_at.elapsed());
+
+                if let Some(run_end_command) = &config.hooks.run_end {
+                    run_end_hook(run_end_command, None, started_at.elapsed(),
+                                 Duration::from_secs(config.hooks.timeout_secs));
+                }
+            }
+
+            GenResult::failed(if is_api_circuit_open(api_endpoint) { ABORTED_EXIT_CODE }
+                              else { ERROR_EXIT_CODE })
+        }
     }
 }
 
-struct ZabbixObjects {
-    items: Vec<ZabbixItem>,
-    web_scenarios: Vec<ZabbixWebScenario>,
-    hosts: Vec<ZabbixHost>
+fn print_report(report: &RunReport, show_diff: bool, output_style: OutputStyle) {
+    if show_diff {
+        print!("{}", render_diff(report));
+        println!();
+    }
+
+    print_summary(report, output_style);
+}
+
+/// Runs `gen` on schedule until termination signal, returns process exit code
+fn start_daemon(config: &Config, output_style: OutputStyle) -> i32 {
+    let schedule = match get_run_schedule(&config.daemon) {
+        Ok(schedule) => schedule,
+        Err(_) => return ERROR_EXIT_CODE
+    };
+
+    let client = reqwest::blocking::Client::new();
+
+    init_audit_log(&config.zabbix.api.endpoint, config.audit.file_path.as_deref());
+
+    register_shutdown_handler();
+
+    let options = GenOptions { dry_run: false, show_diff: false, report_path: None, retry_failed: false,
+                              adopt: false, full: false, parallel: false, output_style };
+
+    let lock_file_path = Path::new(&config.processing.lock_file);
+
+    run_daemon(&schedule, config.daemon.jitter_secs, || {
+        match acquire_run_lock(lock_file_path) {
+            Ok(Some(_run_lock)) => {
+                run_gen(&client, config, &options);
+            }
+            Ok(None) => warn!("another wszl run is in progress (lock '{}'), run skipped",
+                              lock_file_path.display()),
+            Err(e) => error!("unable to acquire run lock '{}': {}", lock_file_path.display(), e)
+        }
+    });
+
+    0
+}
+
+#[cfg(windows)]
+fn run_service_command(service_matches: &ArgMatches, config_file_path: &Path, logging_level: &str,
+                       output_style: OutputStyle) {
+    let result = match service_matches.subcommand_name() {
+        Some(SERVICE_INSTALL_COMMAND) => {
+            // Service is started in system directory, so paths must be absolute
+            let work_dir = env::current_dir().expect("unable to get working directory");
+            let config_file = work_dir.join(config_file_path);
+
+            install_service(vec![
+                OsString::from(format!("--{}", WORK_DIR_ARGUMENT)), work_dir.into_os_string(),
+                OsString::from(format!("--{}", CONFIG_ARGUMENT)), config_file.into_os_string(),
+                OsString::from(format!("--{}", LOG_LEVEL_ARGUMENT)), OsString::from(logging_level),
+                OsString::from(SERVICE_COMMAND), OsString::from(SERVICE_RUN_COMMAND)
+            ])
+        }
+        Some(SERVICE_UNINSTALL_COMMAND) => uninstall_service(),
+        _ => load_config(config_file_path).and_then(|config| {
+            run_as_service(Box::new(move || start_daemon(&config, output_style)))
+        })
+    };
+
+    if result.is_err() {
+        exit(ERROR_EXIT_CODE)
+    }
+}
+
+#[cfg(not(windows))]
+fn run_service_command(_service_matches: &ArgMatches, _config_file_path: &Path, _logging_level: &str,
+                       _output_style: OutputStyle) {
+    error!("windows service is supported on Windows only, use daemon command with systemd");
+    exit(ERROR_EXIT_CODE)
+}
+
+fn load_config(file_path: &Path) -> OperationResult<Config> {
+    let mut config = load_config_from_file(file_path)?;
+
+    if let Some(syslog_config) = &config.syslog {
+        init_syslog(syslog_config)?;
+    }
+
+    if let Some(vault_config) = &config.vault {
+        let credentials = get_vault_credentials(&reqwest::blocking::Client::new(), vault_config)?;
+
+        if let Some(username) = credentials.username {
+            config.zabbix.api.username = username;
+        }
+
+        config.zabbix.api.password = credentials.password;
+    }
+
+    config.zabbix.api.password = get_api_password(&config.zabbix.api)?;
+
+    Ok(config)
 }
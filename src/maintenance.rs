@@ -0,0 +1,111 @@
+pub mod maintenance {
+    use chrono::{Local, TimeZone};
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    use crate::config::config::GraceConfig;
+    use crate::errors::errors::OperationError;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::http::http::send_post_request;
+    use crate::types::types::EmptyResult;
+    use crate::zabbix::zabbix;
+    use crate::zabbix::zabbix::{log_zabbix_error, MANAGED_TAG_NAME, MANAGED_TAG_VALUE, ZabbixError, ZabbixRequest};
+
+    /// 0 - with data collection, checks keep running
+    const MAINTENANCE_TYPE_WITH_DATA: u8 = 0;
+    /// 0 - one time only
+    const TIMEPERIOD_TYPE_ONE_TIME: u8 = 0;
+    /// Problem tag operator 'equals' of maintenance
+    const MAINTENANCE_TAG_OPERATOR_EQUALS: u8 = 0;
+
+    #[derive(Serialize)]
+    pub struct MaintenanceParams {
+        pub name: String,
+        pub active_since: u64,
+        pub active_till: u64,
+        pub maintenance_type: u8,
+        pub hostids: Vec<String>,
+        pub timeperiods: Vec<MaintenanceTimePeriod>,
+        pub tags: Vec<MaintenanceTag>
+    }
+
+    #[derive(Serialize)]
+    pub struct MaintenanceTimePeriod {
+        pub timeperiod_type: u8,
+        pub start_date: u64,
+        pub period: u64
+    }
+
+    #[derive(Serialize)]
+    pub struct MaintenanceTag {
+        pub tag: String,
+        pub operator: u8,
+        pub value: String
+    }
+
+    #[derive(Deserialize)]
+    struct CreateMaintenanceResponse {
+        error: Option<ZabbixError>
+    }
+
+    /// Maintenance from `now` for configured duration, only problems of managed triggers are suppressed.
+    /// Maintenance names are unique in Zabbix, so time of creation and the first host are part of it.
+    pub fn get_grace_maintenance_params(grace_config: &GraceConfig, hosts: &[&ZabbixHost],
+                                        now: u64) -> MaintenanceParams {
+        let created_at = Local.timestamp_opt(now as i64, 0).single()
+                            .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+                            .unwrap_or_else(|| now.to_string());
+
+        let first_host = hosts.first().map(|host| host.host.as_str()).unwrap_or_default();
+
+        MaintenanceParams {
+            name: format!("{} {} {}", grace_config.name, created_at, first_host).trim().to_string(),
+            active_since: now,
+            active_till: now + grace_config.duration_secs,
+            maintenance_type: MAINTENANCE_TYPE_WITH_DATA,
+            hostids: hosts.iter().map(|host| host.hostid.to_string()).collect(),
+            timeperiods: vec![MaintenanceTimePeriod {
+                timeperiod_type: TIMEPERIOD_TYPE_ONE_TIME,
+                start_date: now,
+                period: grace_config.duration_secs
+            }],
+            tags: vec![MaintenanceTag {
+                tag: MANAGED_TAG_NAME.to_string(),
+                operator: MAINTENANCE_TAG_OPERATOR_EQUALS,
+                value: MANAGED_TAG_VALUE.to_string()
+            }]
+        }
+    }
+
+    pub fn create_maintenance(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                              params: MaintenanceParams) -> EmptyResult {
+        let name = params.name.to_string();
+
+        let request: ZabbixRequest<MaintenanceParams> = ZabbixRequest::new(
+            "maintenance.create", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let create_response: CreateMaintenanceResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match create_response.error {
+                    Some(_) => {
+                        log_zabbix_error(&create_response.error);
+                        error!("unable to create maintenance '{}'", name);
+                        Err(OperationError::Error)
+                    }
+                    None => {
+                        info!("maintenance '{}' has been created", name);
+                        Ok(())
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to create maintenance '{}'", name);
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
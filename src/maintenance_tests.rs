@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod maintenance_tests {
+    use crate::config::config::GraceConfig;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::maintenance::maintenance::get_grace_maintenance_params;
+
+    #[test]
+    fn grace_maintenance_should_cover_hosts_for_configured_duration() {
+        let hosts = [
+            ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(),
+                         maintenance_status: "0".to_string(), status: "0".to_string(),
+                         proxy_hostid: "0".to_string() },
+            ZabbixHost { hostid: "10002".to_string(), host: "web-02".to_string(),
+                         maintenance_status: "0".to_string(), status: "0".to_string(),
+                         proxy_hostid: "0".to_string() }
+        ];
+
+        let grace_config = GraceConfig { duration_secs: 7200, name: "WSZL grace period".to_string() };
+
+        let params = get_grace_maintenance_params(&grace_config, &hosts.iter().collect::<Vec<_>>(), 1700000000);
+
+        assert!(params.name.starts_with("WSZL grace period "));
+        assert!(params.name.ends_with(" web-01"));
+        assert_eq!(params.active_since, 1700000000);
+        assert_eq!(params.active_till, 1700007200);
+        assert_eq!(params.maintenance_type, 0);
+        assert_eq!(params.hostids, vec!["10001", "10002"]);
+        assert_eq!(params.timeperiods[0].start_date, 1700000000);
+        assert_eq!(params.timeperiods[0].period, 7200);
+        assert_eq!(params.tags[0].tag, "managed-by");
+        assert_eq!(params.tags[0].value, "wszl");
+    }
+}
@@ -0,0 +1,186 @@
+pub mod metrics {
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::path::Path;
+    use std::sync::Mutex;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use crate::config::config::MetricsConfig;
+    use crate::errors::errors::OperationError;
+    use crate::report::report::RunReport;
+    use crate::types::types::EmptyResult;
+
+    const METRIC_PREFIX: &str = "wszl";
+
+    const PUSHGATEWAY_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+    /// Stats of every Zabbix API endpoint, parallel runs against different servers are counted apart
+    static API_CALL_STATS: Mutex<BTreeMap<String, ApiCallStats>> = Mutex::new(BTreeMap::new());
+
+    #[derive(Clone)]
+    pub struct ApiCallStats {
+        pub calls: u64,
+        pub failures: u64,
+        pub total_duration: Duration,
+        pub max_duration: Duration,
+        /// Errors returned by Zabbix API or transport
+        pub errors: Vec<String>
+    }
+
+    impl ApiCallStats {
+        const fn new() -> ApiCallStats {
+            ApiCallStats {
+                calls: 0,
+                failures: 0,
+                total_duration: Duration::from_secs(0),
+                max_duration: Duration::from_secs(0),
+                errors: Vec::new()
+            }
+        }
+    }
+
+    pub fn record_api_call(api_endpoint: &str, duration: Duration, success: bool) {
+        let mut api_call_stats = API_CALL_STATS.lock().unwrap();
+        let stats = api_call_stats.entry(api_endpoint.to_string()).or_insert_with(ApiCallStats::new);
+
+        stats.calls += 1;
+        if !success { stats.failures += 1 }
+
+        stats.total_duration += duration;
+        if duration > stats.max_duration { stats.max_duration = duration }
+    }
+
+    pub fn record_api_error(api_endpoint: &str, message: &str) {
+        API_CALL_STATS.lock().unwrap().entry(api_endpoint.to_string()).or_insert_with(ApiCallStats::new)
+                                      .errors.push(message.to_string());
+    }
+
+    /// Stats are per run, daemon mode resets them before every run
+    pub fn reset_api_call_stats(api_endpoint: &str) {
+        API_CALL_STATS.lock().unwrap().insert(api_endpoint.to_string(), ApiCallStats::new());
+    }
+
+    pub fn get_api_call_stats(api_endpoint: &str) -> ApiCallStats {
+        API_CALL_STATS.lock().unwrap().get(api_endpoint).cloned().unwrap_or_else(ApiCallStats::new)
+    }
+
+    /// Publishes run metrics to the node_exporter textfile and/or pushgateway, if configured.
+    /// `report` is absent when the run failed before any item was processed.
+    pub fn publish_metrics(client: &reqwest::blocking::Client, metrics_config: &MetricsConfig, api_endpoint: &str,
+                           report: Option<&RunReport>, run_duration: Duration, success: bool) {
+        if metrics_config.textfile_path.is_none() && metrics_config.pushgateway_url.is_none() {
+            return;
+        }
+
+        let content = render_metrics(&get_api_call_stats(api_endpoint), report, run_duration, success);
+
+        if let Some(textfile_path) = &metrics_config.textfile_path {
+            match write_textfile(Path::new(textfile_path), &content) {
+                Ok(_) => debug!("metrics have been written to '{}'", textfile_path),
+                Err(e) => error!("unable to write metrics to '{}': {}", textfile_path, e)
+            }
+        }
+
+        if let Some(pushgateway_url) = &metrics_config.pushgateway_url {
+            if push_metrics(client, pushgateway_url, &metrics_config.job, &content).is_ok() {
+                debug!("metrics have been pushed to '{}'", pushgateway_url)
+            }
+        }
+    }
+
+    pub fn render_metrics(api_stats: &ApiCallStats, report: Option<&RunReport>, run_duration: Duration,
+                          success: bool) -> String {
+        let mut content = String::new();
+
+        if let Some(report) = report {
+            let counts = report.get_counts();
+
+            append_gauge(&mut content, "items_scanned", "Items processed during the last run",
+                         counts.items as f64);
+            append_gauge(&mut content, "scenarios_created", "Web scenarios created during the last run",
+                         counts.scenarios_created as f64);
+            append_gauge(&mut content, "triggers_created", "Triggers created during the last run",
+                         counts.triggers_created as f64);
+            append_gauge(&mut content, "items_renamed", "Items which web scenario and triggers have been renamed",
+                         counts.renamed as f64);
+            append_gauge(&mut content, "items_adopted", "Items which hand-made web scenario has been adopted",
+                         counts.adopted as f64);
+            append_gauge(&mut content, "items_planned", "Items with changes planned by dry-run",
+                         counts.planned as f64);
+            append_gauge(&mut content, "items_skipped",
+                         "Items skipped because web scenario already exists, host is disabled or by script",
+                         counts.skipped as f64);
+            append_gauge(&mut content, "items_duplicate", "Items skipped because their url is taken from another item",
+                         counts.duplicates as f64);
+            append_gauge(&mut content, "items_unreachable", "Items which url didn't respond to probe",
+                         counts.unreachable as f64);
+            append_gauge(&mut content, "items_deferred", "Items deferred because host is in maintenance",
+                         counts.deferred as f64);
+            append_gauge(&mut content, "items_stale", "Items skipped because they are unsupported or outdated",
+                         counts.stale as f64);
+            append_gauge(&mut content, "items_failed", "Items failed during the last run",
+                         counts.failed as f64);
+        }
+
+        append_gauge(&mut content, "api_calls", "Zabbix API calls made during the last run",
+                     api_stats.calls as f64);
+        append_gauge(&mut content, "api_call_failures", "Failed Zabbix API calls during the last run",
+                     api_stats.failures as f64);
+        append_gauge(&mut content, "api_call_duration_seconds_sum",
+                     "Total time spent in Zabbix API calls during the last run",
+                     api_stats.total_duration.as_secs_f64());
+        append_gauge(&mut content, "api_call_duration_seconds_max",
+                     "Slowest Zabbix API call during the last run",
+                     api_stats.max_duration.as_secs_f64());
+
+        append_gauge(&mut content, "run_duration_seconds", "Duration of the last run",
+                     run_duration.as_secs_f64());
+        append_gauge(&mut content, "last_run_success", "1 if the last run finished without errors",
+                     if success { 1.0 } else { 0.0 });
+
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)
+                                         .unwrap_or_default().as_secs();
+        append_gauge(&mut content, "last_run_timestamp_seconds", "Unix time of the last run",
+                     timestamp as f64);
+
+        content
+    }
+
+    fn append_gauge(content: &mut String, name: &str, help: &str, value: f64) {
+        content.push_str(&format!("# HELP {}_{} {}\n", METRIC_PREFIX, name, help));
+        content.push_str(&format!("# TYPE {}_{} gauge\n", METRIC_PREFIX, name));
+        content.push_str(&format!("{}_{} {}\n", METRIC_PREFIX, name, value));
+    }
+
+    /// Writes through a temporary file, so node_exporter never reads a partial file.
+    fn write_textfile(path: &Path, content: &str) -> EmptyResult {
+        let temp_path = path.with_extension("prom.tmp");
+        fs::write(&temp_path, content)?;
+        fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    fn push_metrics(client: &reqwest::blocking::Client, pushgateway_url: &str,
+                    job: &str, content: &str) -> EmptyResult {
+        let url = format!("{}/metrics/job/{}", pushgateway_url.trim_end_matches('/'), job);
+
+        match client.put(&url)
+            .header("Content-Type", PUSHGATEWAY_CONTENT_TYPE)
+            .body(content.to_string())
+            .send() {
+            Ok(response) => {
+                if response.status().is_success() {
+                    Ok(())
+
+                } else {
+                    error!("unexpected pushgateway response code {}", response.status());
+                    Err(OperationError::Error)
+                }
+            }
+            Err(e) => {
+                error!("unable to push metrics to '{}': {}", url, e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod metrics_tests {
+    use std::time::Duration;
+
+    use crate::report::report::{ItemOutcome, ItemStatus, RunReport};
+    use crate::metrics::metrics::{get_api_call_stats, record_api_call, record_api_error, render_metrics,
+                                  reset_api_call_stats};
+
+    const EU_API_ENDPOINT: &str = "http://zabbix-eu.metrics/api_jsonrpc.php";
+    const US_API_ENDPOINT: &str = "http://zabbix-us.metrics/api_jsonrpc.php";
+
+    #[test]
+    fn stats_of_endpoints_should_be_independent() {
+        reset_api_call_stats(EU_API_ENDPOINT);
+        reset_api_call_stats(US_API_ENDPOINT);
+
+        record_api_call(EU_API_ENDPOINT, Duration::from_millis(100), true);
+        record_api_call(EU_API_ENDPOINT, Duration::from_millis(300), false);
+        record_api_error(EU_API_ENDPOINT, "httptest.create: error -32602: Invalid params.");
+        record_api_call(US_API_ENDPOINT, Duration::from_millis(50), true);
+
+        let eu_stats = get_api_call_stats(EU_API_ENDPOINT);
+        assert_eq!(eu_stats.calls, 2);
+        assert_eq!(eu_stats.failures, 1);
+        assert_eq!(eu_stats.max_duration, Duration::from_millis(300));
+        assert_eq!(eu_stats.errors.len(), 1);
+
+        let us_stats = get_api_call_stats(US_API_ENDPOINT);
+        assert_eq!(us_stats.calls, 1);
+        assert_eq!(us_stats.failures, 0);
+        assert!(us_stats.errors.is_empty());
+
+        let content = render_metrics(&eu_stats, None, Duration::from_secs(5), false);
+        assert!(content.contains("wszl_api_calls 2\n"));
+        assert!(content.contains("wszl_api_call_failures 1\n"));
+
+        reset_api_call_stats(EU_API_ENDPOINT);
+        assert_eq!(get_api_call_stats(EU_API_ENDPOINT).calls, 0);
+    }
+
+    #[test]
+    fn every_outcome_count_should_be_exported() {
+        let mut report = RunReport::new();
+
+        for status in [ItemStatus::Created, ItemStatus::Renamed("https://old.example.com".to_string()),
+                       ItemStatus::Adopted("Homepage".to_string()), ItemStatus::SkippedExisting,
+                       ItemStatus::Deferred, ItemStatus::Stale("item has no data".to_string())] {
+            let mut outcome = ItemOutcome::new("vhost item", "web-01");
+            outcome.status = status;
+            report.outcomes.push(outcome);
+        }
+
+        let api_stats = get_api_call_stats("http://zabbix-none.metrics/api_jsonrpc.php");
+        let content = render_metrics(&api_stats, Some(&report), Duration::from_secs(5), true);
+
+        for metric in ["wszl_items_scanned 6\n", "wszl_items_renamed 1\n", "wszl_items_adopted 1\n",
+                       "wszl_items_planned 0\n", "wszl_items_skipped 1\n", "wszl_items_duplicate 0\n",
+                       "wszl_items_unreachable 0\n", "wszl_items_deferred 1\n", "wszl_items_stale 1\n",
+                       "wszl_items_failed 0\n"] {
+            assert!(content.contains(metric), "{} is missing", metric);
+        }
+    }
+}
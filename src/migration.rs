@@ -0,0 +1,172 @@
+pub mod migration {
+    use std::collections::HashMap;
+
+    use reqwest::blocking::Client;
+
+    use crate::config::config::{Config, TemplatesConfig};
+    use crate::errors::errors::OperationError;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::latency::latency::is_latency_trigger;
+    use crate::session::session::{close_api_session, open_api_session};
+    use crate::templates::templates::{NameTemplates, ObjectNames};
+    use crate::triggers::triggers::{apply_correlation_tag, find_url_triggers, get_trigger_params, update_trigger};
+    use crate::types::types::OperationResult;
+    use crate::webscenarios::webscenarios::{find_managed_web_scenarios, find_web_scenarios,
+                                            update_web_scenario_names, ZabbixWebScenario};
+
+    /// Managed web scenario named by the old hard-coded scheme and its names by configured templates
+    pub struct NameMigration<'a> {
+        pub web_scenario: &'a ZabbixWebScenario,
+        pub host: &'a ZabbixHost,
+        pub url: String,
+        pub names: ObjectNames
+    }
+
+    /// Renames managed web scenarios and triggers named by the old scheme ("Check index page '...'")
+    /// after `templates` from config, returns number of migrated checks.
+    /// Objects are updated in place, so collected history is kept.
+    /// Any failed migration is an error after all checks have been tried.
+    pub fn migrate_names(client: &Client, config: &Config, dry_run: bool) -> OperationResult<usize> {
+        let api_endpoint = &config.zabbix.api.endpoint;
+
+        let legacy_templates = NameTemplates::new(&TemplatesConfig::default())?;
+        let name_templates = NameTemplates::new(&config.templates)?;
+
+        match open_api_session(client, &config.zabbix.api) {
+            Ok(auth_token) => {
+                let result = find_managed_web_scenarios(client, api_endpoint, &auth_token)
+                    .and_then(|managed_web_scenarios| {
+                        let mut hosts: Vec<&ZabbixHost> = vec![];
+
+                        for host in managed_web_scenarios.iter().flat_map(|web_scenario| &web_scenario.hosts) {
+                            if !hosts.iter().any(|known_host| known_host.hostid == host.hostid) {
+                                hosts.push(host);
+                            }
+                        }
+
+                        if hosts.is_empty() {
+                            info!("no web scenarios managed by wszl found");
+                            return Ok(0)
+                        }
+
+                        let host_ids: Vec<String> = hosts.iter().map(|host| host.hostid.to_string()).collect();
+                        let web_scenarios = find_web_scenarios(client, api_endpoint, &auth_token, &host_ids)?;
+
+                        let migrations = get_name_migrations(&web_scenarios, &hosts, &legacy_templates,
+                                                             &name_templates)?;
+
+                        if migrations.is_empty() {
+                            info!("no web scenarios named by the old scheme found");
+                        }
+
+                        if dry_run {
+                            for migration in &migrations {
+                                info!("web scenario '{}' of host '{}' would be renamed to '{}'",
+                                      migration.web_scenario.name, migration.host.host, migration.names.scenario);
+                            }
+
+                            return Ok(migrations.len())
+                        }
+
+                        let mut migrated = 0;
+                        let mut failed = false;
+
+                        for migration in &migrations {
+                            if migrate_check(client, config, &auth_token, migration) {
+                                migrated += 1;
+                            } else {
+                                failed = true;
+                            }
+                        }
+
+                        if failed { Err(OperationError::Error) } else { Ok(migrated) }
+                    });
+
+                if close_api_session(client, &config.zabbix.api, &auth_token).is_err() {
+                    warn!("unable to close api session");
+                }
+
+                result
+            }
+            Err(_) => {
+                error!("unable to login");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Web scenarios which have the name of old scheme for url of their first step and get a different name
+    /// from templates. Templates get no `groups`, item key patterns aren't known here.
+    pub fn get_name_migrations<'a>(web_scenarios: &'a [ZabbixWebScenario], hosts: &[&'a ZabbixHost],
+                                   legacy_templates: &NameTemplates,
+                                   name_templates: &NameTemplates) -> OperationResult<Vec<NameMigration<'a>>> {
+        let mut migrations = vec![];
+
+        for web_scenario in web_scenarios {
+            let url = match web_scenario.steps.iter().find(|step| step.no == "1") {
+                Some(step) => &step.url,
+                None => continue
+            };
+
+            let host = match hosts.iter().find(|host| host.hostid == web_scenario.hostid) {
+                Some(host) => *host,
+                None => continue
+            };
+
+            let legacy_names = legacy_templates.get_object_names(host, url, &HashMap::new(), None)?;
+
+            if web_scenario.name != legacy_names.scenario {
+                continue;
+            }
+
+            let names = name_templates.get_object_names(host, url, &HashMap::new(), None)?;
+
+            if names.scenario == web_scenario.name {
+                continue;
+            }
+
+            migrations.push(NameMigration { web_scenario, host, url: url.to_string(), names });
+        }
+
+        Ok(migrations)
+    }
+
+    /// Web scenario and its trigger get new names, latency trigger is named after url only
+    fn migrate_check(client: &Client, config: &Config, auth_token: &str, migration: &NameMigration) -> bool {
+        let api_endpoint = &config.zabbix.api.endpoint;
+        let host = &migration.host.host;
+        let url = &migration.url;
+
+        if update_web_scenario_names(client, api_endpoint, auth_token, migration.web_scenario,
+                                     &migration.names).is_err() {
+            error!("unable to rename web scenario of url '{}' of host '{}'", url, host);
+            return false
+        }
+
+        let mut trigger_params = get_trigger_params(&migration.names, url);
+
+        if let Some(correlation_tag) = &config.zabbix.correlation_tag {
+            apply_correlation_tag(&mut trigger_params, correlation_tag);
+        }
+
+        match find_url_triggers(client, api_endpoint, auth_token, &migration.host.hostid, url) {
+            Ok(triggers) => {
+                for trigger in triggers.iter().filter(|trigger| !is_latency_trigger(&trigger.tags)) {
+                    if update_trigger(client, api_endpoint, auth_token, &trigger.triggerid,
+                                      &trigger_params).is_err() {
+                        error!("unable to rename trigger of url '{}' of host '{}'", url, host);
+                        return false
+                    }
+                }
+
+                info!("web scenario and trigger of url '{}' of host '{}' have been renamed to '{}'",
+                      url, host, migration.names.scenario);
+                true
+            }
+            Err(_) => {
+                error!("unable to find trigger of url '{}' of host '{}'", url, host);
+                false
+            }
+        }
+    }
+}
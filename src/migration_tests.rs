@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod migration_tests {
+    use crate::config::config::TemplatesConfig;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::migration::migration::get_name_migrations;
+    use crate::templates::templates::NameTemplates;
+    use crate::webscenarios::webscenarios::{ZabbixHttpStep, ZabbixWebScenario};
+
+    fn get_web_scenario(httptestid: &str, name: &str, url: &str) -> ZabbixWebScenario {
+        ZabbixWebScenario {
+            httptestid: httptestid.to_string(),
+            hostid: "10001".to_string(),
+            name: name.to_string(),
+            steps: vec![ZabbixHttpStep { httpstepid: format!("{}1", httptestid), no: "1".to_string(),
+                                         url: url.to_string(), name: "Index page".to_string() }],
+            tags: vec![]
+        }
+    }
+
+    fn get_host() -> ZabbixHost {
+        ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(), maintenance_status: String::new(),
+                     status: String::new(), proxy_hostid: String::new() }
+    }
+
+    fn get_custom_templates() -> NameTemplates {
+        let templates_config = TemplatesConfig {
+            scenario_name: "{{ host.host }}: {{ url }}".to_string(),
+            ..TemplatesConfig::default()
+        };
+
+        NameTemplates::new(&templates_config).unwrap()
+    }
+
+    #[test]
+    fn only_web_scenarios_named_by_old_scheme_should_be_migrated() {
+        let web_scenarios = [
+            get_web_scenario("1", "Check index page 'https://a.example.com'", "https://a.example.com"),
+            get_web_scenario("2", "web-01: https://b.example.com", "https://b.example.com"),
+            get_web_scenario("3", "Check index page 'https://old.example.com'", "https://c.example.com")
+        ];
+        let host = get_host();
+
+        let legacy_templates = NameTemplates::new(&TemplatesConfig::default()).unwrap();
+
+        let migrations = get_name_migrations(&web_scenarios, &[&host], &legacy_templates,
+                                             &get_custom_templates()).unwrap();
+
+        assert_eq!(migrations.len(), 1);
+        assert_eq!(migrations[0].web_scenario.httptestid, "1");
+        assert_eq!(migrations[0].url, "https://a.example.com");
+        assert_eq!(migrations[0].names.scenario, "web-01: https://a.example.com");
+    }
+
+    #[test]
+    fn default_templates_should_need_no_migration() {
+        let web_scenarios = [
+            get_web_scenario("1", "Check index page 'https://a.example.com'", "https://a.example.com")
+        ];
+        let host = get_host();
+
+        let legacy_templates = NameTemplates::new(&TemplatesConfig::default()).unwrap();
+        let name_templates = NameTemplates::new(&TemplatesConfig::default()).unwrap();
+
+        assert!(get_name_migrations(&web_scenarios, &[&host], &legacy_templates, &name_templates)
+                    .unwrap().is_empty());
+    }
+}
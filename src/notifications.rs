@@ -0,0 +1,217 @@
+pub mod notifications {
+    use std::collections::BTreeMap;
+    use std::error::Error;
+    use std::time::Duration;
+
+    use serde::Serialize;
+    use serde_json::json;
+
+    use crate::config::config::{NotificationsConfig, NotifyOn};
+    use crate::email::email::send_email_report;
+    use crate::errors::errors::OperationError;
+    use crate::report::report::{ItemStatus, RunCounts, RunReport};
+    use crate::sender::sender::send_run_outcome;
+    use crate::types::types::EmptyResult;
+
+    const TELEGRAM_API_URL: &str = "https://api.telegram.org";
+
+    const RUN_FAILED_MESSAGE: &str = "run failed before items were processed, see wszl.log for details";
+
+    #[derive(Serialize)]
+    pub struct RunSummary {
+        pub status: String,
+        pub duration_seconds: f64,
+        pub counts: Option<RunCounts>,
+        pub created: Vec<CreatedObject>,
+        pub failed: Vec<FailedObject>,
+        pub errors: Vec<String>
+    }
+
+    #[derive(Serialize)]
+    pub struct CreatedObject {
+        pub host: String,
+        pub url: String
+    }
+
+    #[derive(Serialize)]
+    pub struct FailedObject {
+        pub item: String,
+        pub host: String,
+        pub url: String,
+        pub reason: String
+    }
+
+    /// Sends run summary to configured notification channels.
+    /// `report` is absent when the run failed before any item was processed.
+    pub fn send_notifications(client: &reqwest::blocking::Client, notifications_config: &NotificationsConfig,
+                              report: Option<&RunReport>, run_duration: Duration) {
+        let summary = get_run_summary(report, run_duration);
+
+        if let Some(webhook_url) = &notifications_config.webhook_url {
+            if send_webhook(client, "webhook", webhook_url, &summary).is_ok() {
+                debug!("run summary has been sent to webhook")
+            }
+        }
+
+        let message = get_summary_message(&summary);
+
+        // Slack and Mattermost incoming webhooks accept the same payload
+        for (chat, chat_config) in [("slack", &notifications_config.slack),
+                                    ("mattermost", &notifications_config.mattermost)] {
+            if let Some(chat_config) = chat_config {
+                if should_notify(&chat_config.notify_on, &summary) &&
+                    send_webhook(client, chat, &chat_config.webhook_url, &json!({ "text": message })).is_ok() {
+                    debug!("run summary has been sent to {}", chat)
+                }
+            }
+        }
+
+        if let Some(telegram_config) = &notifications_config.telegram {
+            if should_notify(&telegram_config.notify_on, &summary) {
+                let url = format!("{}/bot{}/sendMessage", TELEGRAM_API_URL, telegram_config.bot_token);
+                let request = json!({ "chat_id": telegram_config.chat_id, "text": message });
+
+                if send_webhook(client, "telegram", &url, &request).is_ok() {
+                    debug!("run summary has been sent to telegram chat '{}'", telegram_config.chat_id)
+                }
+            }
+        }
+
+        // Every run is reported, so Zabbix can alert when runs stop (nodata)
+        if let Some(trapper_config) = &notifications_config.trapper {
+            if send_run_outcome(trapper_config, &summary).is_ok() {
+                debug!("run outcome has been sent to trapper item '{}'", trapper_config.key)
+            }
+        }
+
+        if let Some(email_config) = &notifications_config.email {
+            if should_notify(&email_config.notify_on, &summary) &&
+                send_email_report(email_config, &summary, &message).is_ok() {
+                debug!("run summary has been sent to {}", email_config.to.join(", "))
+            }
+        }
+    }
+
+    pub fn should_notify(notify_on: &NotifyOn, summary: &RunSummary) -> bool {
+        let has_errors = summary.status != "success";
+
+        match notify_on {
+            NotifyOn::Always => true,
+            NotifyOn::Changes => has_errors || !summary.created.is_empty(),
+            NotifyOn::Errors => has_errors
+        }
+    }
+
+    /// Human-readable summary for chats, i.e.
+    /// "wszl: 3 new urls onboarded on host web-01, 1 failure: web-02 https://example.com - unable to create trigger"
+    pub fn get_summary_message(summary: &RunSummary) -> String {
+        let mut created_by_host: BTreeMap<&str, usize> = BTreeMap::new();
+
+        for created in &summary.created {
+            *created_by_host.entry(&created.host).or_insert(0) += 1;
+        }
+
+        let mut parts: Vec<String> = created_by_host.iter()
+            .map(|(host, count)| format!("{} new url{} onboarded on host {}", count, plural(*count), host))
+            .collect();
+
+        if !summary.failed.is_empty() {
+            let failures: Vec<String> = summary.failed.iter()
+                .map(|failed| format!("{} {} - {}", failed.host, failed.url, failed.reason))
+                .collect();
+
+            parts.push(format!("{} failure{}: {}", failures.len(), plural(failures.len()), failures.join("; ")));
+
+        } else if summary.counts.is_none() {
+            parts.push(RUN_FAILED_MESSAGE.to_string());
+        }
+
+        if summary.status == "interrupted" {
+            parts.push("run was interrupted".to_string());
+        }
+
+        if summary.status == "timed-out" {
+            parts.push("run has exceeded its timeout".to_string());
+        }
+
+        if parts.is_empty() {
+            parts.push("no changes".to_string());
+        }
+
+        format!("wszl: {}", parts.join(", "))
+    }
+
+    fn plural(count: usize) -> &'static str {
+        if count == 1 { "" } else { "s" }
+    }
+
+    pub fn get_run_summary(report: Option<&RunReport>, run_duration: Duration) -> RunSummary {
+        let mut summary = RunSummary {
+            status: "failed".to_string(),
+            duration_seconds: run_duration.as_secs_f64(),
+            counts: None,
+            created: vec![],
+            failed: vec![],
+            errors: vec![]
+        };
+
+        match report {
+            Some(report) => {
+                summary.status = if report.interrupted {
+                    "interrupted"
+                } else if report.timed_out {
+                    "timed-out"
+                } else if report.has_failures() {
+                    "failed"
+                } else {
+                    "success"
+                }.to_string();
+
+                summary.counts = Some(report.get_counts());
+
+                for outcome in &report.outcomes {
+                    match &outcome.status {
+                        ItemStatus::Created => summary.created.push(CreatedObject {
+                            host: outcome.host.to_string(),
+                            url: outcome.url.to_string()
+                        }),
+                        ItemStatus::Failed(reason) => summary.failed.push(FailedObject {
+                            item: outcome.item_name.to_string(),
+                            host: outcome.host.to_string(),
+                            url: outcome.url.to_string(),
+                            reason: reason.to_string()
+                        }),
+                        _ => {}
+                    }
+                }
+
+                summary.errors = summary.failed.iter().map(|failed| failed.reason.to_string()).collect();
+            }
+            None => summary.errors.push(RUN_FAILED_MESSAGE.to_string())
+        }
+
+        summary
+    }
+
+    /// Webhook urls carry secrets, so they are never written to the log
+    fn send_webhook<T: Serialize>(client: &reqwest::blocking::Client, target: &str, webhook_url: &str,
+                                  payload: &T) -> EmptyResult {
+        match client.post(webhook_url).json(payload).send() {
+            Ok(response) => {
+                if response.status().is_success() {
+                    Ok(())
+
+                } else {
+                    error!("unexpected {} response code {}", target, response.status());
+                    Err(OperationError::Error)
+                }
+            }
+            Err(e) => {
+                let reason = e.source().map(|source| source.to_string())
+                                       .unwrap_or_else(|| "request error".to_string());
+                error!("unable to send run summary to {}: {}", target, reason);
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod notifications_tests {
+    use std::time::Duration;
+
+    use crate::config::config::NotifyOn;
+    use crate::notifications::notifications::{get_run_summary, get_summary_message, should_notify};
+    use crate::report::report::{ItemOutcome, ItemStatus, RunReport};
+
+    #[test]
+    fn summary_should_contain_created_and_failed_objects() {
+        let mut report = RunReport::new();
+
+        let mut created = ItemOutcome::new("vhost item", "web-01");
+        created.url = "https://example.com".to_string();
+        created.scenario_created = true;
+        created.trigger_created = true;
+        report.outcomes.push(created);
+
+        let mut skipped = ItemOutcome::new("vhost item", "web-01");
+        skipped.status = ItemStatus::SkippedExisting;
+        report.outcomes.push(skipped);
+
+        let mut failed = ItemOutcome::new("vhost item", "web-02");
+        failed.url = "https://shop.example.com".to_string();
+        failed.fail("unable to create trigger");
+        report.outcomes.push(failed);
+
+        let summary = get_run_summary(Some(&report), Duration::from_secs(3));
+
+        assert_eq!(summary.status, "failed");
+        assert_eq!(summary.duration_seconds, 3.0);
+        assert_eq!(summary.created.len(), 1);
+        assert_eq!(summary.created[0].url, "https://example.com");
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].host, "web-02");
+        assert_eq!(summary.errors, vec!["unable to create trigger"]);
+
+        let counts = summary.counts.unwrap();
+        assert_eq!(counts.items, 3);
+        assert_eq!(counts.skipped, 1);
+    }
+
+    #[test]
+    fn summary_of_failed_run_should_contain_error() {
+        let summary = get_run_summary(None, Duration::from_secs(1));
+
+        assert_eq!(summary.status, "failed");
+        assert!(summary.counts.is_none());
+        assert_eq!(summary.errors.len(), 1);
+    }
+
+    #[test]
+    fn timed_out_run_should_be_reported() {
+        let mut report = RunReport::new();
+        report.timed_out = true;
+
+        let summary = get_run_summary(Some(&report), Duration::from_secs(900));
+
+        assert_eq!(summary.status, "timed-out");
+        assert_eq!(get_summary_message(&summary), "wszl: run has exceeded its timeout");
+    }
+
+    #[test]
+    fn message_should_group_created_urls_by_host() {
+        let mut report = RunReport::new();
+
+        for url in &["https://a.example.com", "https://b.example.com"] {
+            let mut created = ItemOutcome::new("vhost item", "web-01");
+            created.url = url.to_string();
+            report.outcomes.push(created);
+        }
+
+        let mut failed = ItemOutcome::new("vhost item", "web-02");
+        failed.url = "https://c.example.com".to_string();
+        failed.fail("unable to create trigger");
+        report.outcomes.push(failed);
+
+        let summary = get_run_summary(Some(&report), Duration::from_secs(1));
+
+        assert_eq!(get_summary_message(&summary),
+                   "wszl: 2 new urls onboarded on host web-01, \
+                    1 failure: web-02 https://c.example.com - unable to create trigger");
+    }
+
+    #[test]
+    fn notifications_should_respect_severity() {
+        let mut report = RunReport::new();
+
+        let no_changes = get_run_summary(Some(&report), Duration::from_secs(1));
+        assert!(should_notify(&NotifyOn::Always, &no_changes));
+        assert!(!should_notify(&NotifyOn::Changes, &no_changes));
+        assert!(!should_notify(&NotifyOn::Errors, &no_changes));
+
+        report.outcomes.push(ItemOutcome::new("vhost item", "web-01"));
+
+        let changes = get_run_summary(Some(&report), Duration::from_secs(1));
+        assert!(should_notify(&NotifyOn::Changes, &changes));
+        assert!(!should_notify(&NotifyOn::Errors, &changes));
+
+        let failed_run = get_run_summary(None, Duration::from_secs(1));
+        assert!(should_notify(&NotifyOn::Errors, &failed_run));
+    }
+}
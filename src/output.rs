@@ -0,0 +1,55 @@
+pub mod output {
+    use std::io::IsTerminal;
+
+    pub const COLOR_AUTO: &str = "auto";
+    pub const COLOR_ALWAYS: &str = "always";
+    pub const COLOR_NEVER: &str = "never";
+
+    const NO_COLOR_ENV_VAR: &str = "NO_COLOR";
+
+    const RESET: &str = "\x1b[0m";
+
+    /// Terminal output (summary table), log file is never colored
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub enum OutputStyle {
+        Plain,
+        Colored
+    }
+
+    #[derive(Clone, Copy)]
+    pub enum Color {
+        Green,
+        Red,
+        Yellow,
+        Dim
+    }
+
+    /// `auto` colors output only for terminal and when `NO_COLOR` isn't set
+    pub fn get_output_style(color_mode: &str, is_terminal: bool, no_color: bool) -> OutputStyle {
+        match color_mode {
+            COLOR_ALWAYS => OutputStyle::Colored,
+            COLOR_NEVER => OutputStyle::Plain,
+            _ => if is_terminal && !no_color { OutputStyle::Colored } else { OutputStyle::Plain }
+        }
+    }
+
+    pub fn get_stdout_style(color_mode: &str) -> OutputStyle {
+        get_output_style(color_mode, std::io::stdout().is_terminal(), std::env::var_os(NO_COLOR_ENV_VAR).is_some())
+    }
+
+    pub fn paint(text: &str, color: Color, style: OutputStyle) -> String {
+        match style {
+            OutputStyle::Plain => text.to_string(),
+            OutputStyle::Colored => {
+                let code = match color {
+                    Color::Green => "\x1b[32m",
+                    Color::Red => "\x1b[31m",
+                    Color::Yellow => "\x1b[33m",
+                    Color::Dim => "\x1b[2m"
+                };
+
+                format!("{}{}{}", code, text, RESET)
+            }
+        }
+    }
+}
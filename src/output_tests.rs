@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod output_tests {
+    use crate::output::output::{Color, get_output_style, OutputStyle, paint};
+    use crate::report::report::{ItemOutcome, ItemStatus, render_compact_summary, render_outcome_row, RunReport};
+
+    #[test]
+    fn colors_should_be_used_only_for_terminal_without_no_color() {
+        assert_eq!(get_output_style("auto", true, false), OutputStyle::Colored);
+        assert_eq!(get_output_style("auto", false, false), OutputStyle::Plain);
+        assert_eq!(get_output_style("auto", true, true), OutputStyle::Plain);
+        assert_eq!(get_output_style("always", false, true), OutputStyle::Colored);
+        assert_eq!(get_output_style("never", true, false), OutputStyle::Plain);
+    }
+
+    #[test]
+    fn plain_style_should_not_have_escape_codes() {
+        assert_eq!(paint("created", Color::Green, OutputStyle::Plain), "created");
+        assert_eq!(paint("failed", Color::Red, OutputStyle::Colored), "\x1b[31mfailed\x1b[0m");
+    }
+
+    #[test]
+    fn failed_row_should_be_red_with_symbol() {
+        let mut outcome = ItemOutcome::new("vhost.item[https://example.com]", "web-01");
+        outcome.url = "https://example.com".to_string();
+        outcome.status = ItemStatus::Failed("unable to create web scenario".to_string());
+
+        let row = render_outcome_row(&outcome, OutputStyle::Colored);
+
+        assert!(row.starts_with("\x1b[31m✘ failed"));
+        assert!(row.contains("https://example.com"));
+
+        assert!(render_outcome_row(&outcome, OutputStyle::Plain).starts_with("failed   web-01"));
+    }
+
+    #[test]
+    fn row_should_show_proxy_of_host() {
+        let mut outcome = ItemOutcome::new("vhost.item[https://example.com]", "web-01");
+        outcome.url = "https://example.com".to_string();
+        outcome.proxy = Some("proxy-berlin".to_string());
+
+        assert!(render_outcome_row(&outcome, OutputStyle::Plain)
+                    .ends_with("scenario and trigger created, via proxy 'proxy-berlin'"));
+
+        outcome.status = ItemStatus::SkippedExisting;
+
+        assert!(render_outcome_row(&outcome, OutputStyle::Plain).ends_with(" via proxy 'proxy-berlin'"));
+        assert!(!render_outcome_row(&outcome, OutputStyle::Plain).contains(", via"));
+    }
+
+    #[test]
+    fn compact_summary_should_have_non_zero_counts_only() {
+        let mut report = RunReport::new();
+
+        let mut created = ItemOutcome::new("vhost.item[https://a.example.com]", "web-01");
+        created.scenario_created = true;
+        report.outcomes.push(created);
+
+        let mut skipped = ItemOutcome::new("vhost.item[https://b.example.com]", "web-01");
+        skipped.status = ItemStatus::SkippedExisting;
+        report.outcomes.push(skipped);
+
+        assert_eq!(render_compact_summary(&report.get_counts(), OutputStyle::Plain),
+                   "2 items: ✔ 1 created  · 1 skipped");
+
+        assert_eq!(render_compact_summary(&RunReport::new().get_counts(), OutputStyle::Plain),
+                   "0 items, nothing to do");
+    }
+}
@@ -0,0 +1,94 @@
+pub mod patterns {
+    use std::collections::HashMap;
+
+    use regex::Regex;
+
+    use crate::config::config::ItemKeyPatternConfig;
+    use crate::errors::errors::OperationError;
+    use crate::types::types::OperationResult;
+
+    const MASK_WILDCARD: &str = "*";
+
+    /// Named group with url, the first group is url otherwise
+    const URL_GROUP: &str = "url";
+
+    /// Url and other named groups captured from item key (i.e. `label`)
+    pub struct CapturedUrl {
+        pub url: String,
+        pub groups: HashMap<String, String>
+    }
+
+    /// Item key pattern: `search` is passed to item.get, `regex` captures url from item key.
+    pub struct ItemKeyPattern {
+        pub search: String,
+        regex: Regex
+    }
+
+    impl ItemKeyPattern {
+        /// Mask like `vhost.item[*]`, where `*` captures url.
+        /// Mask without wildcard is treated as key prefix (`vhost.item` -> `vhost.item[*]`).
+        pub fn from_mask(mask: &str) -> OperationResult<ItemKeyPattern> {
+            let mask = if mask.contains(MASK_WILDCARD) {
+                mask.to_string()
+
+            } else {
+                format!("{}[{}]", mask, MASK_WILDCARD)
+            };
+
+            let escaped_parts: Vec<String> = mask.split(MASK_WILDCARD)
+                                                 .map(regex::escape).collect();
+
+            let pattern = format!("^{}$", escaped_parts.join("(.*)"));
+
+            ItemKeyPattern::from_regex(&pattern, &mask)
+        }
+
+        /// Regular expression, group named `url` or the first capture group is url.
+        /// Other named groups are available in name templates as `groups`.
+        pub fn from_regex(pattern: &str, search: &str) -> OperationResult<ItemKeyPattern> {
+            match Regex::new(pattern) {
+                Ok(regex) => {
+                    if regex.captures_len() < 2 {
+                        error!("item key pattern '{}' has no capture group for url", pattern);
+                        return Err(OperationError::Error)
+                    }
+
+                    Ok(ItemKeyPattern { search: search.to_string(), regex })
+                }
+                Err(e) => {
+                    error!("invalid item key pattern '{}': {}", pattern, e);
+                    Err(OperationError::Error)
+                }
+            }
+        }
+
+        pub fn capture(&self, item_key: &str) -> Option<CapturedUrl> {
+            let captures = self.regex.captures(item_key)?;
+
+            let url = captures.name(URL_GROUP).or_else(|| captures.get(1))?;
+
+            let groups = self.regex.capture_names().flatten()
+                .filter(|name| *name != URL_GROUP)
+                .filter_map(|name| captures.name(name).map(|value| (name.to_string(), value.as_str().to_string())))
+                .collect();
+
+            Some(CapturedUrl { url: url.as_str().to_string(), groups })
+        }
+    }
+
+    pub fn get_item_key_patterns(pattern_configs: &[ItemKeyPatternConfig]) ->
+                                                        OperationResult<Vec<ItemKeyPattern>> {
+        pattern_configs.iter().map(|pattern_config| {
+            match pattern_config {
+                ItemKeyPatternConfig::Mask(mask) => ItemKeyPattern::from_mask(mask),
+                ItemKeyPatternConfig::Regex { regex, search } =>
+                                                    ItemKeyPattern::from_regex(regex, search)
+            }
+        }).collect()
+    }
+
+    /// Tries patterns in order, returns url captured by the first matched one.
+    pub fn capture_url(patterns: &[ItemKeyPattern], item_key: &str) -> Option<CapturedUrl> {
+        patterns.iter().find_map(|pattern| pattern.capture(item_key))
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod patterns_tests {
+    use crate::patterns::patterns::{capture_url, ItemKeyPattern};
+
+    #[test]
+    fn mask_wildcard_should_capture_url() {
+        let pattern = ItemKeyPattern::from_mask("vhost.item[*]").unwrap();
+
+        assert_eq!(pattern.search, "vhost.item[*]");
+        assert_eq!(pattern.capture("vhost.item[https://example.com]").unwrap().url, "https://example.com");
+        assert!(pattern.capture("vhostxitem[https://example.com]").is_none());
+    }
+
+    #[test]
+    fn mask_without_wildcard_should_be_treated_as_key_prefix() {
+        let pattern = ItemKeyPattern::from_mask("vhost.item").unwrap();
+
+        assert_eq!(pattern.search, "vhost.item[*]");
+        assert_eq!(pattern.capture("vhost.item[example.com]").unwrap().url, "example.com");
+    }
+
+    #[test]
+    fn regex_without_capture_group_should_be_rejected() {
+        assert!(ItemKeyPattern::from_regex("^vhost\\.item\\[.*\\]$", "vhost.item[").is_err());
+    }
+
+    #[test]
+    fn patterns_should_be_tried_in_order() {
+        let patterns = vec![
+            ItemKeyPattern::from_mask("vhost.item[*]").unwrap(),
+            ItemKeyPattern::from_regex("^nginx\\.vhost\\[([^,]+),\\d+\\]$", "nginx.vhost[").unwrap()
+        ];
+
+        assert_eq!(capture_url(&patterns, "nginx.vhost[example.com,443]").unwrap().url, "example.com");
+        assert!(capture_url(&patterns, "web.url[example.com]").is_none());
+    }
+
+    #[test]
+    fn named_groups_should_be_captured_with_url() {
+        let pattern = ItemKeyPattern::from_regex("^vhost\\.item\\[(?P<label>[^,]+),(?P<url>.+)\\]$", "vhost.item[")
+                                     .unwrap();
+
+        let captured_url = pattern.capture("vhost.item[Online shop,https://shop.example.com]").unwrap();
+
+        assert_eq!(captured_url.url, "https://shop.example.com");
+        assert_eq!(captured_url.groups.len(), 1);
+        assert_eq!(captured_url.groups["label"], "Online shop");
+    }
+}
@@ -0,0 +1,107 @@
+pub mod plan {
+    use crate::report::report::{ItemStatus, RunReport};
+    use crate::triggers::triggers::TriggerParams;
+    use crate::webscenarios::webscenarios::{HttpField, WebScenarioParams};
+
+    /// Objects which would be created for url, collected in dry-run mode
+    pub struct PlannedChange {
+        pub host: String,
+        pub scenario: WebScenarioParams,
+        pub trigger: TriggerParams,
+        pub latency_trigger: Option<TriggerParams>,
+        /// Web scenario and trigger of this url would be renamed instead of created
+        pub renamed_from: Option<String>,
+        /// Hand-made web scenario (its name) would be taken over, triggers would be created
+        pub adopted_from: Option<String>
+    }
+
+    /// Renders planned changes like `terraform plan`: every object with its properties,
+    /// `+` marks objects to create, `~` objects to rename or adopt.
+    pub fn render_diff(report: &RunReport) -> String {
+        let mut diff = String::new();
+        let mut scenario_additions = 0;
+        let mut trigger_additions = 0;
+        let mut changes = 0;
+
+        for outcome in &report.outcomes {
+            if let ItemStatus::Planned(change) = &outcome.status {
+                let triggers = if change.latency_trigger.is_some() { 2 } else { 1 };
+
+                let (marker, trigger_marker) = match (&change.renamed_from, &change.adopted_from) {
+                    (Some(_), _) => { changes += 1 + triggers; ("~", "~") }
+                    (None, Some(_)) => { changes += 1; trigger_additions += triggers; ("~", "+") }
+                    (None, None) => { scenario_additions += 1; trigger_additions += triggers; ("+", "+") }
+                };
+
+                let scenario = &change.scenario;
+
+                diff.push_str(&format!("{} web scenario \"{}\" on host {}", marker, scenario.name, change.host));
+
+                match (&change.renamed_from, &change.adopted_from) {
+                    (Some(old_url), _) => diff.push_str(&format!(" (renamed from {})\n", old_url)),
+                    (None, Some(old_name)) => diff.push_str(&format!(" (adopted from \"{}\")\n", old_name)),
+                    (None, None) => diff.push('\n')
+                }
+
+                diff.push_str(&format!("{}     update interval: {}\n", marker, scenario.delay));
+                diff.push_str(&format!("{}     attempts: {}\n", marker, scenario.retries));
+
+                if !scenario.http_proxy.is_empty() {
+                    diff.push_str(&format!("{}     http proxy: {}\n", marker, scenario.http_proxy));
+                }
+
+                if !scenario.variables.is_empty() {
+                    diff.push_str(&format!("{}     variables: {}\n", marker, get_field_names(&scenario.variables)));
+                }
+
+                for step in &scenario.steps {
+                    let method = if step.posts.is_empty() { "" } else { "POST " };
+
+                    diff.push_str(&format!("{}     step {} \"{}\": {}{}, status codes {}, timeout {}\n",
+                                           marker, step.no, step.name, method, step.url, step.status_codes,
+                                           step.timeout));
+
+                    if !step.headers.is_empty() {
+                        diff.push_str(&format!("{}         headers: {}\n", marker, get_field_names(&step.headers)));
+                    }
+
+                    if !step.variables.is_empty() {
+                        diff.push_str(&format!("{}         variables: {}\n", marker, get_field_names(&step.variables)));
+                    }
+
+                    if !step.required.is_empty() {
+                        diff.push_str(&format!("{}         required: {}\n", marker, step.required));
+                    }
+
+                    match step.retrieve_mode {
+                        1 => diff.push_str(&format!("{}         retrieve: headers\n", marker)),
+                        2 => diff.push_str(&format!("{}         retrieve: both\n", marker)),
+                        _ => {}
+                    }
+                }
+
+                for trigger in std::iter::once(&change.trigger).chain(&change.latency_trigger) {
+                    diff.push_str(&format!("{} trigger \"{}\" on host {}\n", trigger_marker, trigger.description,
+                                           change.host));
+                    diff.push_str(&format!("{}     expression: {}\n", trigger_marker, trigger.expression));
+                    diff.push_str(&format!("{}     severity: {}\n", trigger_marker, trigger.priority));
+
+                    if !trigger.correlation_tag.is_empty() {
+                        diff.push_str(&format!("{}     correlation tag: {}\n", trigger_marker, trigger.correlation_tag));
+                    }
+                }
+                diff.push('\n');
+            }
+        }
+
+        diff.push_str(&format!("Plan: {} web scenario(s) and {} trigger(s) to add, {} to change, 0 to destroy.\n",
+                               scenario_additions, trigger_additions, changes));
+
+        diff
+    }
+
+    /// Values are left out, they may contain credentials
+    fn get_field_names(fields: &[HttpField]) -> String {
+        fields.iter().map(|field| field.name.as_str()).collect::<Vec<&str>>().join(", ")
+    }
+}
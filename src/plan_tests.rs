@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod plan_tests {
+    use std::collections::HashMap;
+
+    use crate::config::config::{RetrieveMode, TemplatesConfig, WebScenarioConfig};
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::plan::plan::{PlannedChange, render_diff};
+    use crate::report::report::{ItemOutcome, ItemStatus, RunReport};
+    use crate::templates::templates::NameTemplates;
+    use crate::triggers::triggers::get_trigger_params;
+    use crate::webscenarios::webscenarios::get_web_scenario_params;
+
+    #[test]
+    fn diff_should_contain_planned_scenarios_and_triggers() {
+        let scenario_config = WebScenarioConfig {
+            response_timeout: "15s".to_string(),
+            expected_status_code: "200".to_string(),
+            attempts: 3,
+            update_interval: "5m".to_string(),
+            retrieve_mode: RetrieveMode::Body,
+            variables: vec![]
+        };
+
+        let url = "https://example.com";
+
+        let host = ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(),
+                                maintenance_status: "0".to_string(), status: "0".to_string(),
+                                proxy_hostid: "0".to_string() };
+
+        let names = NameTemplates::new(&TemplatesConfig::default()).unwrap()
+                        .get_object_names(&host, url, &HashMap::new(), None).unwrap();
+
+        let mut report = RunReport::new();
+
+        let mut planned = ItemOutcome::new("vhost item", "web-01");
+        planned.url = url.to_string();
+        planned.status = ItemStatus::Planned(Box::new(PlannedChange {
+            host: "web-01".to_string(),
+            scenario: get_web_scenario_params(&scenario_config, &names, url, "10001"),
+            trigger: get_trigger_params(&names, url),
+            latency_trigger: None,
+            renamed_from: None,
+            adopted_from: None
+        }));
+        report.outcomes.push(planned);
+
+        let mut skipped = ItemOutcome::new("vhost item", "web-01");
+        skipped.status = ItemStatus::SkippedExisting;
+        report.outcomes.push(skipped);
+
+        assert_eq!(render_diff(&report),
+            "+ web scenario \"Check index page 'https://example.com'\" on host web-01\n\
+             +     update interval: 5m\n\
+             +     attempts: 3\n\
+             +     step 1 \"Get page\": https://example.com, status codes 200, timeout 15s\n\
+             + trigger \"Site 'https://example.com' is unavailable\" on host web-01\n\
+             +     expression: last(/web-01/web.test.fail[Check index page 'https://example.com'])<>0\n\
+             +     severity: 4\n\
+             \n\
+             Plan: 1 web scenario(s) and 1 trigger(s) to add, 0 to change, 0 to destroy.\n");
+    }
+
+    #[test]
+    fn diff_should_contain_retrieve_mode_of_headers_only_steps() {
+        let scenario_config = WebScenarioConfig {
+            response_timeout: "15s".to_string(),
+            expected_status_code: "200".to_string(),
+            attempts: 3,
+            update_interval: "5m".to_string(),
+            retrieve_mode: RetrieveMode::Headers,
+            variables: vec![]
+        };
+
+        let url = "https://example.com";
+
+        let host = ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(),
+                                maintenance_status: "0".to_string(), status: "0".to_string(),
+                                proxy_hostid: "0".to_string() };
+
+        let names = NameTemplates::new(&TemplatesConfig::default()).unwrap()
+                        .get_object_names(&host, url, &HashMap::new(), None).unwrap();
+
+        let mut report = RunReport::new();
+
+        let mut planned = ItemOutcome::new("vhost item", "web-01");
+        planned.url = url.to_string();
+        planned.status = ItemStatus::Planned(Box::new(PlannedChange {
+            host: "web-01".to_string(),
+            scenario: get_web_scenario_params(&scenario_config, &names, url, "10001"),
+            trigger: get_trigger_params(&names, url),
+            latency_trigger: None,
+            renamed_from: None,
+            adopted_from: None
+        }));
+        report.outcomes.push(planned);
+
+        assert!(render_diff(&report).contains(
+            "+     step 1 \"Get page\": https://example.com, status codes 200, timeout 15s\n\
+             +         retrieve: headers\n"));
+    }
+
+    #[test]
+    fn adopted_scenario_should_be_changed_and_its_triggers_added() {
+        let scenario_config = WebScenarioConfig {
+            response_timeout: "15s".to_string(),
+            expected_status_code: "200".to_string(),
+            attempts: 3,
+            update_interval: "5m".to_string(),
+            retrieve_mode: RetrieveMode::Body,
+            variables: vec![]
+        };
+
+        let url = "https://example.com";
+
+        let host = ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(),
+                                maintenance_status: "0".to_string(), status: "0".to_string(),
+                                proxy_hostid: "0".to_string() };
+
+        let names = NameTemplates::new(&TemplatesConfig::default()).unwrap()
+                        .get_object_names(&host, url, &HashMap::new(), None).unwrap();
+
+        let mut report = RunReport::new();
+
+        let mut planned = ItemOutcome::new("vhost item", "web-01");
+        planned.url = url.to_string();
+        planned.status = ItemStatus::Planned(Box::new(PlannedChange {
+            host: "web-01".to_string(),
+            scenario: get_web_scenario_params(&scenario_config, &names, url, "10001"),
+            trigger: get_trigger_params(&names, url),
+            latency_trigger: None,
+            renamed_from: None,
+            adopted_from: Some("Homepage".to_string())
+        }));
+        report.outcomes.push(planned);
+
+        let diff = render_diff(&report);
+
+        assert!(diff.starts_with("~ web scenario \"Check index page 'https://example.com'\" on host web-01 \
+                                  (adopted from \"Homepage\")\n"));
+        assert!(diff.contains("+ trigger \"Site 'https://example.com' is unavailable\" on host web-01\n"));
+        assert!(diff.ends_with("Plan: 0 web scenario(s) and 1 trigger(s) to add, 1 to change, 0 to destroy.\n"));
+    }
+}
@@ -0,0 +1,76 @@
+pub mod probe {
+    use std::error::Error;
+    use std::time::Duration;
+
+    use reqwest::blocking::Client;
+
+    use crate::config::config::ProbeConfig;
+    use crate::errors::errors::OperationError;
+    use crate::types::types::OperationResult;
+
+    const DEFAULT_SCHEME: &str = "http://";
+
+    /// Checks that url responds before web scenario is created for it.
+    /// Any HTTP response means the url is reachable, only transport errors (DNS, connection, timeout) count.
+    pub struct UrlProber {
+        client: Client,
+        pub skip_unreachable: bool
+    }
+
+    impl UrlProber {
+        pub fn new(probe_config: &ProbeConfig) -> OperationResult<UrlProber> {
+            let client = Client::builder()
+                .timeout(Duration::from_secs(probe_config.timeout_secs))
+                .build();
+
+            match client {
+                Ok(client) => Ok(UrlProber { client, skip_unreachable: probe_config.skip_unreachable }),
+                Err(e) => {
+                    error!("unable to create http client for url probes: {}", e);
+                    Err(OperationError::Error)
+                }
+            }
+        }
+
+        pub fn probe(&self, url: &str) -> Result<(), String> {
+            let probe_url = get_probe_url(url);
+            debug!("probe url '{}'", probe_url);
+
+            match self.client.get(&probe_url).send() {
+                Ok(response) => {
+                    debug!("url '{}' responded with {}", probe_url, response.status());
+                    Ok(())
+                }
+                Err(e) => {
+                    let reason = if e.is_timeout() {
+                        "timeout".to_string()
+                    } else {
+                        get_root_cause(&e)
+                    };
+
+                    Err(reason)
+                }
+            }
+        }
+    }
+
+    /// Zabbix treats urls without scheme as http
+    pub fn get_probe_url(url: &str) -> String {
+        if url.contains("://") {
+            url.to_string()
+        } else {
+            format!("{}{}", DEFAULT_SCHEME, url)
+        }
+    }
+
+    /// The innermost error, i.e. "failed to lookup address information" instead of request description
+    fn get_root_cause(error: &dyn Error) -> String {
+        let mut cause = error;
+
+        while let Some(source) = cause.source() {
+            cause = source;
+        }
+
+        cause.to_string()
+    }
+}
@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod probe_tests {
+    use std::net::TcpListener;
+
+    use crate::config::config::ProbeConfig;
+    use crate::probe::probe::{get_probe_url, UrlProber};
+
+    #[test]
+    fn url_without_scheme_should_be_probed_over_http() {
+        assert_eq!(get_probe_url("example.com/health"), "http://example.com/health");
+        assert_eq!(get_probe_url("https://example.com"), "https://example.com");
+    }
+
+    #[test]
+    fn refused_connection_should_be_reported() {
+        // Port of a closed listener, nothing accepts connections there
+        let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+
+        let url_prober = UrlProber::new(&ProbeConfig { skip_unreachable: true, timeout_secs: 2 }).unwrap();
+
+        assert!(url_prober.probe(&format!("127.0.0.1:{}", port)).is_err());
+    }
+}
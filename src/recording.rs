@@ -0,0 +1,167 @@
+pub mod recording {
+    use std::fs;
+    use std::fs::OpenOptions;
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+
+    use serde::Deserialize;
+    use serde::Serialize;
+    use serde_json::Value;
+
+    use crate::errors::errors::OperationError;
+    use crate::types::types::{EmptyResult, StringResult};
+
+    pub const RECORDING_FILE_NAME: &str = "api-responses.jsonl";
+
+    /// Params of these methods hold credentials, they are neither recorded nor matched.
+    /// Their responses hold session token, it's replaced with `REPLAYED_AUTH_TOKEN`.
+    const SECRET_PARAMS_METHODS: [&str; 2] = ["user.login", "user.checkAuthentication"];
+
+    /// Session token of replayed login, requests aren't sent anywhere
+    pub const REPLAYED_AUTH_TOKEN: &str = "00000000000000000000000000000000";
+
+    static RECORDING_FILE_PATH: Mutex<Option<PathBuf>> = Mutex::new(None);
+    static REPLAYED_RESPONSES: Mutex<Option<Vec<RecordedResponse>>> = Mutex::new(None);
+
+    /// One line of the recording file
+    #[derive(Serialize, Deserialize)]
+    pub struct RecordedResponse {
+        pub method: String,
+        pub params: Value,
+        pub response: String,
+        /// Response has been replayed already
+        #[serde(skip)]
+        pub replayed: bool
+    }
+
+    /// Every API response is appended to the recording file in `directory` from now on
+    pub fn start_recording(directory: &Path) -> EmptyResult {
+        let file_path = directory.join(RECORDING_FILE_NAME);
+
+        match fs::create_dir_all(directory).and_then(|_| fs::write(&file_path, "")) {
+            Ok(_) => {
+                info!("api responses will be recorded to '{}'", file_path.display());
+                *RECORDING_FILE_PATH.lock().unwrap() = Some(file_path);
+                Ok(())
+            }
+            Err(e) => {
+                error!("unable to create recording file '{}': {}", file_path.display(), e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// API requests are answered from the recording in `directory` from now on, network isn't used
+    pub fn start_replay(directory: &Path) -> EmptyResult {
+        let file_path = directory.join(RECORDING_FILE_NAME);
+
+        match fs::read_to_string(&file_path) {
+            Ok(content) => match parse_recording(&content) {
+                Ok(responses) => {
+                    info!("{} api responses will be replayed from '{}'", responses.len(), file_path.display());
+                    *REPLAYED_RESPONSES.lock().unwrap() = Some(responses);
+                    Ok(())
+                }
+                Err(e) => {
+                    error!("invalid recording file '{}': {}", file_path.display(), e);
+                    Err(OperationError::Error)
+                }
+            },
+            Err(e) => {
+                error!("unable to read recording file '{}': {}", file_path.display(), e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    pub fn is_replaying() -> bool {
+        REPLAYED_RESPONSES.lock().unwrap().is_some()
+    }
+
+    pub fn parse_recording(content: &str) -> Result<Vec<RecordedResponse>, serde_json::Error> {
+        content.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect()
+    }
+
+    fn get_recorded_params(method: &str, params: &Value) -> Value {
+        if SECRET_PARAMS_METHODS.contains(&method) { Value::Null } else { params.clone() }
+    }
+
+    /// Session token of login (`result`) or session check (`result.sessionid`) is replaced
+    pub fn get_recorded_response(method: &str, response: &str) -> String {
+        if !SECRET_PARAMS_METHODS.contains(&method) {
+            return response.to_string()
+        }
+
+        match serde_json::from_str::<Value>(response) {
+            Ok(mut response) => {
+                match response.get_mut("result") {
+                    Some(Value::String(token)) => *token = REPLAYED_AUTH_TOKEN.to_string(),
+                    Some(Value::Object(session)) if session.contains_key("sessionid") => {
+                        session.insert("sessionid".to_string(), Value::String(REPLAYED_AUTH_TOKEN.to_string()));
+                    }
+                    _ => {}
+                }
+
+                response.to_string()
+            }
+            // Anything but JSON-RPC response can't be replayed anyway
+            Err(_) => String::new()
+        }
+    }
+
+    pub fn record_response(method: &str, params: &Value, response: &str) {
+        let recording_file_path = RECORDING_FILE_PATH.lock().unwrap();
+
+        if let Some(recording_file_path) = recording_file_path.as_ref() {
+            let record = RecordedResponse {
+                method: method.to_string(),
+                params: get_recorded_params(method, params),
+                response: get_recorded_response(method, response),
+                replayed: false
+            };
+
+            let result = OpenOptions::new().append(true).open(recording_file_path)
+                .and_then(|mut file| writeln!(file, "{}", serde_json::to_string(&record).unwrap()));
+
+            if let Err(e) = result {
+                error!("unable to record api response to '{}': {}", recording_file_path.display(), e);
+            }
+        }
+    }
+
+    pub fn replay_response(method: &str, params: &Value) -> StringResult {
+        let mut replayed_responses = REPLAYED_RESPONSES.lock().unwrap();
+
+        match replayed_responses.as_mut().and_then(|responses| find_recorded_response(responses, method, params)) {
+            // Recordings of older versions hold real session tokens
+            Some(response) => Ok(get_recorded_response(method, &response)),
+            None => {
+                error!("response of '{}' with these params hasn't been recorded", method);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Identical requests get recorded responses in the original order, the last one is repeated
+    pub fn find_recorded_response(responses: &mut [RecordedResponse], method: &str,
+                                  params: &Value) -> Option<String> {
+        let params = get_recorded_params(method, params);
+
+        let mut matching = responses.iter_mut()
+            .filter(|recorded| recorded.method == method && recorded.params == params)
+            .peekable();
+
+        while let Some(recorded) = matching.next() {
+            if !recorded.replayed || matching.peek().is_none() {
+                recorded.replayed = true;
+                return Some(recorded.response.to_string())
+            }
+        }
+
+        None
+    }
+}
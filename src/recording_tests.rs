@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod recording_tests {
+    use serde_json::{json, Value};
+
+    use crate::recording::recording::{find_recorded_response, get_recorded_response, parse_recording,
+                                      REPLAYED_AUTH_TOKEN};
+
+    const RECORDING: &str = r#"{"method":"user.login","params":null,"response":"{\"result\":\"token\"}"}
+{"method":"item.get","params":{"hostids":["10001"]},"response":"{\"result\":[1]}"}
+{"method":"item.get","params":{"hostids":["10002"]},"response":"{\"result\":[2]}"}
+{"method":"item.get","params":{"hostids":["10001"]},"response":"{\"result\":[3]}"}
+"#;
+
+    #[test]
+    fn responses_should_be_matched_by_method_and_params() {
+        let mut responses = parse_recording(RECORDING).unwrap();
+
+        assert_eq!(find_recorded_response(&mut responses, "item.get", &json!({"hostids": ["10002"]})),
+                   Some(r#"{"result":[2]}"#.to_string()));
+        assert_eq!(find_recorded_response(&mut responses, "item.get", &json!({"hostids": ["10003"]})), None);
+        assert_eq!(find_recorded_response(&mut responses, "host.get", &json!({"hostids": ["10002"]})), None);
+    }
+
+    #[test]
+    fn identical_requests_should_get_responses_in_recorded_order() {
+        let mut responses = parse_recording(RECORDING).unwrap();
+        let params = json!({"hostids": ["10001"]});
+
+        assert_eq!(find_recorded_response(&mut responses, "item.get", &params), Some(r#"{"result":[1]}"#.to_string()));
+        assert_eq!(find_recorded_response(&mut responses, "item.get", &params), Some(r#"{"result":[3]}"#.to_string()));
+        assert_eq!(find_recorded_response(&mut responses, "item.get", &params), Some(r#"{"result":[3]}"#.to_string()));
+    }
+
+    #[test]
+    fn login_should_be_matched_without_credentials() {
+        let mut responses = parse_recording(RECORDING).unwrap();
+
+        assert_eq!(find_recorded_response(&mut responses, "user.login",
+                                          &json!({"user": "Admin", "password": "secret"})),
+                   Some(r#"{"result":"token"}"#.to_string()));
+    }
+
+    #[test]
+    fn session_tokens_should_be_replaced() {
+        let token = "0424bd59b807674191e7d77572075f33";
+
+        let response = get_recorded_response("user.login", &format!(r#"{{"jsonrpc":"2.0","result":"{}"}}"#, token));
+        assert_eq!(response, format!(r#"{{"jsonrpc":"2.0","result":"{}"}}"#, REPLAYED_AUTH_TOKEN));
+
+        let response = get_recorded_response("user.checkAuthentication",
+                                             &format!(r#"{{"result":{{"userid":"1","sessionid":"{}"}}}}"#, token));
+        assert!(!response.contains(token));
+        assert!(response.contains(REPLAYED_AUTH_TOKEN));
+
+        let error = json!({"error": {"code": -32602, "message": "Invalid params.",
+                                     "data": "Login name or password is incorrect."}});
+        assert_eq!(serde_json::from_str::<Value>(&get_recorded_response("user.login", &error.to_string())).unwrap(),
+                   error);
+
+        assert_eq!(get_recorded_response("item.get", r#"{"result":"token"}"#), r#"{"result":"token"}"#);
+    }
+}
@@ -0,0 +1,76 @@
+pub mod renames {
+    use std::collections::HashSet;
+
+    use crate::discovery::discovery::DiscoveredUrl;
+    use crate::report::report::ItemOutcome;
+    use crate::webscenarios::webscenarios::ZabbixWebScenario;
+
+    /// Managed web scenario of url which has disappeared and the url which has replaced it
+    pub struct UrlRename<'a> {
+        pub web_scenario: &'a ZabbixWebScenario,
+        pub old_url: String,
+        pub new_url: String
+    }
+
+    /// Web scenario is renamed when its host has exactly one scenario which url isn't discovered anymore
+    /// and exactly one discovered url without scenario. Anything else is ambiguous and left alone.
+    /// Hosts of `partial_host_ids` are left alone as well, see `get_partial_host_ids`.
+    pub fn find_url_renames<'a>(web_scenarios: &'a [ZabbixWebScenario], urls: &[DiscoveredUrl],
+                                partial_host_ids: &HashSet<String>) -> Vec<UrlRename<'a>> {
+        let mut renames = vec![];
+
+        let host_ids: HashSet<&str> = urls.iter()
+            .map(|url| url.hostid.as_str())
+            .filter(|hostid| !partial_host_ids.contains(*hostid))
+            .collect();
+
+        for hostid in host_ids {
+            let host_urls: HashSet<&str> = urls.iter()
+                .filter(|url| url.hostid == hostid)
+                .map(|url| url.url.as_str()).collect();
+
+            let host_scenarios: Vec<(&ZabbixWebScenario, &str)> = web_scenarios.iter()
+                .filter(|web_scenario| web_scenario.hostid == hostid)
+                .filter_map(|web_scenario| get_scenario_url(web_scenario).map(|url| (web_scenario, url)))
+                .collect();
+
+            let removed: Vec<&(&ZabbixWebScenario, &str)> = host_scenarios.iter()
+                .filter(|(_, url)| !host_urls.contains(url)).collect();
+
+            let added: Vec<&str> = host_urls.iter()
+                .filter(|url| !host_scenarios.iter().any(|(_, scenario_url)| scenario_url == *url))
+                .cloned().collect();
+
+            if let ([(web_scenario, old_url)], [new_url]) = (removed.as_slice(), added.as_slice()) {
+                debug!("url '{}' of host {} looks renamed to '{}'", old_url, hostid, new_url);
+
+                renames.push(UrlRename {
+                    web_scenario,
+                    old_url: old_url.to_string(),
+                    new_url: new_url.to_string()
+                });
+            }
+        }
+
+        renames
+    }
+
+    /// Hosts which only some urls have been discovered of, their other scenarios would look like their urls
+    /// have disappeared. All hosts are partial when only some items are processed (new items only or retry
+    /// of failed items), otherwise hosts of items which urls couldn't be taken from (`failures`, stale included).
+    pub fn get_partial_host_ids(urls: &[DiscoveredUrl], failures: &[ItemOutcome],
+                                all_items: bool) -> HashSet<String> {
+        if all_items {
+            failures.iter().map(|failure| failure.host.to_string()).collect()
+        } else {
+            urls.iter().map(|url| url.hostid.to_string())
+                .chain(failures.iter().map(|failure| failure.host.to_string()))
+                .collect()
+        }
+    }
+
+    /// Url of the first step
+    fn get_scenario_url(web_scenario: &ZabbixWebScenario) -> Option<&str> {
+        web_scenario.steps.iter().find(|step| step.no == "1").map(|step| step.url.as_str())
+    }
+}
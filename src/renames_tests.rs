@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod renames_tests {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::discovery::discovery::DiscoveredUrl;
+    use crate::renames::renames::{find_url_renames, get_partial_host_ids};
+    use crate::report::report::{ItemOutcome, ItemStatus};
+    use crate::scripting::scripting::UrlOverrides;
+    use crate::webscenarios::webscenarios::{ZabbixHttpStep, ZabbixWebScenario};
+
+    fn get_web_scenario(httptestid: &str, hostid: &str, url: &str) -> ZabbixWebScenario {
+        ZabbixWebScenario {
+            httptestid: httptestid.to_string(),
+            hostid: hostid.to_string(),
+            name: format!("Check index page '{}'", url),
+            steps: vec![ZabbixHttpStep { httpstepid: format!("{}1", httptestid), no: "1".to_string(),
+                                         url: url.to_string(), name: "Index page".to_string() }],
+            tags: vec![]
+        }
+    }
+
+    fn get_url(hostid: &str, url: &str) -> DiscoveredUrl {
+        DiscoveredUrl { source: "vhost item".to_string(), key: String::new(), itemid: String::new(),
+                        hostid: hostid.to_string(), url: url.to_string(), overrides: UrlOverrides::default(),
+                        groups: HashMap::new() }
+    }
+
+    #[test]
+    fn one_removed_and_one_added_url_on_host_should_be_renamed() {
+        let web_scenarios = vec![
+            get_web_scenario("301", "10001", "https://old.example.com"),
+            get_web_scenario("302", "10001", "https://shop.example.com")
+        ];
+
+        let urls = vec![get_url("10001", "https://new.example.com"), get_url("10001", "https://shop.example.com")];
+
+        let renames = find_url_renames(&web_scenarios, &urls, &HashSet::new());
+
+        assert_eq!(renames.len(), 1);
+        assert_eq!(renames[0].web_scenario.httptestid, "301");
+        assert_eq!(renames[0].old_url, "https://old.example.com");
+        assert_eq!(renames[0].new_url, "https://new.example.com");
+    }
+
+    #[test]
+    fn ambiguous_changes_should_not_be_renamed() {
+        let web_scenarios = vec![
+            get_web_scenario("301", "10001", "https://old.example.com"),
+            get_web_scenario("302", "10002", "https://a.example.com"),
+            get_web_scenario("303", "10002", "https://b.example.com")
+        ];
+
+        let urls = vec![
+            // Two new urls for one removed
+            get_url("10001", "https://new1.example.com"), get_url("10001", "https://new2.example.com"),
+            // Two removed urls for one new
+            get_url("10002", "https://c.example.com")
+        ];
+
+        assert!(find_url_renames(&web_scenarios, &urls, &HashSet::new()).is_empty());
+    }
+
+    #[test]
+    fn new_item_of_new_items_only_run_should_not_be_renamed() {
+        let web_scenarios = vec![get_web_scenario("301", "10001", "https://shop.example.com")];
+
+        // Existing item isn't processed again, only the new one
+        let urls = vec![get_url("10001", "https://new.example.com")];
+
+        let partial_host_ids = get_partial_host_ids(&urls, &[], false);
+
+        assert!(find_url_renames(&web_scenarios, &urls, &partial_host_ids).is_empty());
+    }
+
+    #[test]
+    fn hosts_with_stale_items_should_not_be_renamed() {
+        let web_scenarios = vec![
+            get_web_scenario("301", "10001", "https://shop.example.com"),
+            get_web_scenario("302", "10002", "https://old.example.com")
+        ];
+
+        let urls = vec![get_url("10001", "https://new.example.com"), get_url("10002", "https://new.example.org")];
+
+        let mut stale_item = ItemOutcome::new("Vhost shop", "10001");
+        stale_item.status = ItemStatus::Stale("item isn't supported".to_string());
+
+        let renames = find_url_renames(&web_scenarios, &urls, &get_partial_host_ids(&urls, &[stale_item], true));
+
+        assert_eq!(renames.len(), 1);
+        assert_eq!(renames[0].web_scenario.httptestid, "302");
+    }
+}
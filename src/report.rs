@@ -0,0 +1,384 @@
+pub mod report {
+    use serde::Serialize;
+
+    use crate::output::output::{Color, OutputStyle, paint};
+    use crate::plan::plan::PlannedChange;
+
+    /// Run has been aborted after more than `processing.max-errors` failed items
+    pub const ERROR_LIMIT_EXIT_CODE: i32 = 4;
+
+    /// Dry-run has planned changes, Zabbix isn't in sync with discovered urls
+    pub const CHANGES_PENDING_EXIT_CODE: i32 = 2;
+
+    pub enum ItemStatus {
+        Created,
+        SkippedExisting,
+        /// Host isn't monitored
+        SkippedDisabled,
+        /// Url script or item overrides asked to skip the url
+        SkippedByScript,
+        /// Url has already been taken from another item (its name)
+        Duplicate(String),
+        /// Objects would be created, dry-run mode
+        Planned(Box<PlannedChange>),
+        /// Url didn't respond to probe, see `probe_error`
+        Unreachable,
+        /// Host is in maintenance, objects will be created by one of the next runs
+        Deferred,
+        /// Item is unsupported or outdated (reason), its url isn't trusted
+        Stale(String),
+        /// Existing web scenario of previous url has been renamed to this url
+        Renamed(String),
+        /// Hand-made web scenario (its name) has been taken over by wszl
+        Adopted(String),
+        Failed(String)
+    }
+
+    pub struct ItemOutcome {
+        pub item_name: String,
+        /// Id of the item, empty for urls from outside of Zabbix
+        pub itemid: String,
+        pub host: String,
+        pub url: String,
+        pub scenario_created: bool,
+        pub trigger_created: bool,
+        pub status: ItemStatus,
+        /// Url probe failure, objects are still created when unreachable urls are only flagged
+        pub probe_error: Option<String>,
+        /// Proxy which monitors the host, web checks are polled from it
+        pub proxy: Option<String>,
+        /// Checks of url are created on this host instead of `host` (host of item)
+        pub central_host: Option<String>,
+        /// Definition of existing managed objects of url from config, collected only for export
+        pub existing: Option<Box<PlannedChange>>
+    }
+
+    impl ItemOutcome {
+        pub fn new(item_name: &str, host: &str) -> ItemOutcome {
+            ItemOutcome {
+                item_name: item_name.to_string(),
+                itemid: String::new(),
+                host: host.to_string(),
+                url: String::new(),
+                scenario_created: false,
+                trigger_created: false,
+                status: ItemStatus::Created,
+                probe_error: None,
+                proxy: None,
+                central_host: None,
+                existing: None
+            }
+        }
+
+        pub fn fail(&mut self, reason: &str) {
+            error!("{}", reason);
+            self.status = ItemStatus::Failed(reason.to_string());
+        }
+
+        pub fn is_failed(&self) -> bool {
+            matches!(self.status, ItemStatus::Failed(_))
+        }
+
+        /// Item isn't reconciled with Zabbix because of a transient state, it's left for one of the next runs.
+        /// Stale items and items of disabled hosts may stay so for good, they are left for a full run.
+        pub fn is_pending(&self) -> bool {
+            matches!(self.status, ItemStatus::Failed(_) | ItemStatus::Deferred | ItemStatus::Unreachable)
+        }
+
+        /// Host which checks of url are created on
+        pub fn get_check_host(&self) -> &str {
+            self.central_host.as_deref().unwrap_or(&self.host)
+        }
+    }
+
+    pub struct RunReport {
+        pub outcomes: Vec<ItemOutcome>,
+        /// Run was stopped before all items were processed
+        pub interrupted: bool,
+        /// Run was stopped by the circuit breaker after repeated API failures
+        pub aborted: bool,
+        /// Run was stopped after more than `processing.max-errors` failed items
+        pub error_limit_reached: bool,
+        /// Run was stopped after `--timeout`
+        pub timed_out: bool,
+        /// Highest id of items matching patterns, absent when only some hosts were processed (`--retry-failed`)
+        pub last_item_id: Option<u64>
+    }
+
+    impl RunReport {
+        pub fn new() -> RunReport {
+            RunReport { outcomes: vec![], interrupted: false, aborted: false, error_limit_reached: false,
+                        timed_out: false, last_item_id: None }
+        }
+
+        pub fn has_planned_changes(&self) -> bool {
+            self.outcomes.iter().any(|outcome| matches!(outcome.status, ItemStatus::Planned(_)))
+        }
+
+        pub fn has_failures(&self) -> bool {
+            self.outcomes.iter().any(|outcome| outcome.is_failed())
+        }
+
+        /// All items have been processed without failures
+        pub fn is_complete_success(&self) -> bool {
+            !self.has_failures() && !self.interrupted && !self.aborted && !self.error_limit_reached && !self.timed_out
+        }
+
+        /// Highest item id which all items up to have been reconciled, pending items (see `ItemOutcome::is_pending`)
+        /// stay above it, so new items only runs process them again
+        pub fn get_reconciled_item_id(&self) -> Option<u64> {
+            let first_pending_item_id = self.outcomes.iter()
+                .filter(|outcome| outcome.is_pending())
+                .filter_map(|outcome| outcome.itemid.parse::<u64>().ok())
+                .min();
+
+            match first_pending_item_id {
+                Some(item_id) => self.last_item_id.map(|last_item_id| last_item_id.min(item_id.saturating_sub(1))),
+                None => self.last_item_id
+            }
+        }
+
+        /// Adds outcomes of a parallel run, i.e. of another config
+        pub fn merge(&mut self, mut other: RunReport) {
+            self.outcomes.append(&mut other.outcomes);
+            self.interrupted |= other.interrupted;
+            self.aborted |= other.aborted;
+            self.error_limit_reached |= other.error_limit_reached;
+            self.timed_out |= other.timed_out;
+            self.last_item_id = self.last_item_id.max(other.last_item_id);
+        }
+
+        pub fn get_counts(&self) -> RunCounts {
+            let mut counts = RunCounts {
+                items: self.outcomes.len(),
+                scenarios_created: 0,
+                triggers_created: 0,
+                renamed: 0,
+                adopted: 0,
+                skipped: 0,
+                duplicates: 0,
+                planned: 0,
+                unreachable: 0,
+                deferred: 0,
+                stale: 0,
+                failed: 0
+            };
+
+            for outcome in &self.outcomes {
+                if outcome.scenario_created { counts.scenarios_created += 1 }
+                if outcome.trigger_created { counts.triggers_created += 1 }
+                if outcome.probe_error.is_some() { counts.unreachable += 1 }
+
+                match outcome.status {
+                    ItemStatus::SkippedExisting | ItemStatus::SkippedDisabled |
+                    ItemStatus::SkippedByScript => counts.skipped += 1,
+                    ItemStatus::Duplicate(_) => counts.duplicates += 1,
+                    ItemStatus::Planned(_) => counts.planned += 1,
+                    ItemStatus::Unreachable => counts.skipped += 1,
+                    ItemStatus::Deferred => counts.deferred += 1,
+                    ItemStatus::Stale(_) => counts.stale += 1,
+                    ItemStatus::Failed(_) => counts.failed += 1,
+                    ItemStatus::Renamed(_) => counts.renamed += 1,
+                    ItemStatus::Adopted(_) => counts.adopted += 1,
+                    ItemStatus::Created => {}
+                }
+            }
+
+            counts
+        }
+    }
+
+    #[derive(Serialize)]
+    pub struct RunCounts {
+        pub items: usize,
+        pub scenarios_created: usize,
+        pub triggers_created: usize,
+        pub renamed: usize,
+        pub adopted: usize,
+        pub skipped: usize,
+        pub duplicates: usize,
+        pub planned: usize,
+        /// Urls which didn't respond to probe (flagged or skipped)
+        pub unreachable: usize,
+        pub deferred: usize,
+        /// Unsupported or outdated items
+        pub stale: usize,
+        pub failed: usize
+    }
+
+    /// Prints created and failed items as a table, skipped items are only counted.
+    /// Colored style marks rows with symbols and prints compact summary.
+    pub fn print_summary(report: &RunReport, style: OutputStyle) {
+        let rows: Vec<&ItemOutcome> = report.outcomes.iter()
+            .filter(|outcome| !matches!(outcome.status, ItemStatus::SkippedExisting))
+            .collect();
+
+        if !rows.is_empty() {
+            match style {
+                OutputStyle::Plain => println!("{:<8} {:<30} {:<50} DETAILS", "STATUS", "HOST", "URL"),
+                OutputStyle::Colored => println!("  {:<8} {:<30} {:<50} DETAILS", "STATUS", "HOST", "URL")
+            }
+
+            for outcome in rows {
+                println!("{}", render_outcome_row(outcome, style));
+            }
+
+            println!();
+        }
+
+        let counts = report.get_counts();
+
+        let summary = get_summary(&counts);
+
+        println!("{}", render_counts(&counts, style));
+
+        info!("run summary - {}", summary);
+
+        if report.interrupted {
+            let message = "run was interrupted, not all items have been processed";
+            println!("{}", paint(message, Color::Yellow, style));
+            warn!("{}", message);
+        }
+
+        if report.aborted {
+            let message = "run was aborted after repeated api failures, not all items have been processed";
+            println!("{}", paint(message, Color::Red, style));
+            error!("{}", message);
+        }
+
+        if report.error_limit_reached {
+            let message = "run was aborted after too many failed items, not all items have been processed";
+            println!("{}", paint(message, Color::Red, style));
+            error!("{}", message);
+        }
+
+        if report.timed_out {
+            let message = "run has exceeded its timeout, not all items have been processed";
+            println!("{}", paint(message, Color::Red, style));
+            error!("{}", message);
+        }
+    }
+
+    pub fn render_outcome_row(outcome: &ItemOutcome, style: OutputStyle) -> String {
+        let (status, mut details) = match &outcome.status {
+            ItemStatus::Created => ("created", "scenario and trigger created".to_string()),
+            ItemStatus::Renamed(old_url) => ("renamed", format!("renamed from '{}'", old_url)),
+            ItemStatus::Adopted(old_name) => ("adopted", format!("adopted hand-made scenario '{}'", old_name)),
+            ItemStatus::Duplicate(source) => ("dup", format!("same url as item '{}'", source)),
+            ItemStatus::Planned(_) => ("planned", "scenario and trigger to create".to_string()),
+            ItemStatus::Unreachable => ("unreach", "skipped".to_string()),
+            ItemStatus::Deferred => ("deferred", "host is in maintenance".to_string()),
+            ItemStatus::SkippedDisabled => ("skipped", "host isn't monitored".to_string()),
+            ItemStatus::SkippedByScript => ("skipped", "skipped by script".to_string()),
+            ItemStatus::Stale(reason) => ("stale", format!("{}, item '{}'", reason, outcome.item_name)),
+            ItemStatus::Failed(reason) => ("failed", get_failure_details(outcome, reason)),
+            ItemStatus::SkippedExisting => ("skipped", String::new())
+        };
+
+        if let Some(probe_error) = &outcome.probe_error {
+            details = format!("{}, url is unreachable: {}", details, probe_error);
+        }
+
+        if let Some(proxy) = &outcome.proxy {
+            details = format!("{}, via proxy '{}'", details, proxy).trim_start_matches(", ").to_string();
+        }
+
+        let row = format!("{:<8} {:<30} {:<50} {}", status, outcome.host, outcome.url, details);
+
+        match style {
+            OutputStyle::Plain => row,
+            OutputStyle::Colored => {
+                let (symbol, color) = match &outcome.status {
+                    ItemStatus::Created | ItemStatus::Renamed(_) | ItemStatus::Adopted(_) |
+                    ItemStatus::Planned(_) => ("✔", Color::Green),
+                    ItemStatus::Failed(_) => ("✘", Color::Red),
+                    ItemStatus::SkippedExisting | ItemStatus::SkippedDisabled |
+                    ItemStatus::SkippedByScript | ItemStatus::Duplicate(_) => ("·", Color::Dim),
+                    ItemStatus::Unreachable | ItemStatus::Deferred | ItemStatus::Stale(_) => ("!", Color::Yellow)
+                };
+
+                paint(&format!("{} {}", symbol, row), color, style)
+            }
+        }
+    }
+
+    /// Non-zero counts only, i.e. `✔ 3 created  · 12 skipped  ✘ 1 failed`
+    pub fn render_compact_summary(counts: &RunCounts, style: OutputStyle) -> String {
+        let parts = vec![
+            (counts.scenarios_created, "✔", "created", Color::Green),
+            (counts.renamed, "✔", "renamed", Color::Green),
+            (counts.adopted, "✔", "adopted", Color::Green),
+            (counts.planned, "✔", "planned", Color::Green),
+            (counts.skipped + counts.duplicates, "·", "skipped", Color::Dim),
+            (counts.deferred, "!", "deferred", Color::Yellow),
+            (counts.stale, "!", "stale", Color::Yellow),
+            (counts.unreachable, "!", "unreachable", Color::Yellow),
+            (counts.failed, "✘", "failed", Color::Red)
+        ];
+
+        let summary: Vec<String> = parts.into_iter()
+            .filter(|(count, _, _, _)| *count > 0)
+            .map(|(count, symbol, name, color)| paint(&format!("{} {} {}", symbol, count, name), color, style))
+            .collect();
+
+        if summary.is_empty() {
+            format!("{} items, nothing to do", counts.items)
+        } else {
+            format!("{} items: {}", counts.items, summary.join("  "))
+        }
+    }
+
+    /// Summary line of counts, full for plain style, compact for colored one
+    pub fn render_counts(counts: &RunCounts, style: OutputStyle) -> String {
+        match style {
+            OutputStyle::Plain => get_summary(counts),
+            OutputStyle::Colored => render_compact_summary(counts, style)
+        }
+    }
+
+    fn get_summary(counts: &RunCounts) -> String {
+        let mut summary = format!(
+            "items: {}, scenarios created: {}, triggers created: {}, skipped: {}, duplicates: {}, failed: {}",
+            counts.items, counts.scenarios_created, counts.triggers_created,
+            counts.skipped, counts.duplicates, counts.failed
+        );
+
+        if counts.renamed > 0 {
+            summary.push_str(&format!(", renamed: {}", counts.renamed));
+        }
+
+        if counts.adopted > 0 {
+            summary.push_str(&format!(", adopted: {}", counts.adopted));
+        }
+
+        if counts.planned > 0 {
+            summary.push_str(&format!(", planned: {}", counts.planned));
+        }
+
+        if counts.stale > 0 {
+            summary.push_str(&format!(", stale items: {}", counts.stale));
+        }
+
+        if counts.deferred > 0 {
+            summary.push_str(&format!(", deferred: {}", counts.deferred));
+        }
+
+        if counts.unreachable > 0 {
+            summary.push_str(&format!(", unreachable: {}", counts.unreachable));
+        }
+
+        summary
+    }
+
+    fn get_failure_details(outcome: &ItemOutcome, reason: &str) -> String {
+        if outcome.scenario_created {
+            format!("{} (web scenario was created)", reason)
+
+        } else if outcome.url.is_empty() {
+            format!("{} (item '{}')", reason, outcome.item_name)
+
+        } else {
+            reason.to_string()
+        }
+    }
+}
@@ -0,0 +1,160 @@
+pub mod reportfile {
+    use std::fs;
+    use std::path::Path;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use serde::Serialize;
+
+    use crate::config::config::{Config, DedupScope, ItemKeyPatternConfig};
+    use crate::errors::errors::OperationError;
+    use crate::metrics::metrics::get_api_call_stats;
+    use crate::report::report::{ItemStatus, RunCounts, RunReport};
+    use crate::types::types::EmptyResult;
+
+    /// Structured report of the run for archival and later diffing
+    #[derive(Serialize)]
+    pub struct RunReportFile {
+        pub finished_at: u64,
+        pub duration_seconds: f64,
+        pub status: String,
+        pub inputs: RunInputs,
+        pub counts: Option<RunCounts>,
+        pub outcomes: Vec<OutcomeRecord>,
+        pub api_errors: Vec<String>
+    }
+
+    #[derive(Serialize)]
+    pub struct RunInputs {
+        pub zabbix_endpoint: String,
+        pub item_key_patterns: Vec<String>,
+        pub lld_item_key: Option<String>,
+        pub urls_file: Option<String>,
+        pub docker_host: Option<String>,
+        pub vhosts_host: Option<String>,
+        pub hosts_per_chunk: usize,
+        pub concurrency: usize,
+        pub dedup_scope: String
+    }
+
+    #[derive(Serialize)]
+    pub struct OutcomeRecord {
+        pub item: String,
+        pub host: String,
+        pub url: String,
+        pub status: String,
+        pub probe_error: Option<String>,
+        pub proxy: Option<String>,
+        pub scenario_created: bool,
+        pub trigger_created: bool,
+        pub details: Option<String>
+    }
+
+    /// File path of one config of `--config-dir` run (report, lock and state files):
+    /// `reports/run.json` becomes `reports/run-<tenant>.json`
+    pub fn get_tenant_file_path(file_path: &str, tenant: &str) -> String {
+        let path = Path::new(file_path);
+
+        let file_name = match (path.file_stem(), path.extension()) {
+            (Some(stem), Some(extension)) => format!("{}-{}.{}", stem.to_string_lossy(), tenant,
+                                                     extension.to_string_lossy()),
+            _ => format!("{}-{}", file_path, tenant)
+        };
+
+        path.with_file_name(file_name).to_string_lossy().to_string()
+    }
+
+    /// Writes report as YAML for .yml/.yaml paths, as JSON otherwise.
+    /// `report` is absent when the run failed before any item was processed.
+    pub fn write_report_file(path: &Path, config: &Config, report: Option<&RunReport>,
+                             run_duration: Duration) -> EmptyResult {
+        let report_file = get_report_file(config, report, run_duration);
+
+        let content = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("yml") | Some("yaml") => serde_yaml::to_string(&report_file).map_err(|e| e.to_string()),
+            _ => serde_json::to_string_pretty(&report_file).map_err(|e| e.to_string())
+        };
+
+        match content {
+            Ok(content) => {
+                fs::write(path, content)?;
+                info!("run report has been written to '{}'", path.display());
+                Ok(())
+            }
+            Err(e) => {
+                error!("unable to serialize run report: {}", e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    pub fn get_report_file(config: &Config, report: Option<&RunReport>, run_duration: Duration) -> RunReportFile {
+        let status = match report {
+            Some(report) if report.interrupted => "interrupted",
+            Some(report) if report.aborted || report.error_limit_reached => "aborted",
+            Some(report) if report.timed_out => "timed-out",
+            Some(report) if !report.has_failures() => "success",
+            _ => "failed"
+        };
+
+        let outcomes = report.map(|report| report.outcomes.iter().map(|outcome| {
+            let (status, details) = match &outcome.status {
+                ItemStatus::Created => ("created", None),
+                ItemStatus::SkippedExisting => ("skipped", None),
+                ItemStatus::SkippedDisabled => ("skipped", Some("host isn't monitored".to_string())),
+                ItemStatus::SkippedByScript => ("skipped", Some("skipped by script".to_string())),
+                ItemStatus::Duplicate(source) => ("duplicate", Some(format!("same url as item '{}'", source))),
+                ItemStatus::Planned(_) => ("planned", None),
+                ItemStatus::Unreachable => ("unreachable", None),
+                ItemStatus::Deferred => ("deferred", Some("host is in maintenance".to_string())),
+                ItemStatus::Stale(reason) => ("stale", Some(reason.to_string())),
+                ItemStatus::Renamed(old_url) => ("renamed", Some(format!("renamed from '{}'", old_url))),
+                ItemStatus::Adopted(old_name) => ("adopted", Some(format!("adopted hand-made scenario '{}'", old_name))),
+                ItemStatus::Failed(reason) => ("failed", Some(reason.to_string()))
+            };
+
+            OutcomeRecord {
+                item: outcome.item_name.to_string(),
+                host: outcome.host.to_string(),
+                url: outcome.url.to_string(),
+                status: status.to_string(),
+                probe_error: outcome.probe_error.clone(),
+                proxy: outcome.proxy.clone(),
+                scenario_created: outcome.scenario_created,
+                trigger_created: outcome.trigger_created,
+                details
+            }
+        }).collect()).unwrap_or_default();
+
+        RunReportFile {
+            finished_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+            duration_seconds: run_duration.as_secs_f64(),
+            status: status.to_string(),
+            inputs: get_run_inputs(config),
+            counts: report.map(|report| report.get_counts()),
+            outcomes,
+            api_errors: get_api_call_stats(&config.zabbix.api.endpoint).errors
+        }
+    }
+
+    fn get_run_inputs(config: &Config) -> RunInputs {
+        let discovery_config = &config.discovery;
+
+        RunInputs {
+            zabbix_endpoint: config.zabbix.api.endpoint.to_string(),
+            item_key_patterns: config.items.patterns.iter().map(|pattern| match pattern {
+                ItemKeyPatternConfig::Mask(mask) => mask.to_string(),
+                ItemKeyPatternConfig::Regex { regex, .. } => regex.to_string()
+            }).collect(),
+            lld_item_key: discovery_config.lld.as_ref().map(|lld| lld.item_key.to_string()),
+            urls_file: discovery_config.urls_file.clone(),
+            docker_host: discovery_config.docker.as_ref().map(|docker| docker.host.to_string()),
+            vhosts_host: discovery_config.vhosts.as_ref().map(|vhosts| vhosts.host.to_string()),
+            hosts_per_chunk: config.processing.hosts_per_chunk,
+            concurrency: config.processing.concurrency,
+            dedup_scope: match config.urls.dedup_scope {
+                DedupScope::Host => "host",
+                DedupScope::Global => "global"
+            }.to_string()
+        }
+    }
+}
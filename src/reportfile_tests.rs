@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod reportfile_tests {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use crate::config::config::load_config_from_file;
+    use crate::report::report::{ItemOutcome, ItemStatus, RunReport};
+    use crate::reportfile::reportfile::{get_report_file, get_tenant_file_path};
+
+    #[test]
+    fn report_should_contain_inputs_and_outcomes() {
+        let config = load_config_from_file(Path::new("tests/wszl.yml")).unwrap();
+
+        let mut report = RunReport::new();
+
+        let mut duplicate = ItemOutcome::new("vhost item 2", "web-01");
+        duplicate.url = "https://example.com".to_string();
+        duplicate.status = ItemStatus::Duplicate("vhost item 1".to_string());
+        report.outcomes.push(duplicate);
+
+        let mut failed = ItemOutcome::new("vhost item 3", "web-01");
+        failed.fail("unable to create trigger");
+        report.outcomes.push(failed);
+
+        let report_file = get_report_file(&config, Some(&report), Duration::from_secs(5));
+
+        assert_eq!(report_file.status, "failed");
+        assert_eq!(report_file.inputs.item_key_patterns.len(), 2);
+        assert_eq!(report_file.inputs.lld_item_key.unwrap(), "vhost.discovery");
+        assert_eq!(report_file.inputs.dedup_scope, "global");
+
+        assert_eq!(report_file.outcomes.len(), 2);
+        assert_eq!(report_file.outcomes[0].status, "duplicate");
+        assert_eq!(report_file.outcomes[0].details.as_ref().unwrap(), "same url as item 'vhost item 1'");
+        assert_eq!(report_file.outcomes[1].status, "failed");
+        assert_eq!(report_file.counts.unwrap().failed, 1);
+    }
+
+    #[test]
+    fn report_of_failed_run_should_have_no_outcomes() {
+        let config = load_config_from_file(Path::new("tests/wszl.yml")).unwrap();
+
+        let report_file = get_report_file(&config, None, Duration::from_secs(1));
+
+        assert_eq!(report_file.status, "failed");
+        assert!(report_file.counts.is_none());
+        assert!(report_file.outcomes.is_empty());
+    }
+
+    #[test]
+    fn tenant_should_be_appended_to_file_name() {
+        assert_eq!(get_tenant_file_path("reports/run.json", "team-a"), "reports/run-team-a.json");
+        assert_eq!(get_tenant_file_path("run", "team-a"), "run-team-a");
+        assert_eq!(get_tenant_file_path("wszl-state.json", "team-a"), "wszl-state-team-a.json");
+    }
+}
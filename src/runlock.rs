@@ -0,0 +1,43 @@
+pub mod runlock {
+    use std::fs::{File, OpenOptions};
+    use std::io::Write;
+    use std::path::Path;
+
+    use fs2::FileExt;
+
+    use crate::types::types::OperationResult;
+
+    /// Exit code when another run holds the lock (EX_TEMPFAIL)
+    pub const LOCKED_EXIT_CODE: i32 = 75;
+
+    /// Exclusive lock on file, released when dropped or when the process exits
+    pub struct RunLock {
+        file: File
+    }
+
+    impl Drop for RunLock {
+        fn drop(&mut self) {
+            if let Err(e) = self.file.unlock() {
+                warn!("unable to release run lock: {}", e);
+            }
+        }
+    }
+
+    /// Returns `None` if the lock is held by another run
+    pub fn acquire_run_lock(file_path: &Path) -> OperationResult<Option<RunLock>> {
+        debug!("acquire run lock '{}'", file_path.display());
+
+        // Not truncated here, file keeps pid of the run holding the lock
+        let mut file = OpenOptions::new().create(true).write(true).truncate(false).open(file_path)?;
+
+        match file.try_lock_exclusive() {
+            Ok(_) => {
+                // Pid is informational only, lock itself is kept by OS
+                file.set_len(0)?;
+                writeln!(file, "{}", std::process::id())?;
+                Ok(Some(RunLock { file }))
+            }
+            Err(_) => Ok(None)
+        }
+    }
+}
@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod runlock_tests {
+    use std::env;
+    use std::fs;
+
+    use crate::runlock::runlock::acquire_run_lock;
+
+    #[test]
+    fn second_run_should_not_get_lock_until_first_releases_it() {
+        let lock_path = env::temp_dir().join(format!("wszl-test-{}.lock", std::process::id()));
+
+        let lock = acquire_run_lock(&lock_path).unwrap();
+        assert!(lock.is_some());
+
+        assert!(acquire_run_lock(&lock_path).unwrap().is_none());
+
+        drop(lock);
+
+        assert!(acquire_run_lock(&lock_path).unwrap().is_some());
+
+        fs::remove_file(&lock_path).unwrap();
+    }
+}
@@ -0,0 +1,102 @@
+pub mod runstate {
+    use std::fs;
+    use std::path::Path;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::errors::errors::OperationError;
+    use crate::report::report::RunReport;
+    use crate::types::types::{EmptyResult, OperationResult};
+
+    /// State kept between runs
+    #[derive(Serialize, Deserialize, Default)]
+    pub struct RunState {
+        pub failed: Vec<FailedItem>,
+        /// Unix time of the last run which processed all items without failures
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub last_success: Option<u64>,
+        /// Highest item id which all items up to were reconciled by that run, only items with higher ids
+        /// are processed by new items only runs
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub last_item_id: Option<u64>
+    }
+
+    #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+    pub struct FailedItem {
+        pub item: String,
+        pub host: String,
+        /// Empty when url couldn't be obtained from item
+        pub url: String
+    }
+
+    impl RunState {
+        /// Run with failures keeps last success of `previous` state, so the next new items only run
+        /// processes its items again
+        pub fn from_report(report: &RunReport, previous: &RunState, now: u64) -> RunState {
+            let (last_success, last_item_id) = if report.is_complete_success() {
+                (Some(now), report.get_reconciled_item_id().or(previous.last_item_id))
+            } else {
+                (previous.last_success, previous.last_item_id)
+            };
+
+            RunState {
+                failed: report.outcomes.iter()
+                    .filter(|outcome| outcome.is_failed())
+                    .map(|outcome| FailedItem {
+                        item: outcome.item_name.to_string(),
+                        host: outcome.host.to_string(),
+                        url: outcome.url.to_string()
+                    })
+                    .collect(),
+                last_success,
+                last_item_id
+            }
+        }
+
+        pub fn get_failed_hosts(&self) -> Vec<String> {
+            let mut hosts: Vec<String> = self.failed.iter().map(|item| item.host.to_string()).collect();
+            hosts.sort();
+            hosts.dedup();
+            hosts
+        }
+
+        /// Url matches failed item of the host, item name is compared when url is unknown
+        pub fn is_failed(&self, host: &str, item: &str, url: &str) -> bool {
+            self.failed.iter().any(|failed_item| {
+                failed_item.host == host &&
+                    if failed_item.url.is_empty() { failed_item.item == item } else { failed_item.url == url }
+            })
+        }
+    }
+
+    /// Missing file means there's nothing from previous runs
+    pub fn load_run_state(file_path: &Path) -> OperationResult<RunState> {
+        if !file_path.exists() {
+            return Ok(RunState::default())
+        }
+
+        let content = fs::read_to_string(file_path)?;
+
+        match serde_json::from_str(&content) {
+            Ok(run_state) => Ok(run_state),
+            Err(e) => {
+                error!("unsupported state file '{}': {}", file_path.display(), e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    pub fn save_run_state(file_path: &Path, run_state: &RunState) -> EmptyResult {
+        match serde_json::to_string_pretty(run_state) {
+            Ok(content) => {
+                fs::write(file_path, content)?;
+                debug!("run state has been written to '{}'", file_path.display());
+                Ok(())
+            }
+            Err(e) => {
+                error!("unable to serialize run state: {}", e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod runstate_tests {
+    use std::env;
+    use std::fs;
+
+    use crate::report::report::{ItemOutcome, ItemStatus, RunReport};
+    use crate::runstate::runstate::{FailedItem, load_run_state, RunState, save_run_state};
+
+    fn get_failed_item(host: &str, item: &str, url: &str) -> FailedItem {
+        FailedItem { item: item.to_string(), host: host.to_string(), url: url.to_string() }
+    }
+
+    #[test]
+    fn only_failed_items_should_be_recorded() {
+        let mut report = RunReport::new();
+
+        let mut created = ItemOutcome::new("Vhost site1", "web1");
+        created.url = "https://site1.com".to_string();
+        report.outcomes.push(created);
+
+        let mut failed = ItemOutcome::new("Vhost site2", "web1");
+        failed.url = "https://site2.com".to_string();
+        failed.fail("unable to create web scenario");
+        report.outcomes.push(failed);
+
+        let run_state = RunState::from_report(&report, &RunState::default(), 1700000000);
+
+        assert_eq!(run_state.failed, vec![get_failed_item("web1", "Vhost site2", "https://site2.com")]);
+    }
+
+    #[test]
+    fn last_success_should_advance_only_after_run_without_failures() {
+        let previous = RunState { failed: vec![], last_success: Some(1600000000), last_item_id: Some(100) };
+
+        let mut report = RunReport::new();
+        report.last_item_id = Some(150);
+        report.outcomes.push(ItemOutcome::new("Vhost site1", "web1"));
+
+        let run_state = RunState::from_report(&report, &previous, 1700000000);
+        assert_eq!(run_state.last_success, Some(1700000000));
+        assert_eq!(run_state.last_item_id, Some(150));
+
+        let mut failed = ItemOutcome::new("Vhost site2", "web1");
+        failed.fail("unable to create web scenario");
+        report.outcomes.push(failed);
+
+        let run_state = RunState::from_report(&report, &previous, 1700000000);
+        assert_eq!(run_state.last_success, Some(1600000000));
+        assert_eq!(run_state.last_item_id, Some(100));
+
+        let mut report = RunReport::new();
+        report.interrupted = true;
+
+        assert_eq!(RunState::from_report(&report, &previous, 1700000000).last_item_id, Some(100));
+    }
+
+    #[test]
+    fn last_item_id_should_not_pass_items_left_for_next_runs() {
+        let mut report = RunReport::new();
+        report.last_item_id = Some(150);
+
+        let mut created = ItemOutcome::new("Vhost site1", "web1");
+        created.itemid = "110".to_string();
+        report.outcomes.push(created);
+
+        for (itemid, status) in [("140", ItemStatus::Deferred), ("130", ItemStatus::Unreachable)] {
+            let mut outcome = ItemOutcome::new("Vhost site2", "web2");
+            outcome.itemid = itemid.to_string();
+            outcome.status = status;
+            report.outcomes.push(outcome);
+        }
+
+        let run_state = RunState::from_report(&report, &RunState::default(), 1700000000);
+        assert_eq!(run_state.last_success, Some(1700000000));
+        assert_eq!(run_state.last_item_id, Some(129));
+    }
+
+    #[test]
+    fn last_item_id_should_pass_disabled_and_stale_items() {
+        let mut report = RunReport::new();
+        report.last_item_id = Some(150);
+
+        for (itemid, status) in [("120", ItemStatus::SkippedDisabled),
+                                 ("130", ItemStatus::Stale("item has no data".to_string()))] {
+            let mut outcome = ItemOutcome::new("Vhost site2", "web2");
+            outcome.itemid = itemid.to_string();
+            outcome.status = status;
+            report.outcomes.push(outcome);
+        }
+
+        let run_state = RunState::from_report(&report, &RunState::default(), 1700000000);
+        assert_eq!(run_state.last_item_id, Some(150));
+    }
+
+    #[test]
+    fn failed_item_should_be_matched_by_url_or_by_item_without_url() {
+        let run_state = RunState {
+            failed: vec![
+                get_failed_item("web1", "Vhost site1", "https://site1.com"),
+                get_failed_item("web2", "Vhost broken", ""),
+                get_failed_item("web1", "Vhost site3", "https://site3.com")
+            ],
+            ..RunState::default()
+        };
+
+        assert_eq!(run_state.get_failed_hosts(), vec!["web1", "web2"]);
+
+        assert!(run_state.is_failed("web1", "Vhost renamed", "https://site1.com"));
+        assert!(run_state.is_failed("web2", "Vhost broken", "https://broken.com"));
+        assert!(!run_state.is_failed("web2", "Vhost site1", "https://site1.com"));
+        assert!(!run_state.is_failed("web1", "Vhost site2", "https://site2.com"));
+    }
+
+    #[test]
+    fn saved_state_should_be_loaded_and_missing_file_should_be_empty() {
+        let file_path = env::temp_dir().join(format!("wszl-test-state-{}.json", std::process::id()));
+
+        assert!(load_run_state(&file_path).unwrap().failed.is_empty());
+
+        let run_state = RunState { failed: vec![get_failed_item("web1", "Vhost site1", "https://site1.com")],
+                                   last_success: Some(1700000000), last_item_id: Some(100) };
+        save_run_state(&file_path, &run_state).unwrap();
+
+        let loaded_state = load_run_state(&file_path).unwrap();
+        assert_eq!(loaded_state.failed, run_state.failed);
+        assert_eq!(loaded_state.last_item_id, Some(100));
+
+        // State of older versions has no last success
+        fs::write(&file_path, r#"{"failed": []}"#).unwrap();
+        assert_eq!(load_run_state(&file_path).unwrap().last_item_id, None);
+
+        fs::remove_file(&file_path).unwrap();
+    }
+}
@@ -0,0 +1,278 @@
+pub mod scripting {
+    use std::fs;
+    use std::path::Path;
+
+    use rhai::{AST, Dynamic, Engine, Map, Scope};
+
+    use crate::errors::errors::OperationError;
+    use crate::triggers::triggers::TriggerParams;
+    use crate::types::types::OperationResult;
+    use crate::webscenarios::webscenarios::{HttpField, WebScenarioParams, WebScenarioStep};
+
+    /// Function which is called by wszl for every url
+    const MAP_URL_FUNCTION: &str = "map_url";
+
+    /// Protection against endless loops in scripts
+    const MAX_OPERATIONS: u64 = 100_000;
+
+    /// Nesting limits of expressions and functions, debug build defaults are too low for nested maps
+    const MAX_EXPRESSION_DEPTH: usize = 128;
+    const MAX_FUNCTION_EXPRESSION_DEPTH: usize = 64;
+
+    /// Item passed to the script as a map
+    pub struct ScriptItem<'a> {
+        pub key: &'a str,
+        pub source: &'a str,
+        pub host: &'a str,
+        pub url: &'a str
+    }
+
+    /// Changes returned by the script (or carried by the item), nothing is changed by default
+    #[derive(Default, Debug, PartialEq, Clone)]
+    pub struct UrlOverrides {
+        pub skip: bool,
+        pub scenario_name: Option<String>,
+        pub steps: Option<Vec<StepOverride>>,
+        pub severity: Option<u8>,
+        /// Expected status codes of steps without their own
+        pub status_codes: Option<String>,
+        /// Text required in response of every step
+        pub required: Option<String>,
+        /// Timeout of every step, i.e. `30s`
+        pub timeout: Option<String>,
+        /// Scenario variables, replace configured variables of the same name
+        pub variables: Option<Vec<HttpField>>
+    }
+
+    impl UrlOverrides {
+        /// Values of `other` win
+        pub fn merge(self, other: UrlOverrides) -> UrlOverrides {
+            UrlOverrides {
+                skip: self.skip || other.skip,
+                scenario_name: other.scenario_name.or(self.scenario_name),
+                steps: other.steps.or(self.steps),
+                severity: other.severity.or(self.severity),
+                status_codes: other.status_codes.or(self.status_codes),
+                required: other.required.or(self.required),
+                timeout: other.timeout.or(self.timeout),
+                variables: other.variables.or(self.variables)
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Clone)]
+    pub struct StepOverride {
+        pub name: String,
+        pub url: String,
+        pub status_codes: Option<String>,
+        pub posts: Option<String>,
+        pub headers: Vec<HttpField>,
+        pub variables: Vec<HttpField>
+    }
+
+    /// Rhai script with `map_url(item)` function, see `get_overrides`
+    pub struct UrlScript {
+        engine: Engine,
+        ast: AST
+    }
+
+    impl UrlScript {
+        pub fn load(file_path: &Path) -> OperationResult<UrlScript> {
+            info!("loading script '{}'", file_path.display());
+
+            let source = fs::read_to_string(file_path)?;
+
+            UrlScript::compile(&source)
+        }
+
+        pub fn compile(source: &str) -> OperationResult<UrlScript> {
+            let mut engine = Engine::new();
+            engine.set_max_operations(MAX_OPERATIONS);
+            engine.set_max_expr_depths(MAX_EXPRESSION_DEPTH, MAX_FUNCTION_EXPRESSION_DEPTH);
+
+            match engine.compile(source) {
+                Ok(ast) => Ok(UrlScript { engine, ast }),
+                Err(e) => {
+                    error!("unable to compile script: {}", e);
+                    Err(OperationError::Error)
+                }
+            }
+        }
+
+        /// Calls `map_url` with item map (`key`, `source`, `host`, `url`). The function returns `()`
+        /// to keep defaults or a map with any of: `skip` (bool), `name` (scenario name),
+        /// `steps` (array of maps with `name`, `url` and optional `status_codes`, `posts`, `headers` and
+        /// `variables`), `severity` (0-5), `timeout` (of every step, i.e. `30s`), `variables` (of scenario).
+        /// Headers and variables are maps of names and values.
+        pub fn get_overrides(&self, item: &ScriptItem) -> OperationResult<UrlOverrides> {
+            let mut item_map = Map::new();
+            item_map.insert("key".into(), item.key.into());
+            item_map.insert("source".into(), item.source.into());
+            item_map.insert("host".into(), item.host.into());
+            item_map.insert("url".into(), item.url.into());
+
+            let result = self.engine.call_fn::<Dynamic>(&mut Scope::new(), &self.ast,
+                                                        MAP_URL_FUNCTION, (item_map,));
+
+            match result {
+                Ok(value) => get_url_overrides(value),
+                Err(e) => {
+                    error!("script has failed for url '{}': {}", item.url, e);
+                    Err(OperationError::Error)
+                }
+            }
+        }
+    }
+
+    fn get_url_overrides(value: Dynamic) -> OperationResult<UrlOverrides> {
+        if value.is_unit() {
+            return Ok(UrlOverrides::default())
+        }
+
+        let overrides_map = match value.try_cast::<Map>() {
+            Some(overrides_map) => overrides_map,
+            None => {
+                error!("script has returned unsupported value, expected map or ()");
+                return Err(OperationError::Error)
+            }
+        };
+
+        let mut overrides = UrlOverrides::default();
+
+        if let Some(skip) = overrides_map.get("skip") {
+            overrides.skip = skip.as_bool().unwrap_or(false);
+        }
+
+        if let Some(name) = overrides_map.get("name") {
+            overrides.scenario_name = Some(name.to_string());
+        }
+
+        if let Some(severity) = overrides_map.get("severity") {
+            match severity.as_int() {
+                Ok(severity) if (0..=5).contains(&severity) => overrides.severity = Some(severity as u8),
+                _ => {
+                    error!("script has returned invalid severity '{}', expected 0-5", severity);
+                    return Err(OperationError::Error)
+                }
+            }
+        }
+
+        if let Some(timeout) = overrides_map.get("timeout") {
+            overrides.timeout = Some(timeout.to_string());
+        }
+
+        if let Some(variables) = overrides_map.get("variables") {
+            overrides.variables = Some(get_fields(variables, "variables", HttpField::variable)?);
+        }
+
+        if let Some(steps) = overrides_map.get("steps") {
+            let mut step_overrides = vec![];
+
+            for step in steps.clone().into_array().unwrap_or_default() {
+                let step_map = step.try_cast::<Map>().unwrap_or_default();
+
+                match (step_map.get("name"), step_map.get("url")) {
+                    (Some(name), Some(url)) => step_overrides.push(StepOverride {
+                        name: name.to_string(),
+                        url: url.to_string(),
+                        status_codes: step_map.get("status_codes").map(|value| value.to_string()),
+                        posts: step_map.get("posts").map(|value| value.to_string()),
+                        headers: match step_map.get("headers") {
+                            Some(headers) => get_fields(headers, "headers", get_header)?,
+                            None => vec![]
+                        },
+                        variables: match step_map.get("variables") {
+                            Some(variables) => get_fields(variables, "variables", HttpField::variable)?,
+                            None => vec![]
+                        }
+                    }),
+                    _ => {
+                        error!("script has returned step without name or url");
+                        return Err(OperationError::Error)
+                    }
+                }
+            }
+
+            if step_overrides.is_empty() {
+                error!("script has returned empty steps");
+                return Err(OperationError::Error)
+            }
+
+            overrides.steps = Some(step_overrides);
+        }
+
+        Ok(overrides)
+    }
+
+    /// Map of names and values returned by the script
+    fn get_fields(value: &Dynamic, property: &str,
+                  get_field: fn(&str, &str) -> HttpField) -> OperationResult<Vec<HttpField>> {
+        match value.clone().try_cast::<Map>() {
+            Some(fields_map) => Ok(fields_map.iter()
+                                    .map(|(name, value)| get_field(name, &value.to_string())).collect()),
+            None => {
+                error!("script has returned invalid {}, expected map of names and values", property);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn get_header(name: &str, value: &str) -> HttpField {
+        HttpField { name: name.to_string(), value: value.to_string() }
+    }
+
+    pub fn apply_scenario_overrides(params: &mut WebScenarioParams, overrides: &UrlOverrides) {
+        let default_status_codes = overrides.status_codes.clone().unwrap_or_else(|| {
+            params.steps.first().map(|step| step.status_codes.to_string()).unwrap_or_default()
+        });
+
+        let retrieve_mode = params.steps.first().map(|step| step.retrieve_mode).unwrap_or_default();
+
+        let default_timeout = params.steps.first().map(|step| step.timeout.to_string()).unwrap_or_default();
+
+        match &overrides.steps {
+            Some(steps) => {
+                params.steps = steps.iter().enumerate().map(|(index, step)| WebScenarioStep {
+                    name: step.name.to_string(),
+                    url: step.url.to_string(),
+                    status_codes: step.status_codes.clone().unwrap_or_else(|| default_status_codes.to_string()),
+                    required: String::new(),
+                    timeout: default_timeout.to_string(),
+                    retrieve_mode,
+                    posts: step.posts.clone().unwrap_or_default(),
+                    headers: step.headers.clone(),
+                    variables: step.variables.clone(),
+                    no: (index + 1) as u8
+                }).collect();
+            }
+            None => {
+                for step in &mut params.steps {
+                    step.status_codes = default_status_codes.to_string();
+                }
+            }
+        }
+
+        if let Some(required) = &overrides.required {
+            for step in &mut params.steps {
+                step.required = required.to_string();
+            }
+        }
+
+        if let Some(timeout) = &overrides.timeout {
+            for step in &mut params.steps {
+                step.timeout = timeout.to_string();
+            }
+        }
+
+        if let Some(variables) = &overrides.variables {
+            params.variables.retain(|variable| !variables.iter().any(|other| other.name == variable.name));
+            params.variables.extend(variables.iter().cloned());
+        }
+    }
+
+    pub fn apply_trigger_overrides(params: &mut TriggerParams, overrides: &UrlOverrides) {
+        if let Some(severity) = overrides.severity {
+            params.priority = severity.to_string();
+        }
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod scripting_tests {
+    use std::collections::HashMap;
+
+    use crate::config::config::{RetrieveMode, ScenarioVariableConfig, TemplatesConfig, WebScenarioConfig};
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::scripting::scripting::{apply_scenario_overrides, ScriptItem, StepOverride, UrlOverrides, UrlScript};
+    use crate::templates::templates::NameTemplates;
+    use crate::webscenarios::webscenarios::{get_web_scenario_params, HttpField};
+
+    const SCRIPT: &str = r#"
+        fn map_url(item) {
+            if item.url.contains("staging") {
+                return #{ skip: true };
+            }
+
+            if item.host == "shop-01" {
+                return #{
+                    name: "Shop " + item.url,
+                    severity: 5,
+                    timeout: "30s",
+                    steps: [
+                        #{ name: "Home", url: item.url },
+                        #{ name: "Cart", url: item.url + "/cart", status_codes: "200,302" }
+                    ]
+                };
+            }
+        }
+    "#;
+
+    fn get_item<'a>(host: &'a str, url: &'a str) -> ScriptItem<'a> {
+        ScriptItem { key: "vhost.item[example.com]", source: "vhost item", host, url }
+    }
+
+    #[test]
+    fn script_without_overrides_should_keep_defaults() {
+        let script = UrlScript::compile(SCRIPT).unwrap();
+
+        assert_eq!(script.get_overrides(&get_item("web-01", "https://example.com")).unwrap(),
+                   UrlOverrides::default());
+    }
+
+    #[test]
+    fn script_should_skip_urls() {
+        let script = UrlScript::compile(SCRIPT).unwrap();
+
+        assert!(script.get_overrides(&get_item("web-01", "https://staging.example.com")).unwrap().skip);
+    }
+
+    #[test]
+    fn script_should_override_name_steps_and_severity() {
+        let script = UrlScript::compile(SCRIPT).unwrap();
+
+        let overrides = script.get_overrides(&get_item("shop-01", "https://shop.example.com")).unwrap();
+
+        assert!(!overrides.skip);
+        assert_eq!(overrides.scenario_name.unwrap(), "Shop https://shop.example.com");
+        assert_eq!(overrides.severity, Some(5));
+        assert_eq!(overrides.timeout.unwrap(), "30s");
+        assert_eq!(overrides.steps.unwrap(), vec![
+            StepOverride { name: "Home".to_string(), url: "https://shop.example.com".to_string(), status_codes: None,
+                           posts: None, headers: vec![], variables: vec![] },
+            StepOverride { name: "Cart".to_string(), url: "https://shop.example.com/cart".to_string(),
+                           status_codes: Some("200,302".to_string()), posts: None, headers: vec![], variables: vec![] }
+        ]);
+    }
+
+    #[test]
+    fn script_overrides_should_win_over_item_overrides() {
+        let script = UrlScript::compile(SCRIPT).unwrap();
+
+        let item_overrides = UrlOverrides {
+            severity: Some(2),
+            required: Some("Cart".to_string()),
+            ..UrlOverrides::default()
+        };
+
+        let overrides = item_overrides.merge(
+            script.get_overrides(&get_item("shop-01", "https://shop.example.com")).unwrap());
+
+        assert_eq!(overrides.severity, Some(5));
+        assert_eq!(overrides.required.unwrap(), "Cart");
+        assert!(overrides.steps.is_some());
+    }
+
+    #[test]
+    fn login_steps_should_pass_variables_to_next_steps() {
+        let script = UrlScript::compile(r#"
+            fn map_url(item) {
+                #{
+                    variables: #{ user: "monitor" },
+                    steps: [
+                        #{ name: "Login", url: item.url + "/login", posts: "user={user}&password={$SHOP_PASSWORD}",
+                           variables: #{ "{token}": "regex:token=([a-z0-9]+)" } },
+                        #{ name: "Account", url: item.url + "/account", headers: #{ Authorization: "Bearer {token}" } }
+                    ]
+                }
+            }
+        "#).unwrap();
+
+        let overrides = script.get_overrides(&get_item("shop-01", "https://shop.example.com")).unwrap();
+
+        let scenario_config = WebScenarioConfig {
+            response_timeout: "15s".to_string(),
+            expected_status_code: "200".to_string(),
+            attempts: 1,
+            update_interval: "5m".to_string(),
+            retrieve_mode: RetrieveMode::Body,
+            variables: vec![
+                ScenarioVariableConfig { name: "user".to_string(), value: "wszl".to_string() },
+                ScenarioVariableConfig { name: "{lang}".to_string(), value: "en".to_string() }
+            ]
+        };
+
+        let host = ZabbixHost { hostid: "10001".to_string(), host: "shop-01".to_string(),
+                                maintenance_status: "0".to_string(), status: "0".to_string(),
+                                proxy_hostid: "0".to_string() };
+
+        let url = "https://shop.example.com";
+
+        let names = NameTemplates::new(&TemplatesConfig::default()).unwrap()
+                        .get_object_names(&host, url, &HashMap::new(), None).unwrap();
+
+        let mut params = get_web_scenario_params(&scenario_config, &names, url, "10001");
+        apply_scenario_overrides(&mut params, &overrides);
+
+        assert_eq!(params.variables, vec![HttpField::variable("lang", "en"), HttpField::variable("user", "monitor")]);
+
+        assert_eq!(params.steps.len(), 2);
+        assert_eq!(params.steps[0].posts, "user={user}&password={$SHOP_PASSWORD}");
+        assert_eq!(params.steps[0].variables, vec![HttpField::variable("{token}", "regex:token=([a-z0-9]+)")]);
+        assert_eq!(params.steps[1].headers, vec![
+            HttpField { name: "Authorization".to_string(), value: "Bearer {token}".to_string() }
+        ]);
+        assert!(params.steps[1].posts.is_empty());
+    }
+
+    #[test]
+    fn invalid_scripts_should_be_rejected() {
+        assert!(UrlScript::compile("fn map_url(item) {").is_err());
+
+        let script = UrlScript::compile("fn map_url(item) { #{ severity: 9 } }").unwrap();
+        assert!(script.get_overrides(&get_item("web-01", "https://example.com")).is_err());
+
+        let script = UrlScript::compile("fn other(item) { () }").unwrap();
+        assert!(script.get_overrides(&get_item("web-01", "https://example.com")).is_err());
+    }
+}
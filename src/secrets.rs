@@ -0,0 +1,63 @@
+pub mod secrets {
+    use crate::config::config::ZabbixApiConfig;
+    use crate::errors::errors::OperationError;
+    use crate::types::types::StringResult;
+
+    const KEYRING_SERVICE: &str = "wszl";
+
+    /// Keyring entry is per user and API endpoint
+    pub fn get_keyring_user(api_config: &ZabbixApiConfig) -> String {
+        format!("{}@{}", api_config.username, api_config.endpoint)
+    }
+
+    /// Password from config, then OS keyring (if enabled), then terminal prompt.
+    /// Prompted password is saved to the keyring when it's enabled.
+    pub fn get_api_password(api_config: &ZabbixApiConfig) -> StringResult {
+        if !api_config.password.is_empty() {
+            return Ok(api_config.password.to_string())
+        }
+
+        let keyring_user = get_keyring_user(api_config);
+
+        if api_config.keyring {
+            if let Some(password) = read_keyring_password(&keyring_user) {
+                debug!("password for '{}' has been found in keyring", keyring_user);
+                return Ok(password)
+            }
+        }
+
+        let prompt = format!("Zabbix API password for '{}': ", keyring_user);
+
+        match rpassword::prompt_password(prompt) {
+            Ok(password) => {
+                if api_config.keyring {
+                    save_keyring_password(&keyring_user, &password);
+                }
+
+                Ok(password)
+            }
+            Err(e) => {
+                error!("unable to read password from terminal: {}", e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn read_keyring_password(keyring_user: &str) -> Option<String> {
+        match keyring::Entry::new(KEYRING_SERVICE, keyring_user).and_then(|entry| entry.get_password()) {
+            Ok(password) => Some(password),
+            Err(keyring::Error::NoEntry) => None,
+            Err(e) => {
+                warn!("unable to read password from keyring: {}", e);
+                None
+            }
+        }
+    }
+
+    fn save_keyring_password(keyring_user: &str, password: &str) {
+        match keyring::Entry::new(KEYRING_SERVICE, keyring_user).and_then(|entry| entry.set_password(password)) {
+            Ok(_) => info!("password for '{}' has been saved to keyring", keyring_user),
+            Err(e) => warn!("unable to save password to keyring: {}", e)
+        }
+    }
+}
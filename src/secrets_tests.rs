@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod secrets_tests {
+    use crate::config::config::ZabbixApiConfig;
+    use crate::secrets::secrets::{get_api_password, get_keyring_user};
+
+    fn get_api_config(password: &str) -> ZabbixApiConfig {
+        ZabbixApiConfig {
+            endpoint: "http://zabbix/api_jsonrpc.php".to_string(),
+            username: "wszl".to_string(),
+            password: password.to_string(),
+            keyring: true,
+            session_file: None
+        }
+    }
+
+    #[test]
+    fn keyring_user_should_include_endpoint() {
+        assert_eq!("wszl@http://zabbix/api_jsonrpc.php", get_keyring_user(&get_api_config("")));
+    }
+
+    #[test]
+    fn configured_password_should_be_used_without_keyring_and_prompt() {
+        assert_eq!("secret", get_api_password(&get_api_config("secret")).unwrap());
+    }
+}
@@ -0,0 +1,101 @@
+pub mod sender {
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+    use std::time::Duration;
+
+    use serde_json::{json, Value};
+
+    use crate::config::config::TrapperConfig;
+    use crate::errors::errors::OperationError;
+    use crate::notifications::notifications::RunSummary;
+    use crate::types::types::EmptyResult;
+
+    const PROTOCOL_HEADER: &[u8; 5] = b"ZBXD\x01";
+    const HEADER_LENGTH: usize = 13;
+
+    const CONNECTION_TIMEOUT_SECS: u64 = 10;
+
+    /// Sends run outcome to trapper item with Zabbix sender protocol.
+    /// Value is JSON, single values can be extracted by dependent items.
+    pub fn send_run_outcome(trapper_config: &TrapperConfig, summary: &RunSummary) -> EmptyResult {
+        let value = get_trapper_value(summary).to_string();
+
+        let request = json!({
+            "request": "sender data",
+            "data": [{
+                "host": trapper_config.host,
+                "key": trapper_config.key,
+                "value": value
+            }]
+        });
+
+        let response = send_packet(&trapper_config.server, &encode_packet(&request.to_string()))?;
+
+        match decode_packet(&response) {
+            Some(response) if response["response"] == "success" => {
+                let info = response["info"].as_str().unwrap_or_default();
+
+                if info.contains("failed: 0") {
+                    Ok(())
+
+                } else {
+                    error!("trapper value wasn't accepted by zabbix ({}), check item '{}' on host '{}'",
+                           info, trapper_config.key, trapper_config.host);
+                    Err(OperationError::Error)
+                }
+            }
+            _ => {
+                error!("unexpected zabbix trapper response");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    pub fn get_trapper_value(summary: &RunSummary) -> Value {
+        json!({
+            "status": summary.status,
+            "success": if summary.status == "success" { 1 } else { 0 },
+            "duration_seconds": summary.duration_seconds,
+            "counts": summary.counts
+        })
+    }
+
+    /// `ZBXD\x01` header, data length (8 bytes, little-endian) and data
+    pub fn encode_packet(data: &str) -> Vec<u8> {
+        let mut packet = Vec::with_capacity(HEADER_LENGTH + data.len());
+        packet.extend_from_slice(PROTOCOL_HEADER);
+        packet.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        packet.extend_from_slice(data.as_bytes());
+        packet
+    }
+
+    pub fn decode_packet(packet: &[u8]) -> Option<Value> {
+        if packet.len() < HEADER_LENGTH || &packet[..PROTOCOL_HEADER.len()] != PROTOCOL_HEADER {
+            return None
+        }
+
+        serde_json::from_slice(&packet[HEADER_LENGTH..]).ok()
+    }
+
+    fn send_packet(server: &str, packet: &[u8]) -> Result<Vec<u8>, OperationError> {
+        let timeout = Some(Duration::from_secs(CONNECTION_TIMEOUT_SECS));
+
+        let mut stream = match TcpStream::connect(server) {
+            Ok(stream) => stream,
+            Err(e) => {
+                error!("unable to connect to zabbix trapper '{}': {}", server, e);
+                return Err(OperationError::Error)
+            }
+        };
+
+        stream.set_read_timeout(timeout)?;
+        stream.set_write_timeout(timeout)?;
+
+        stream.write_all(packet)?;
+
+        let mut response = vec![];
+        stream.read_to_end(&mut response)?;
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod sender_tests {
+    use std::time::Duration;
+
+    use crate::notifications::notifications::get_run_summary;
+    use crate::report::report::RunReport;
+    use crate::sender::sender::{decode_packet, encode_packet, get_trapper_value};
+
+    #[test]
+    fn packet_should_have_header_and_length() {
+        let packet = encode_packet("{}");
+
+        assert_eq!(packet, b"ZBXD\x01\x02\x00\x00\x00\x00\x00\x00\x00{}".to_vec());
+    }
+
+    #[test]
+    fn response_packet_should_be_decoded() {
+        let packet = encode_packet(r#"{"response":"success","info":"processed: 1; failed: 0"}"#);
+
+        let response = decode_packet(&packet).unwrap();
+
+        assert_eq!(response["response"], "success");
+        assert!(decode_packet(b"HTTP/1.1 400").is_none());
+    }
+
+    #[test]
+    fn trapper_value_should_contain_status_and_counts() {
+        let summary = get_run_summary(Some(&RunReport::new()), Duration::from_secs(2));
+
+        let value = get_trapper_value(&summary);
+
+        assert_eq!(value["status"], "success");
+        assert_eq!(value["success"], 1);
+        assert_eq!(value["counts"]["failed"], 0);
+
+        let summary = get_run_summary(None, Duration::from_secs(2));
+
+        assert_eq!(get_trapper_value(&summary)["success"], 0);
+    }
+}
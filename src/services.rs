@@ -0,0 +1,206 @@
+pub mod services {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    use crate::config::config::{ServiceGroupBy, ServicesConfig};
+    use crate::errors::errors::OperationError;
+    use crate::http::http::send_post_request;
+    use crate::types::types::{OperationResult, StringResult};
+    use crate::zabbix::zabbix;
+    use crate::zabbix::zabbix::{log_zabbix_error, ZabbixError, ZabbixRequest};
+
+    /// Service is in problem state if at least one child is
+    const ALGORITHM_ANY_CHILD: u8 = 1;
+
+    /// Zabbix service (API before 6.0), `triggerid` is set for leaf services only
+    #[derive(Serialize)]
+    pub struct ServiceParams {
+        pub name: String,
+        pub algorithm: u8,
+        pub showsla: u8,
+        pub goodsla: String,
+        pub sortorder: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub triggerid: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub parentid: Option<String>
+    }
+
+    #[derive(Serialize)]
+    struct GetServicesParams {
+        output: Vec<String>,
+        filter: HashMap<String, String>
+    }
+
+    #[derive(Deserialize)]
+    struct ZabbixService {
+        serviceid: String
+    }
+
+    #[derive(Deserialize)]
+    struct GetServicesResponse {
+        result: Option<Vec<ZabbixService>>,
+        error: Option<ZabbixError>
+    }
+
+    #[derive(Deserialize)]
+    struct CreateServiceResult {
+        serviceids: Vec<String>
+    }
+
+    #[derive(Deserialize)]
+    struct CreateServiceResponse {
+        result: Option<CreateServiceResult>,
+        error: Option<ZabbixError>
+    }
+
+    /// Service tree: root service -> group service (host or domain) -> url service linked to trigger.
+    /// Ids of root and group services are cached for the run.
+    pub struct ServiceTree<'a> {
+        config: &'a ServicesConfig,
+        service_ids: Mutex<HashMap<String, String>>
+    }
+
+    impl<'a> ServiceTree<'a> {
+        pub fn new(config: &'a ServicesConfig) -> ServiceTree<'a> {
+            ServiceTree { config, service_ids: Mutex::new(HashMap::new()) }
+        }
+
+        /// Creates service of url under its group, group and root services are created when missing
+        pub fn add_url_service(&self, client: &reqwest::blocking::Client, api_endpoint: &str,
+                               auth_token: &str, host: &str, url: &str, triggerid: &str) -> StringResult {
+            let group_service_id = {
+                let mut service_ids = self.service_ids.lock().unwrap();
+
+                let root_service_id = get_or_create_service(
+                    client, api_endpoint, auth_token, &mut service_ids,
+                    get_service_params(self.config, &self.config.root, None, None)
+                )?;
+
+                let group_name = get_service_group_name(&self.config.group_by, host, url);
+
+                get_or_create_service(
+                    client, api_endpoint, auth_token, &mut service_ids,
+                    get_service_params(self.config, &group_name, None, Some(root_service_id))
+                )?
+            };
+
+            let params = get_service_params(self.config, &format!("Site '{}'", url),
+                                            Some(triggerid.to_string()), Some(group_service_id));
+
+            create_service(client, api_endpoint, auth_token, params)
+        }
+    }
+
+    /// Name of the parent service of url
+    pub fn get_service_group_name(group_by: &ServiceGroupBy, host: &str, url: &str) -> String {
+        match group_by {
+            ServiceGroupBy::Host => format!("Websites on {}", host),
+            ServiceGroupBy::Domain => {
+                let domain = reqwest::Url::parse(url).ok()
+                    .and_then(|parsed_url| parsed_url.host_str().map(String::from))
+                    .unwrap_or_else(|| url.to_string());
+
+                format!("Websites of {}", domain)
+            }
+        }
+    }
+
+    pub fn get_service_params(config: &ServicesConfig, name: &str, triggerid: Option<String>,
+                              parentid: Option<String>) -> ServiceParams {
+        ServiceParams {
+            name: name.to_string(),
+            algorithm: ALGORITHM_ANY_CHILD,
+            showsla: 1,
+            goodsla: format!("{:.4}", config.sla),
+            sortorder: 0,
+            triggerid,
+            parentid
+        }
+    }
+
+    fn get_or_create_service(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                             service_ids: &mut HashMap<String, String>, params: ServiceParams) -> StringResult {
+        if let Some(service_id) = service_ids.get(&params.name) {
+            return Ok(service_id.to_string())
+        }
+
+        let name = params.name.to_string();
+
+        let service_id = match find_service_id(client, api_endpoint, auth_token, &name)? {
+            Some(service_id) => service_id,
+            None => create_service(client, api_endpoint, auth_token, params)?
+        };
+
+        service_ids.insert(name, service_id.to_string());
+
+        Ok(service_id)
+    }
+
+    fn find_service_id(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                       name: &str) -> OperationResult<Option<String>> {
+        let mut filter = HashMap::new();
+        filter.insert("name".to_string(), name.to_string());
+
+        let params = GetServicesParams { output: vec!["serviceid".to_string()], filter };
+
+        let request: ZabbixRequest<GetServicesParams> = ZabbixRequest::new(
+            "service.get", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: GetServicesResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match search_response.result {
+                    Some(services) => Ok(services.into_iter().next().map(|service| service.serviceid)),
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        error!("unable to find service '{}'", name);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find service '{}'", name);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn create_service(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                      params: ServiceParams) -> StringResult {
+        let name = params.name.to_string();
+
+        let request: ZabbixRequest<ServiceParams> = ZabbixRequest::new(
+            "service.create", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let create_response: CreateServiceResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match create_response.result.and_then(|result| result.serviceids.into_iter().next()) {
+                    Some(service_id) => {
+                        info!("service '{}' has been created", name);
+                        Ok(service_id)
+                    }
+                    None => {
+                        log_zabbix_error(&create_response.error);
+                        error!("unable to create service '{}'", name);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to create service '{}'", name);
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
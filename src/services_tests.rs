@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod services_tests {
+    use crate::config::config::{ServiceGroupBy, ServicesConfig};
+    use crate::services::services::{get_service_group_name, get_service_params};
+
+    #[test]
+    fn urls_should_be_grouped_by_host_or_domain() {
+        assert_eq!(get_service_group_name(&ServiceGroupBy::Host, "web-01", "https://example.com/shop"),
+                   "Websites on web-01");
+        assert_eq!(get_service_group_name(&ServiceGroupBy::Domain, "web-01", "https://example.com:8443/shop"),
+                   "Websites of example.com");
+    }
+
+    #[test]
+    fn url_service_should_be_linked_to_trigger() {
+        let config = ServicesConfig { root: "Websites".to_string(), group_by: ServiceGroupBy::Host, sla: 99.5 };
+
+        let params = get_service_params(&config, "Site 'https://example.com'",
+                                        Some("13001".to_string()), Some("7".to_string()));
+
+        let params_value = serde_json::to_value(&params).unwrap();
+
+        assert_eq!(params_value["goodsla"], "99.5000");
+        assert_eq!(params_value["triggerid"], "13001");
+        assert_eq!(params_value["parentid"], "7");
+
+        let root_params = serde_json::to_value(get_service_params(&config, "Websites", None, None)).unwrap();
+
+        assert!(root_params.get("triggerid").is_none());
+        assert!(root_params.get("parentid").is_none());
+    }
+}
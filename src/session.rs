@@ -0,0 +1,90 @@
+pub mod session {
+    use std::fs;
+    use std::fs::OpenOptions;
+    use std::io::Write;
+    #[cfg(unix)]
+    use std::os::unix::fs::OpenOptionsExt;
+    use std::path::Path;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::auth::auth::{is_session_active, login_to_zabbix_api, logout_from_zabbix_api};
+    use crate::config::config::ZabbixApiConfig;
+    use crate::types::types::{EmptyResult, StringResult};
+
+    /// Content of session file
+    #[derive(Serialize, Deserialize)]
+    pub struct CachedSession {
+        pub endpoint: String,
+        pub username: String,
+        pub token: String
+    }
+
+    /// Reuses token from session file while Zabbix accepts it, otherwise logins (and saves new token)
+    pub fn open_api_session(client: &reqwest::blocking::Client, api_config: &ZabbixApiConfig) -> StringResult {
+        if let Some(session_file) = &api_config.session_file {
+            if let Some(token) = read_session_token(Path::new(session_file), api_config) {
+                if is_session_active(client, &api_config.endpoint, &token) {
+                    debug!("cached api session has been reused");
+                    return Ok(token)
+                }
+
+                info!("cached api session has expired, login again");
+            }
+        }
+
+        let token = login_to_zabbix_api(client, &api_config.endpoint, &api_config.username, &api_config.password)?;
+
+        if let Some(session_file) = &api_config.session_file {
+            if let Err(e) = save_session_token(Path::new(session_file), api_config, &token) {
+                warn!("unable to save api session to '{}': {}", session_file, e);
+            }
+        }
+
+        Ok(token)
+    }
+
+    /// Cached session stays open for the next run
+    pub fn close_api_session(client: &reqwest::blocking::Client, api_config: &ZabbixApiConfig,
+                             auth_token: &str) -> EmptyResult {
+        match api_config.session_file {
+            Some(_) => Ok(()),
+            None => logout_from_zabbix_api(client, &api_config.endpoint, auth_token)
+        }
+    }
+
+    /// Token is ignored when endpoint or username has been changed
+    pub fn read_session_token(file_path: &Path, api_config: &ZabbixApiConfig) -> Option<String> {
+        let content = fs::read_to_string(file_path).ok()?;
+
+        match serde_json::from_str::<CachedSession>(&content) {
+            Ok(session) => {
+                if session.endpoint == api_config.endpoint && session.username == api_config.username {
+                    Some(session.token)
+                } else { None }
+            }
+            Err(e) => {
+                warn!("unsupported session file '{}': {}", file_path.display(), e);
+                None
+            }
+        }
+    }
+
+    /// File is readable by owner only
+    pub fn save_session_token(file_path: &Path, api_config: &ZabbixApiConfig, token: &str) -> std::io::Result<()> {
+        let session = CachedSession {
+            endpoint: api_config.endpoint.to_string(),
+            username: api_config.username.to_string(),
+            token: token.to_string()
+        };
+
+        let mut options = OpenOptions::new();
+        options.create(true).write(true).truncate(true);
+
+        #[cfg(unix)]
+        options.mode(0o600);
+
+        let mut file = options.open(file_path)?;
+        file.write_all(serde_json::to_string(&session)?.as_bytes())
+    }
+}
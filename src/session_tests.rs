@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod session_tests {
+    use std::env;
+    use std::fs;
+
+    use crate::config::config::ZabbixApiConfig;
+    use crate::session::session::{read_session_token, save_session_token};
+
+    fn get_api_config(username: &str) -> ZabbixApiConfig {
+        ZabbixApiConfig {
+            endpoint: "http://zabbix/api_jsonrpc.php".to_string(),
+            username: username.to_string(),
+            password: "secret".to_string(),
+            keyring: false,
+            session_file: None
+        }
+    }
+
+    #[test]
+    fn saved_token_should_be_read_for_the_same_endpoint_and_user() {
+        let file_path = env::temp_dir().join(format!("wszl-test-session-{}.json", std::process::id()));
+
+        save_session_token(&file_path, &get_api_config("wszl"), "c2f8a1b0").unwrap();
+
+        assert_eq!(read_session_token(&file_path, &get_api_config("wszl")).unwrap(), "c2f8a1b0");
+        assert!(read_session_token(&file_path, &get_api_config("admin")).is_none());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&file_path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        fs::remove_file(&file_path).unwrap();
+    }
+}
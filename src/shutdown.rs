@@ -0,0 +1,51 @@
+pub mod shutdown {
+    use std::process::exit;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+    /// Run has been stopped after `--timeout`, same as `timeout` command
+    pub const TIMED_OUT_EXIT_CODE: i32 = 124;
+
+    static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+    static RUN_DEADLINE: Mutex<Option<Instant>> = Mutex::new(None);
+
+    /// Handles SIGINT/SIGTERM: the first signal asks the run to stop after the current item,
+    /// the second one terminates the process immediately.
+    pub fn register_shutdown_handler() {
+        let result = ctrlc::set_handler(|| {
+            if SHUTDOWN_REQUESTED.swap(true, Ordering::SeqCst) {
+                warn!("second termination signal received, exit immediately");
+                exit(INTERRUPTED_EXIT_CODE);
+            }
+
+            warn!("termination signal received, finishing current item..");
+        });
+
+        if let Err(e) = result {
+            error!("unable to register termination signal handler: {}", e);
+        }
+    }
+
+    /// Same as the first termination signal, i.e. windows service stop
+    #[cfg_attr(not(windows), allow(dead_code))]
+    pub fn request_shutdown() {
+        SHUTDOWN_REQUESTED.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_shutdown_requested() -> bool {
+        SHUTDOWN_REQUESTED.load(Ordering::SeqCst)
+    }
+
+    /// Run stops after the current item once `timeout` from now has passed
+    pub fn set_run_deadline(timeout: Duration) {
+        *RUN_DEADLINE.lock().unwrap() = Some(Instant::now() + timeout);
+    }
+
+    pub fn is_run_deadline_exceeded() -> bool {
+        RUN_DEADLINE.lock().unwrap().map(|deadline| Instant::now() >= deadline).unwrap_or(false)
+    }
+}
@@ -0,0 +1,162 @@
+pub mod status {
+    use reqwest::blocking::Client;
+
+    use crate::config::config::Config;
+    use crate::errors::errors::OperationError;
+    use crate::items::items::{find_web_items, ZabbixWebItem};
+    use crate::session::session::{close_api_session, open_api_session};
+    use crate::triggers::triggers::{find_managed_triggers, ZabbixTrigger};
+    use crate::types::types::OperationResult;
+    use crate::webscenarios::webscenarios::{find_managed_web_scenarios, ZabbixManagedWebScenario};
+
+    #[derive(PartialEq, Debug)]
+    pub enum CheckState {
+        Failing,
+        NeverChecked,
+        Ok,
+        Disabled
+    }
+
+    /// Last known result of managed web scenario
+    pub struct CheckHealth {
+        pub host: String,
+        pub url: String,
+        pub state: CheckState,
+        /// Unix time of the last check
+        pub last_check: Option<u64>
+    }
+
+    pub fn find_checks_health(client: &Client, config: &Config) -> OperationResult<Vec<CheckHealth>> {
+        let api_endpoint = &config.zabbix.api.endpoint;
+
+        match open_api_session(client, &config.zabbix.api) {
+            Ok(auth_token) => {
+                let result = find_managed_web_scenarios(client, api_endpoint, &auth_token)
+                    .and_then(|web_scenarios| {
+                        let item_keys = web_scenarios.iter()
+                                            .map(|web_scenario| get_fail_item_key(&web_scenario.name)).collect();
+
+                        let web_items = find_web_items(client, api_endpoint, &auth_token, item_keys)?;
+                        let triggers = find_managed_triggers(client, api_endpoint, &auth_token)?;
+
+                        Ok(get_checks_health(&web_scenarios, &web_items, &triggers))
+                    });
+
+                if close_api_session(client, &config.zabbix.api, &auth_token).is_err() {
+                    warn!("unable to close api session");
+                }
+
+                result
+            }
+            Err(_) => {
+                error!("unable to login");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn get_fail_item_key(scenario_name: &str) -> String {
+        format!("web.test.fail[{}]", scenario_name)
+    }
+
+    /// Scenario is failing when its last check failed or its trigger is in problem state.
+    /// Failing checks go first.
+    pub fn get_checks_health(web_scenarios: &[ZabbixManagedWebScenario], web_items: &[ZabbixWebItem],
+                             triggers: &[ZabbixTrigger]) -> Vec<CheckHealth> {
+        let mut checks = vec![];
+
+        for web_scenario in web_scenarios {
+            let url = web_scenario.steps.first().map(|step| step.url.to_string()).unwrap_or_default();
+            let fail_item_key = get_fail_item_key(&web_scenario.name);
+
+            for host in &web_scenario.hosts {
+                let fail_item = web_items.iter()
+                    .find(|item| item.hostid == host.hostid && item.key_ == fail_item_key);
+
+                let last_check = fail_item.and_then(|item| item.lastclock.parse::<u64>().ok())
+                                          .filter(|lastclock| *lastclock > 0);
+
+                let has_problem = triggers.iter().any(|trigger|
+                    trigger.url == url && trigger.value == "1" &&
+                    trigger.hosts.iter().any(|trigger_host| trigger_host.hostid == host.hostid)
+                );
+
+                let last_check_failed = fail_item.map(|item| !item.lastvalue.is_empty() && item.lastvalue != "0")
+                                                 .unwrap_or(false);
+
+                let state = if web_scenario.status == "1" {
+                    CheckState::Disabled
+
+                } else if has_problem || (last_check.is_some() && last_check_failed) {
+                    CheckState::Failing
+
+                } else if last_check.is_none() {
+                    CheckState::NeverChecked
+
+                } else {
+                    CheckState::Ok
+                };
+
+                checks.push(CheckHealth {
+                    host: host.host.to_string(),
+                    url: url.to_string(),
+                    state,
+                    last_check
+                });
+            }
+        }
+
+        checks.sort_by(|a, b| get_state_order(&a.state).cmp(&get_state_order(&b.state))
+            .then_with(|| (&a.host, &a.url).cmp(&(&b.host, &b.url))));
+
+        checks
+    }
+
+    fn get_state_order(state: &CheckState) -> u8 {
+        match state {
+            CheckState::Failing => 0,
+            CheckState::NeverChecked => 1,
+            CheckState::Ok => 2,
+            CheckState::Disabled => 3
+        }
+    }
+
+    /// `now` - unix time, last checks are shown relative to it
+    pub fn render_status(checks: &[CheckHealth], now: u64) -> String {
+        let mut output = format!("{:<14} {:<30} {:<50} LAST CHECK\n", "STATE", "HOST", "URL");
+
+        let mut counts = [0; 4];
+
+        for check in checks {
+            let state = match check.state {
+                CheckState::Failing => "failing",
+                CheckState::NeverChecked => "never checked",
+                CheckState::Ok => "ok",
+                CheckState::Disabled => "disabled"
+            };
+
+            counts[get_state_order(&check.state) as usize] += 1;
+
+            let last_check = match check.last_check {
+                Some(last_check) => format_age(now.saturating_sub(last_check)),
+                None => "-".to_string()
+            };
+
+            output.push_str(&format!("{:<14} {:<30} {:<50} {}\n", state, check.host, check.url, last_check));
+        }
+
+        output.push_str(&format!("\nfailing: {}, never checked: {}, ok: {}, disabled: {}\n",
+                                 counts[0], counts[1], counts[2], counts[3]));
+
+        output
+    }
+
+    fn format_age(seconds: u64) -> String {
+        match seconds {
+            0..=59 => format!("{}s ago", seconds),
+            60..=3599 => format!("{}m ago", seconds / 60),
+            3600..=86399 => format!("{}h ago", seconds / 3600),
+            _ => format!("{}d ago", seconds / 86400)
+        }
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod status_tests {
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::items::items::ZabbixWebItem;
+    use crate::status::status::{CheckState, get_checks_health, render_status};
+    use crate::triggers::triggers::ZabbixTrigger;
+    use crate::webscenarios::webscenarios::{ZabbixManagedWebScenario, ZabbixWebScenarioStep};
+
+    fn get_host() -> ZabbixHost {
+        ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(), maintenance_status: "0".to_string(),
+                     status: "0".to_string(), proxy_hostid: "0".to_string() }
+    }
+
+    fn get_web_scenario(url: &str) -> ZabbixManagedWebScenario {
+        ZabbixManagedWebScenario {
+            httptestid: "301".to_string(),
+            name: format!("Check index page '{}'", url),
+            status: "0".to_string(),
+            hosts: vec![get_host()],
+            steps: vec![ZabbixWebScenarioStep { url: url.to_string() }]
+        }
+    }
+
+    fn get_fail_item(url: &str, lastvalue: &str, lastclock: &str) -> ZabbixWebItem {
+        ZabbixWebItem {
+            itemid: "201".to_string(),
+            hostid: "10001".to_string(),
+            key_: format!("web.test.fail[Check index page '{}']", url),
+            lastvalue: lastvalue.to_string(),
+            lastclock: lastclock.to_string()
+        }
+    }
+
+    #[test]
+    fn failing_and_never_checked_scenarios_should_be_detected() {
+        let web_scenarios = vec![
+            get_web_scenario("https://a.example.com"),
+            get_web_scenario("https://b.example.com"),
+            get_web_scenario("https://c.example.com"),
+            get_web_scenario("https://d.example.com")
+        ];
+
+        let web_items = vec![
+            get_fail_item("https://a.example.com", "0", "1000"),
+            get_fail_item("https://b.example.com", "1", "1000"),
+            get_fail_item("https://c.example.com", "", "0"),
+            get_fail_item("https://d.example.com", "0", "1000")
+        ];
+
+        let triggers = vec![
+            ZabbixTrigger {
+                description: "Site 'https://d.example.com' is unavailable".to_string(),
+                url: "https://d.example.com".to_string(),
+                status: "0".to_string(),
+                value: "1".to_string(),
+                hosts: vec![get_host()]
+            }
+        ];
+
+        let checks = get_checks_health(&web_scenarios, &web_items, &triggers);
+
+        let states: Vec<(&str, &CheckState)> = checks.iter()
+            .map(|check| (check.url.as_str(), &check.state)).collect();
+
+        assert_eq!(states, vec![
+            ("https://b.example.com", &CheckState::Failing),
+            ("https://d.example.com", &CheckState::Failing),
+            ("https://c.example.com", &CheckState::NeverChecked),
+            ("https://a.example.com", &CheckState::Ok)
+        ]);
+
+        let output = render_status(&checks, 1000 + 3 * 60);
+
+        assert!(output.contains("3m ago"));
+        assert!(output.ends_with("failing: 2, never checked: 1, ok: 1, disabled: 0\n"));
+    }
+}
@@ -0,0 +1,184 @@
+pub mod syslog {
+    use std::error::Error;
+    use std::fs;
+    use std::io::Write;
+    use std::net::{TcpStream, UdpSocket};
+    #[cfg(unix)]
+    use std::os::unix::net::UnixDatagram;
+    use std::sync::Mutex;
+
+    use chrono::{SecondsFormat, Utc};
+    use log::{Level, LevelFilter, Record};
+    use log4rs::append::Append;
+
+    use crate::config::config::SyslogConfig;
+    use crate::errors::errors::OperationError;
+    use crate::logging::logging::get_logging_level_from_string;
+    use crate::types::types::EmptyResult;
+
+    const UDP_PREFIX: &str = "udp://";
+    const TCP_PREFIX: &str = "tcp://";
+    const UNIX_PREFIX: &str = "unix://";
+
+    /// Records are sent only after syslog is set up from config
+    static SYSLOG_SENDER: Mutex<Option<SyslogSender>> = Mutex::new(None);
+
+    enum SyslogTransport {
+        Udp(UdpSocket),
+        /// Octet-counting framing (RFC 6587)
+        Tcp(TcpStream),
+        #[cfg(unix)]
+        Unix(UnixDatagram)
+    }
+
+    struct SyslogSender {
+        transport: SyslogTransport,
+        facility: u8,
+        app_name: String,
+        host_name: String,
+        max_level: LevelFilter
+    }
+
+    /// Part of logging config from start, sends nothing until `init_syslog`
+    #[derive(Debug)]
+    pub struct SyslogAppender;
+
+    impl Append for SyslogAppender {
+        fn append(&self, record: &Record) -> Result<(), Box<dyn Error + Sync + Send>> {
+            let mut sender = SYSLOG_SENDER.lock().unwrap();
+
+            match sender.as_mut() {
+                Some(sender) if record.level() <= sender.max_level => {
+                    let message = format_syslog_message(
+                        sender.facility, get_severity(record.level()), &get_timestamp(),
+                        &sender.host_name, &sender.app_name, std::process::id(),
+                        &format!("[{}] {}", record.target(), record.args())
+                    );
+
+                    match &mut sender.transport {
+                        SyslogTransport::Udp(socket) => { socket.send(message.as_bytes())?; }
+                        SyslogTransport::Tcp(stream) => {
+                            write!(stream, "{} {}", message.len(), message)?;
+                        }
+                        #[cfg(unix)]
+                        SyslogTransport::Unix(socket) => { socket.send(message.as_bytes())?; }
+                    }
+
+                    Ok(())
+                }
+                _ => Ok(())
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Connects to syslog: `udp://host:514`, `tcp://host:601` or `unix:///dev/log`
+    pub fn init_syslog(syslog_config: &SyslogConfig) -> EmptyResult {
+        let facility = match get_facility_code(&syslog_config.facility) {
+            Some(facility) => facility,
+            None => {
+                error!("unknown syslog facility '{}'", syslog_config.facility);
+                return Err(OperationError::Error)
+            }
+        };
+
+        let transport = match get_transport(&syslog_config.address) {
+            Ok(transport) => transport,
+            Err(e) => {
+                error!("unable to connect to syslog '{}': {}", syslog_config.address, e);
+                return Err(OperationError::Error)
+            }
+        };
+
+        *SYSLOG_SENDER.lock().unwrap() = Some(SyslogSender {
+            transport,
+            facility,
+            app_name: syslog_config.app_name.to_string(),
+            host_name: get_host_name(),
+            max_level: get_logging_level_from_string(&syslog_config.level)
+        });
+
+        info!("logs are sent to syslog '{}'", syslog_config.address);
+
+        Ok(())
+    }
+
+    /// RFC 5424 message without structured data
+    pub fn format_syslog_message(facility: u8, severity: u8, timestamp: &str, host_name: &str,
+                                 app_name: &str, pid: u32, message: &str) -> String {
+        format!("<{}>1 {} {} {} {} - - {}", facility as u16 * 8 + severity as u16,
+                timestamp, host_name, app_name, pid, message)
+    }
+
+    pub fn get_severity(level: Level) -> u8 {
+        match level {
+            Level::Error => 3,
+            Level::Warn => 4,
+            Level::Info => 6,
+            Level::Debug | Level::Trace => 7
+        }
+    }
+
+    pub fn get_facility_code(facility: &str) -> Option<u8> {
+        let code = match facility {
+            "kern" => 0,
+            "user" => 1,
+            "mail" => 2,
+            "daemon" => 3,
+            "auth" => 4,
+            "syslog" => 5,
+            "lpr" => 6,
+            "news" => 7,
+            "uucp" => 8,
+            "cron" => 9,
+            "authpriv" => 10,
+            "ftp" => 11,
+            _ => 16 + facility.strip_prefix("local")?.parse::<u8>().ok().filter(|number| *number < 8)?
+        };
+
+        Some(code)
+    }
+
+    fn get_transport(address: &str) -> std::io::Result<SyslogTransport> {
+        if let Some(address) = address.strip_prefix(UDP_PREFIX) {
+            let socket = UdpSocket::bind("0.0.0.0:0")?;
+            socket.connect(address)?;
+            Ok(SyslogTransport::Udp(socket))
+
+        } else if let Some(address) = address.strip_prefix(TCP_PREFIX) {
+            Ok(SyslogTransport::Tcp(TcpStream::connect(address)?))
+
+        } else {
+            get_unix_transport(address.strip_prefix(UNIX_PREFIX).unwrap_or(address))
+        }
+    }
+
+    #[cfg(unix)]
+    fn get_unix_transport(path: &str) -> std::io::Result<SyslogTransport> {
+        let socket = UnixDatagram::unbound()?;
+        socket.connect(path)?;
+        Ok(SyslogTransport::Unix(socket))
+    }
+
+    #[cfg(not(unix))]
+    fn get_unix_transport(path: &str) -> std::io::Result<SyslogTransport> {
+        Err(std::io::Error::new(std::io::ErrorKind::Unsupported,
+                                format!("unix socket '{}' isn't supported, use udp:// or tcp://", path)))
+    }
+
+    fn get_timestamp() -> String {
+        Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+    }
+
+    /// `-` is nil value in RFC 5424
+    fn get_host_name() -> String {
+        fs::read_to_string("/proc/sys/kernel/hostname")
+            .or_else(|_| fs::read_to_string("/etc/hostname"))
+            .map(|host_name| host_name.trim().to_string())
+            .ok()
+            .or_else(|| std::env::var("COMPUTERNAME").ok())
+            .filter(|host_name| !host_name.is_empty())
+            .unwrap_or_else(|| "-".to_string())
+    }
+}
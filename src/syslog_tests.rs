@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod syslog_tests {
+    use log::Level;
+
+    use crate::syslog::syslog::{format_syslog_message, get_facility_code, get_severity};
+
+    #[test]
+    fn message_should_be_formatted_by_rfc5424() {
+        let message = format_syslog_message(get_facility_code("daemon").unwrap(), get_severity(Level::Error),
+                                            "2021-03-01T12:00:00.000Z", "zabbix-01", "wszl", 4242,
+                                            "[wszl::generator] unable to create trigger");
+
+        assert_eq!(message,
+                   "<27>1 2021-03-01T12:00:00.000Z zabbix-01 wszl 4242 - - [wszl::generator] unable to create trigger");
+    }
+
+    #[test]
+    fn facility_names_should_be_mapped_to_codes() {
+        assert_eq!(get_facility_code("user"), Some(1));
+        assert_eq!(get_facility_code("local0"), Some(16));
+        assert_eq!(get_facility_code("local7"), Some(23));
+        assert_eq!(get_facility_code("local8"), None);
+        assert_eq!(get_facility_code("apps"), None);
+    }
+}
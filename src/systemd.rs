@@ -0,0 +1,37 @@
+pub mod systemd {
+    #[cfg(unix)]
+    use sd_notify::NotifyState;
+
+    /// `Type=notify` readiness, no-op when wszl isn't started by systemd
+    pub fn notify_ready() {
+        #[cfg(unix)]
+        send_notification(&[NotifyState::Ready]);
+    }
+
+    pub fn notify_stopping() {
+        #[cfg(unix)]
+        send_notification(&[NotifyState::Stopping]);
+    }
+
+    /// Resets systemd watchdog timer (`WatchdogSec`)
+    pub fn notify_watchdog() {
+        #[cfg(unix)]
+        send_notification(&[NotifyState::Watchdog]);
+    }
+
+    /// Shown by `systemctl status`
+    pub fn notify_status(status: &str) {
+        #[cfg(unix)]
+        send_notification(&[NotifyState::Status(status)]);
+
+        #[cfg(not(unix))]
+        let _ = status;
+    }
+
+    #[cfg(unix)]
+    fn send_notification(states: &[NotifyState]) {
+        if let Err(e) = sd_notify::notify(false, states) {
+            warn!("unable to notify systemd: {}", e);
+        }
+    }
+}
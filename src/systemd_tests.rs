@@ -0,0 +1,32 @@
+#[cfg(all(test, unix))]
+mod systemd_tests {
+    use std::env;
+    use std::fs;
+    use std::os::unix::net::UnixDatagram;
+
+    use crate::systemd::systemd::{notify_ready, notify_status};
+
+    #[test]
+    fn readiness_and_status_should_be_sent_to_notify_socket() {
+        let socket_path = env::temp_dir().join(format!("wszl-test-notify-{}.sock", std::process::id()));
+
+        let socket = UnixDatagram::bind(&socket_path).unwrap();
+
+        env::set_var("NOTIFY_SOCKET", &socket_path);
+
+        notify_ready();
+        notify_status("next run in 60s");
+
+        env::remove_var("NOTIFY_SOCKET");
+
+        let mut buffer = [0; 128];
+
+        let size = socket.recv(&mut buffer).unwrap();
+        assert_eq!("READY=1\n", String::from_utf8_lossy(&buffer[..size]));
+
+        let size = socket.recv(&mut buffer).unwrap();
+        assert_eq!("STATUS=next run in 60s\n", String::from_utf8_lossy(&buffer[..size]));
+
+        fs::remove_file(&socket_path).unwrap();
+    }
+}
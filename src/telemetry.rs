@@ -0,0 +1,224 @@
+pub mod telemetry {
+    use std::cell::RefCell;
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Mutex;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use serde_json::{json, Value};
+
+    use crate::config::config::TracingConfig;
+    use crate::errors::errors::OperationError;
+    use crate::types::types::EmptyResult;
+
+    const OTLP_TRACES_PATH: &str = "/v1/traces";
+
+    const SPAN_KIND_INTERNAL: u8 = 1;
+    const SPAN_KIND_CLIENT: u8 = 3;
+
+    const STATUS_CODE_OK: u8 = 1;
+    const STATUS_CODE_ERROR: u8 = 2;
+
+    static TRACE_ID: Mutex<Option<String>> = Mutex::new(None);
+
+    static FINISHED_SPANS: Mutex<Vec<FinishedSpan>> = Mutex::new(vec![]);
+
+    static ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    thread_local! {
+        /// Ids of currently open spans, the last one is the parent for new spans
+        static SPAN_STACK: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+    }
+
+    struct FinishedSpan {
+        span_id: String,
+        parent_span_id: String,
+        name: String,
+        kind: u8,
+        start_time: u128,
+        end_time: u128,
+        attributes: Vec<(String, String)>,
+        failed: bool
+    }
+
+    /// Span is finished when guard goes out of scope.
+    pub struct SpanGuard {
+        span_id: String,
+        parent_span_id: String,
+        name: String,
+        kind: u8,
+        start_time: u128,
+        attributes: Vec<(String, String)>,
+        failed: bool
+    }
+
+    impl SpanGuard {
+        pub fn set_attribute(&mut self, key: &str, value: &str) {
+            self.attributes.push((key.to_string(), value.to_string()));
+        }
+
+        pub fn set_error(&mut self) {
+            self.failed = true;
+        }
+    }
+
+    impl Drop for SpanGuard {
+        fn drop(&mut self) {
+            SPAN_STACK.with(|stack| {
+                let mut stack = stack.borrow_mut();
+                if let Some(position) = stack.iter().rposition(|id| id == &self.span_id) {
+                    stack.remove(position);
+                }
+            });
+
+            FINISHED_SPANS.lock().unwrap().push(FinishedSpan {
+                span_id: self.span_id.to_string(),
+                parent_span_id: self.parent_span_id.to_string(),
+                name: self.name.to_string(),
+                kind: self.kind,
+                start_time: self.start_time,
+                end_time: get_unix_time_nanos(),
+                attributes: self.attributes.drain(..).collect(),
+                failed: self.failed
+            });
+        }
+    }
+
+    /// Starts span for a phase of the run (login, item search, item processing, etc.)
+    pub fn start_span(name: &str) -> SpanGuard {
+        start_span_with_kind(name, SPAN_KIND_INTERNAL)
+    }
+
+    /// Starts span for an outgoing call (Zabbix JSON-RPC request)
+    pub fn start_client_span(name: &str) -> SpanGuard {
+        start_span_with_kind(name, SPAN_KIND_CLIENT)
+    }
+
+    /// Starts span with explicit parent, for work moved to another thread
+    pub fn start_child_span(name: &str, parent_span_id: &str) -> SpanGuard {
+        create_span(name, SPAN_KIND_INTERNAL, parent_span_id.to_string())
+    }
+
+    pub fn get_current_span_id() -> String {
+        SPAN_STACK.with(|stack| stack.borrow().last().cloned().unwrap_or_default())
+    }
+
+    fn start_span_with_kind(name: &str, kind: u8) -> SpanGuard {
+        create_span(name, kind, get_current_span_id())
+    }
+
+    fn create_span(name: &str, kind: u8, parent_span_id: String) -> SpanGuard {
+        let span_id = generate_id(1);
+
+        SPAN_STACK.with(|stack| stack.borrow_mut().push(span_id.to_string()));
+
+        SpanGuard {
+            span_id,
+            parent_span_id,
+            name: name.to_string(),
+            kind,
+            start_time: get_unix_time_nanos(),
+            attributes: vec![],
+            failed: false
+        }
+    }
+
+    /// Exports collected spans over OTLP/HTTP (JSON encoding), if endpoint is configured.
+    /// Spans of the next run (daemon mode) belong to a new trace.
+    pub fn export_spans(client: &reqwest::blocking::Client, tracing_config: &TracingConfig) {
+        let spans: Vec<FinishedSpan> = FINISHED_SPANS.lock().unwrap().drain(..).collect();
+
+        if let Some(endpoint) = &tracing_config.otlp_endpoint {
+            if !spans.is_empty() {
+                let url = format!("{}{}", endpoint.trim_end_matches('/'), OTLP_TRACES_PATH);
+
+                let payload = get_otlp_payload(&tracing_config.service_name, &spans);
+
+                if send_spans(client, &url, &payload).is_ok() {
+                    debug!("{} spans have been exported to '{}'", spans.len(), url)
+                }
+            }
+        }
+
+        *TRACE_ID.lock().unwrap() = None;
+    }
+
+    fn send_spans(client: &reqwest::blocking::Client, url: &str, payload: &Value) -> EmptyResult {
+        match client.post(url).json(payload).send() {
+            Ok(response) => {
+                if response.status().is_success() {
+                    Ok(())
+
+                } else {
+                    error!("unexpected otlp collector response code {}", response.status());
+                    Err(OperationError::Error)
+                }
+            }
+            Err(e) => {
+                error!("unable to export spans to '{}': {}", url, e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn get_otlp_payload(service_name: &str, spans: &[FinishedSpan]) -> Value {
+        let trace_id = get_trace_id();
+
+        let otlp_spans: Vec<Value> = spans.iter().map(|span| {
+            let attributes: Vec<Value> = span.attributes.iter()
+                .map(|(key, value)| get_otlp_attribute(key, value))
+                .collect();
+
+            json!({
+                "traceId": trace_id,
+                "spanId": span.span_id,
+                "parentSpanId": span.parent_span_id,
+                "name": span.name,
+                "kind": span.kind,
+                "startTimeUnixNano": span.start_time.to_string(),
+                "endTimeUnixNano": span.end_time.to_string(),
+                "attributes": attributes,
+                "status": {
+                    "code": if span.failed { STATUS_CODE_ERROR } else { STATUS_CODE_OK }
+                }
+            })
+        }).collect();
+
+        json!({
+            "resourceSpans": [{
+                "resource": {
+                    "attributes": [get_otlp_attribute("service.name", service_name)]
+                },
+                "scopeSpans": [{
+                    "scope": { "name": "wszl" },
+                    "spans": otlp_spans
+                }]
+            }]
+        })
+    }
+
+    fn get_otlp_attribute(key: &str, value: &str) -> Value {
+        json!({ "key": key, "value": { "stringValue": value } })
+    }
+
+    fn get_trace_id() -> String {
+        let mut trace_id = TRACE_ID.lock().unwrap();
+
+        trace_id.get_or_insert_with(|| generate_id(2)).to_string()
+    }
+
+    /// Generates random hex id of `parts` * 8 bytes.
+    fn generate_id(parts: usize) -> String {
+        (0..parts).map(|_| {
+            let mut hasher = RandomState::new().build_hasher();
+            hasher.write_u128(get_unix_time_nanos());
+            hasher.write_u64(ID_COUNTER.fetch_add(1, Ordering::Relaxed));
+            format!("{:016x}", hasher.finish())
+        }).collect()
+    }
+
+    fn get_unix_time_nanos() -> u128 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos()
+    }
+}
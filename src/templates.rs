@@ -0,0 +1,224 @@
+pub mod templates {
+    use std::collections::HashMap;
+    use std::error::Error;
+
+    use serde::Serialize;
+    use tera::{Context, Tera, Value};
+
+    use crate::config::config::TemplatesConfig;
+    use crate::errors::errors::OperationError;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::types::types::{OperationResult, StringResult};
+    use crate::zabbix::zabbix::quote_key_param;
+
+    const SCENARIO_NAME_TEMPLATE: &str = "scenario-name";
+    const STEP_NAME_TEMPLATE: &str = "step-name";
+    const TRIGGER_NAME_TEMPLATE: &str = "trigger-name";
+    const TRIGGER_EXPRESSION_TEMPLATE: &str = "trigger-expression";
+    const TRIGGER_COMMENTS_TEMPLATE: &str = "trigger-comments";
+
+    /// Zabbix limit of trigger name
+    const TRIGGER_NAME_MAX_LENGTH: usize = 255;
+
+    /// Names and definitions of objects created for url
+    #[derive(Clone)]
+    pub struct ObjectNames {
+        pub scenario: String,
+        pub step: String,
+        pub trigger: String,
+        pub trigger_expression: String,
+        pub trigger_comments: String
+    }
+
+    #[derive(Serialize)]
+    struct HostProperties<'a> {
+        host: &'a str,
+        hostid: &'a str
+    }
+
+    /// Parts of url, empty when url can't be parsed
+    #[derive(Serialize, Default)]
+    struct UrlParts {
+        scheme: String,
+        host: String,
+        port: String,
+        path: String
+    }
+
+    /// Tera templates of object names. Templates get `host` (`host.host`, `host.hostid`), `url`,
+    /// `url_parts` (`scheme`, `host`, `port`, `path`), `groups` (named groups of item key pattern),
+    /// `origin_host` (host of item, differs from `host` for checks on central host) and `vars` from config.
+    /// Step and trigger templates get rendered `scenario` and `step` names as well.
+    /// Filter `key_param` quotes value for item key parameter, filter `slug` makes ascii slug of value.
+    /// Rendered names are sanitized, see `sanitize_name`.
+    pub struct NameTemplates {
+        tera: Tera,
+        vars: HashMap<String, String>,
+        max_name_length: usize
+    }
+
+    impl NameTemplates {
+        pub fn new(templates_config: &TemplatesConfig) -> OperationResult<NameTemplates> {
+            let mut tera = Tera::default();
+            tera.register_filter("key_param", key_param_filter);
+            tera.register_filter("slug", slug_filter);
+
+            let templates = vec![
+                (SCENARIO_NAME_TEMPLATE, &templates_config.scenario_name),
+                (STEP_NAME_TEMPLATE, &templates_config.step_name),
+                (TRIGGER_NAME_TEMPLATE, &templates_config.trigger_name),
+                (TRIGGER_EXPRESSION_TEMPLATE, &templates_config.trigger_expression),
+                (TRIGGER_COMMENTS_TEMPLATE, &templates_config.trigger_comments)
+            ];
+
+            if let Err(e) = tera.add_raw_templates(templates) {
+                error!("invalid template: {}", get_error_details(&e));
+                return Err(OperationError::Error)
+            }
+
+            Ok(NameTemplates {
+                tera,
+                vars: templates_config.vars.clone(),
+                max_name_length: templates_config.max_name_length
+            })
+        }
+
+        /// `scenario_name` replaces rendered scenario name, i.e. when it's set by url script
+        pub fn get_object_names(&self, host: &ZabbixHost, url: &str, groups: &HashMap<String, String>,
+                                scenario_name: Option<&str>) -> OperationResult<ObjectNames> {
+            self.render_object_names(host, None, url, groups, scenario_name)
+        }
+
+        /// Names of checks created on central host for url of `origin_host`, scenario name gets the origin host
+        /// in parentheses, so the same url of several hosts doesn't collide
+        pub fn get_central_object_names(&self, central_host: &ZabbixHost, origin_host: &ZabbixHost, url: &str,
+                                        groups: &HashMap<String, String>,
+                                        scenario_name: Option<&str>) -> OperationResult<ObjectNames> {
+            self.render_object_names(central_host, Some(origin_host), url, groups, scenario_name)
+        }
+
+        fn render_object_names(&self, host: &ZabbixHost, origin_host: Option<&ZabbixHost>, url: &str,
+                               groups: &HashMap<String, String>,
+                               scenario_name: Option<&str>) -> OperationResult<ObjectNames> {
+            let origin = origin_host.unwrap_or(host);
+
+            let mut context = Context::new();
+            context.insert("host", &HostProperties { host: &host.host, hostid: &host.hostid });
+            context.insert("origin_host", &HostProperties { host: &origin.host, hostid: &origin.hostid });
+            context.insert("url", url);
+            context.insert("url_parts", &get_url_parts(url));
+            context.insert("groups", groups);
+            context.insert("vars", &self.vars);
+
+            let mut scenario = match scenario_name {
+                Some(scenario_name) => scenario_name.to_string(),
+                None => self.render(SCENARIO_NAME_TEMPLATE, &context)?
+            };
+
+            if let Some(origin_host) = origin_host {
+                scenario = format!("{} ({})", scenario, origin_host.host);
+            }
+
+            let scenario = sanitize_name(&scenario, self.max_name_length);
+
+            context.insert("scenario", &scenario);
+
+            let step = sanitize_name(&self.render(STEP_NAME_TEMPLATE, &context)?, self.max_name_length);
+            context.insert("step", &step);
+
+            Ok(ObjectNames {
+                trigger: sanitize_name(&self.render(TRIGGER_NAME_TEMPLATE, &context)?, TRIGGER_NAME_MAX_LENGTH),
+                trigger_expression: self.render(TRIGGER_EXPRESSION_TEMPLATE, &context)?,
+                trigger_comments: self.render(TRIGGER_COMMENTS_TEMPLATE, &context)?,
+                scenario,
+                step
+            })
+        }
+
+        fn render(&self, template_name: &str, context: &Context) -> StringResult {
+            match self.tera.render(template_name, context) {
+                Ok(value) => Ok(value.trim().to_string()),
+                Err(e) => {
+                    error!("unable to render template '{}': {}", template_name, get_error_details(&e));
+                    Err(OperationError::Error)
+                }
+            }
+        }
+    }
+
+    fn key_param_filter(value: &Value, _: &HashMap<String, Value>) -> tera::Result<Value> {
+        match value.as_str() {
+            Some(param) => Ok(Value::String(quote_key_param(param))),
+            None => Err(tera::Error::msg("filter `key_param` expects string"))
+        }
+    }
+
+    fn slug_filter(value: &Value, _: &HashMap<String, Value>) -> tera::Result<Value> {
+        match value.as_str() {
+            Some(value) => Ok(Value::String(get_slug(value))),
+            None => Err(tera::Error::msg("filter `slug` expects string"))
+        }
+    }
+
+    /// Lowercase ascii letters and digits, anything else becomes single `-`
+    pub fn get_slug(value: &str) -> String {
+        let mut slug = String::new();
+
+        for c in value.chars() {
+            if c.is_ascii_alphanumeric() {
+                slug.push(c.to_ascii_lowercase());
+            } else if !slug.is_empty() && !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+
+        slug.trim_end_matches('-').to_string()
+    }
+
+    /// Control characters (i.e. new lines of multi-line templates) become spaces and trailing backslashes are removed,
+    /// item key parameter with the name can't be quoted otherwise.
+    /// Names longer than `max_length` characters are cut and get hash of the whole name, so they stay unique.
+    pub fn sanitize_name(name: &str, max_length: usize) -> String {
+        let name: String = name.chars().map(|c| if c.is_control() { ' ' } else { c }).collect();
+        let name = name.trim().trim_end_matches('\\');
+
+        if name.chars().count() <= max_length {
+            return name.to_string()
+        }
+
+        let suffix = format!("~{:08x}", get_name_hash(name));
+        let prefix: String = name.chars().take(max_length.saturating_sub(suffix.len())).collect();
+
+        format!("{}{}", prefix.trim_end().trim_end_matches('\\'), suffix)
+    }
+
+    /// FNV-1a, names have to be the same in every run and build
+    fn get_name_hash(name: &str) -> u32 {
+        name.bytes().fold(0x811c9dc5, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x01000193))
+    }
+
+    fn get_url_parts(url: &str) -> UrlParts {
+        match reqwest::Url::parse(url) {
+            Ok(parsed_url) => UrlParts {
+                scheme: parsed_url.scheme().to_string(),
+                host: parsed_url.host_str().unwrap_or_default().to_string(),
+                port: parsed_url.port_or_known_default().map(|port| port.to_string()).unwrap_or_default(),
+                path: parsed_url.path().to_string()
+            },
+            Err(_) => UrlParts::default()
+        }
+    }
+
+    /// Tera error with its causes, the top level message doesn't explain much
+    fn get_error_details(e: &tera::Error) -> String {
+        let mut details = e.to_string();
+        let mut source = e.source();
+
+        while let Some(cause) = source {
+            details.push_str(&format!(": {}", cause));
+            source = cause.source();
+        }
+
+        details
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod templates_tests {
+    use std::collections::HashMap;
+
+    use crate::config::config::TemplatesConfig;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::templates::templates::{get_slug, NameTemplates, sanitize_name};
+
+    fn get_host() -> ZabbixHost {
+        ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(),
+                     maintenance_status: "0".to_string(), status: "0".to_string(),
+                     proxy_hostid: "0".to_string() }
+    }
+
+    #[test]
+    fn default_templates_should_keep_names() {
+        let templates = NameTemplates::new(&TemplatesConfig::default()).unwrap();
+
+        let names = templates.get_object_names(&get_host(), "https://example.com", &HashMap::new(), None).unwrap();
+
+        assert_eq!(names.scenario, "Check index page 'https://example.com'");
+        assert_eq!(names.step, "Get page");
+        assert_eq!(names.trigger, "Site 'https://example.com' is unavailable");
+        assert_eq!(names.trigger_expression,
+                   "last(/web-01/web.test.fail[Check index page 'https://example.com'])<>0");
+        assert_eq!(names.trigger_comments, "");
+    }
+
+    #[test]
+    fn scenario_name_with_ipv6_url_should_be_quoted_in_expression() {
+        let templates = NameTemplates::new(&TemplatesConfig::default()).unwrap();
+
+        let names = templates.get_object_names(&get_host(), "https://[2001:db8::1]:8443/path",
+                                               &HashMap::new(), None).unwrap();
+
+        assert_eq!(names.trigger_expression,
+                   "last(/web-01/web.test.fail[\"Check index page 'https://[2001:db8::1]:8443/path'\"])<>0");
+    }
+
+    #[test]
+    fn templates_should_get_url_parts_and_vars() {
+        let mut templates_config = TemplatesConfig {
+            scenario_name: "{{ vars.team }}: {{ url_parts.host }}{{ url_parts.path }}".to_string(),
+            trigger_comments: "Owner: {{ vars.team }}, port {{ url_parts.port }}".to_string(),
+            ..TemplatesConfig::default()
+        };
+        templates_config.vars.insert("team".to_string(), "shop".to_string());
+
+        let templates = NameTemplates::new(&templates_config).unwrap();
+
+        let names = templates.get_object_names(&get_host(), "https://example.com/cart", &HashMap::new(), None).unwrap();
+
+        assert_eq!(names.scenario, "shop: example.com/cart");
+        assert_eq!(names.trigger_expression, "last(/web-01/web.test.fail[shop: example.com/cart])<>0");
+        assert_eq!(names.trigger_comments, "Owner: shop, port 443");
+    }
+
+    #[test]
+    fn invalid_template_should_be_rejected() {
+        let templates_config = TemplatesConfig {
+            scenario_name: "{{ url ".to_string(),
+            ..TemplatesConfig::default()
+        };
+
+        assert!(NameTemplates::new(&templates_config).is_err());
+    }
+
+    #[test]
+    fn templates_should_get_named_groups_of_item_key() {
+        let templates_config = TemplatesConfig {
+            trigger_name: "{{ groups.label | default(value=url) }} is unavailable".to_string(),
+            ..TemplatesConfig::default()
+        };
+
+        let templates = NameTemplates::new(&templates_config).unwrap();
+
+        let mut groups = HashMap::new();
+        groups.insert("label".to_string(), "Online shop".to_string());
+
+        let names = templates.get_object_names(&get_host(), "https://shop.example.com", &groups, None).unwrap();
+        assert_eq!(names.trigger, "Online shop is unavailable");
+
+        let names = templates.get_object_names(&get_host(), "https://shop.example.com", &HashMap::new(), None)
+                             .unwrap();
+        assert_eq!(names.trigger, "https://shop.example.com is unavailable");
+    }
+
+    #[test]
+    fn long_names_should_be_shortened_with_hash() {
+        let url = "https://shop.example.com/catalog/very/long/path/to/product?with=query&and=more";
+
+        let templates = NameTemplates::new(&TemplatesConfig::default()).unwrap();
+        let names = templates.get_object_names(&get_host(), url, &HashMap::new(), None).unwrap();
+
+        assert_eq!(names.scenario.chars().count(), 64);
+        assert!(names.scenario.starts_with("Check index page 'https://shop.example.com/catalog/very~"));
+        assert_eq!(names.trigger_expression, format!("last(/web-01/web.test.fail[{}])<>0", names.scenario));
+
+        // Same prefix, different hash
+        let other_names = templates.get_object_names(&get_host(), &format!("{}2", url), &HashMap::new(), None)
+                                   .unwrap();
+        assert_ne!(names.scenario, other_names.scenario);
+
+        // Hash is stable across runs
+        assert_eq!(sanitize_name(&"x".repeat(20), 16), "xxxxxxx~5af00795");
+    }
+
+    #[test]
+    fn names_should_be_safe_for_item_key_parameter() {
+        assert_eq!(sanitize_name("Check 'https://example.com/a\\'", 64), "Check 'https://example.com/a\\'");
+        assert_eq!(sanitize_name("Check https://example.com/a\\", 64), "Check https://example.com/a");
+        assert_eq!(sanitize_name("Check\nsite\t", 64), "Check site");
+        assert_eq!(sanitize_name("Kontrola 'https://příklad.cz/čeština'", 20), "Kontrola 'h~bdd38dd2");
+    }
+
+    #[test]
+    fn slug_should_contain_only_ascii_letters_and_digits() {
+        assert_eq!(get_slug("https://Shop.example.com/cart?id=1"), "https-shop-example-com-cart-id-1");
+        assert_eq!(get_slug("/příklad/"), "p-klad");
+
+        let templates_config = TemplatesConfig {
+            scenario_name: "check-{{ url | slug }}".to_string(),
+            ..TemplatesConfig::default()
+        };
+        let templates = NameTemplates::new(&templates_config).unwrap();
+
+        let names = templates.get_object_names(&get_host(), "https://example.com/a,b", &HashMap::new(), None)
+                             .unwrap();
+        assert_eq!(names.scenario, "check-https-example-com-a-b");
+    }
+}
@@ -0,0 +1,130 @@
+pub mod timings {
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    static TIMINGS_ENABLED: AtomicBool = AtomicBool::new(false);
+    /// Timings of every Zabbix API endpoint
+    static API_CALL_TIMINGS: Mutex<BTreeMap<String, Vec<ApiCallTiming>>> = Mutex::new(BTreeMap::new());
+
+    const LOGIN_PHASE: &str = "login";
+    const LOGIN_METHODS: [&str; 3] = ["user.login", "user.checkAuthentication", "user.logout"];
+
+    #[derive(Clone)]
+    pub struct ApiCallTiming {
+        pub method: String,
+        pub started_at: Instant,
+        pub duration: Duration
+    }
+
+    /// Wall time and latency percentiles of API calls of one phase
+    pub struct PhaseTimings {
+        pub phase: String,
+        pub calls: usize,
+        /// From the start of the first call to the end of the last one
+        pub wall_time: Duration,
+        pub p50: Duration,
+        pub p90: Duration,
+        pub p99: Duration,
+        pub max: Duration
+    }
+
+    /// API calls are timed only after timings are enabled
+    pub fn enable_timings() {
+        TIMINGS_ENABLED.store(true, Ordering::SeqCst);
+    }
+
+    /// Timings are per run, daemon mode resets them before every run
+    pub fn reset_timings(api_endpoint: &str) {
+        API_CALL_TIMINGS.lock().unwrap().remove(api_endpoint);
+    }
+
+    pub fn record_api_call_timing(api_endpoint: &str, method: &str, started_at: Instant, duration: Duration) {
+        if TIMINGS_ENABLED.load(Ordering::SeqCst) {
+            API_CALL_TIMINGS.lock().unwrap().entry(api_endpoint.to_string()).or_default()
+                            .push(ApiCallTiming { method: method.to_string(), started_at, duration });
+        }
+    }
+
+    pub fn is_timings_enabled() -> bool {
+        TIMINGS_ENABLED.load(Ordering::SeqCst)
+    }
+
+    pub fn get_api_call_timings(api_endpoint: &str) -> Vec<ApiCallTiming> {
+        API_CALL_TIMINGS.lock().unwrap().get(api_endpoint).cloned().unwrap_or_default()
+    }
+
+    /// Login related methods make one phase, every other API method is a phase of its own
+    fn get_phase(method: &str) -> &str {
+        if LOGIN_METHODS.contains(&method) { LOGIN_PHASE } else { method }
+    }
+
+    /// Phases in order of their first call
+    pub fn get_phase_timings(timings: &[ApiCallTiming]) -> Vec<PhaseTimings> {
+        let mut phases: Vec<(&str, Vec<&ApiCallTiming>)> = vec![];
+
+        for timing in timings {
+            let phase = get_phase(&timing.method);
+
+            match phases.iter_mut().find(|(name, _)| *name == phase) {
+                Some((_, phase_timings)) => phase_timings.push(timing),
+                None => phases.push((phase, vec![timing]))
+            }
+        }
+
+        phases.into_iter().map(|(phase, phase_timings)| {
+            let first_start = phase_timings.iter().map(|timing| timing.started_at).min().unwrap();
+            let last_end = phase_timings.iter().map(|timing| timing.started_at + timing.duration).max().unwrap();
+
+            let mut durations: Vec<Duration> = phase_timings.iter().map(|timing| timing.duration).collect();
+            durations.sort();
+
+            PhaseTimings {
+                phase: phase.to_string(),
+                calls: durations.len(),
+                wall_time: last_end.duration_since(first_start),
+                p50: get_percentile(&durations, 50),
+                p90: get_percentile(&durations, 90),
+                p99: get_percentile(&durations, 99),
+                max: *durations.last().unwrap()
+            }
+        }).collect()
+    }
+
+    /// Nearest-rank percentile of sorted durations
+    pub fn get_percentile(sorted_durations: &[Duration], percentile: usize) -> Duration {
+        if sorted_durations.is_empty() {
+            return Duration::from_secs(0)
+        }
+
+        let rank = (percentile * sorted_durations.len()).div_ceil(100).max(1);
+
+        sorted_durations[rank - 1]
+    }
+
+    pub fn render_timings(phases: &[PhaseTimings], run_duration: Duration) -> String {
+        let mut output = format!("{:<28} {:>6} {:>10} {:>10} {:>10} {:>10} {:>10}\n",
+                                 "PHASE", "CALLS", "WALL", "P50", "P90", "P99", "MAX");
+
+        for phase in phases {
+            output.push_str(&format!("{:<28} {:>6} {:>10} {:>10} {:>10} {:>10} {:>10}\n",
+                                     phase.phase, phase.calls, format_duration(phase.wall_time),
+                                     format_duration(phase.p50), format_duration(phase.p90),
+                                     format_duration(phase.p99), format_duration(phase.max)));
+        }
+
+        let api_calls: usize = phases.iter().map(|phase| phase.calls).sum();
+
+        output.push_str(&format!("\nrun: {}, api calls: {}\n", format_duration(run_duration), api_calls));
+
+        output
+    }
+
+    fn format_duration(duration: Duration) -> String {
+        match duration.as_millis() {
+            0..=999 => format!("{}ms", duration.as_millis()),
+            _ => format!("{:.2}s", duration.as_secs_f64())
+        }
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod timings_tests {
+    use std::time::{Duration, Instant};
+
+    use crate::timings::timings::{ApiCallTiming, get_percentile, get_phase_timings, render_timings};
+
+    fn get_timing(method: &str, started_at: Instant, start_ms: u64, duration_ms: u64) -> ApiCallTiming {
+        ApiCallTiming {
+            method: method.to_string(),
+            started_at: started_at + Duration::from_millis(start_ms),
+            duration: Duration::from_millis(duration_ms)
+        }
+    }
+
+    #[test]
+    fn percentiles_should_use_nearest_rank() {
+        let durations: Vec<Duration> = (1..=10).map(|ms| Duration::from_millis(ms * 10)).collect();
+
+        assert_eq!(get_percentile(&durations, 50), Duration::from_millis(50));
+        assert_eq!(get_percentile(&durations, 90), Duration::from_millis(90));
+        assert_eq!(get_percentile(&durations, 99), Duration::from_millis(100));
+        assert_eq!(get_percentile(&[], 50), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn calls_should_be_grouped_into_phases_in_order() {
+        let now = Instant::now();
+
+        let timings = vec![
+            get_timing("user.login", now, 0, 40),
+            get_timing("item.get", now, 50, 200),
+            get_timing("httptest.create", now, 300, 100),
+            get_timing("httptest.create", now, 320, 300),
+            get_timing("user.logout", now, 700, 10)
+        ];
+
+        let phases = get_phase_timings(&timings);
+
+        assert_eq!(phases.iter().map(|phase| phase.phase.as_str()).collect::<Vec<_>>(),
+                   vec!["login", "item.get", "httptest.create"]);
+        assert_eq!(phases[0].calls, 2);
+        assert_eq!(phases[0].wall_time, Duration::from_millis(710));
+        assert_eq!(phases[2].wall_time, Duration::from_millis(320));
+        assert_eq!(phases[2].p50, Duration::from_millis(100));
+        assert_eq!(phases[2].max, Duration::from_millis(300));
+
+        let output = render_timings(&phases, Duration::from_millis(1500));
+
+        assert!(output.starts_with("PHASE"));
+        assert!(output.contains("httptest.create"));
+        assert!(output.ends_with("run: 1.50s, api calls: 5\n"));
+    }
+}
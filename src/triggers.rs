@@ -1,47 +1,206 @@
 pub mod triggers {
+    use std::collections::HashMap;
+
     use serde::Deserialize;
     use serde::Serialize;
 
     use crate::errors::errors::OperationError;
+    use crate::hosts::hosts::ZabbixHost;
     use crate::http::http::send_post_request;
-    use crate::types::types::EmptyResult;
-    use crate::zabbix::zabbix::{log_zabbix_error, ZabbixError, ZabbixRequest};
+    use crate::templates::templates::{ObjectNames, sanitize_name};
+    use crate::types::types::{EmptyResult, OperationResult, StringResult};
+    use crate::zabbix::zabbix::{get_managed_tag, get_managed_tag_filter, log_zabbix_error, ZabbixError,
+                                ZabbixRequest, ZabbixTag, ZabbixTagFilter};
     use crate::zabbix::zabbix;
 
+    /// OK event closes problems with matching value of the correlation tag
+    const CORRELATION_MODE_TAG_VALUE: u8 = 1;
+
+    /// Zabbix limit of tag value
+    const TAG_VALUE_MAX_LENGTH: usize = 255;
+
     #[derive(Serialize)]
-    struct CreateRequestParams {
+    pub struct TriggerParams {
+        pub description: String,
+        pub expression: String,
+        pub priority: String,
+        pub url: String,
+        #[serde(skip_serializing_if = "String::is_empty")]
+        pub comments: String,
+        pub tags: Vec<ZabbixTag>,
+        /// 1 - OK event closes only problems with the same value of `correlation_tag`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub correlation_mode: Option<u8>,
+        #[serde(skip_serializing_if = "String::is_empty")]
+        pub correlation_tag: String
+    }
+
+    #[derive(Deserialize)]
+    struct CreateTriggerResult {
+        triggerids: Vec<String>
+    }
+
+    #[derive(Deserialize)]
+    struct CreateTriggerResponse {
+        result: Option<CreateTriggerResult>,
+        error: Option<ZabbixError>
+    }
+
+    /// Trigger managed by wszl, `url` is the url of its web scenario
+    #[derive(Deserialize)]
+    pub struct ZabbixTrigger {
+        pub description: String,
+        pub url: String,
+        /// 0 - enabled, 1 - disabled
+        pub status: String,
+        /// 1 - trigger is in problem state
+        pub value: String,
+        pub hosts: Vec<ZabbixHost>
+    }
+
+    #[derive(Serialize)]
+    struct GetUrlTriggersRequestParams {
+        output: Vec<String>,
+        #[serde(rename = "selectTags")]
+        select_tags: Vec<String>,
+        hostids: Vec<String>,
+        tags: Vec<ZabbixTagFilter>,
+        filter: HashMap<String, String>
+    }
+
+    #[derive(Serialize)]
+    struct GetUntaggedUrlTriggersRequestParams {
+        output: Vec<String>,
+        #[serde(rename = "selectTags")]
+        select_tags: Vec<String>,
+        hostids: Vec<String>,
+        filter: HashMap<String, String>
+    }
+
+    /// Only tags are changed
+    #[derive(Serialize)]
+    struct TagTriggerParams {
+        triggerid: String,
+        tags: Vec<ZabbixTag>
+    }
+
+    /// Managed trigger of url, tags tell the kind of trigger
+    #[derive(Deserialize)]
+    pub struct ZabbixUrlTrigger {
+        pub triggerid: String,
+        pub tags: Vec<ZabbixTag>
+    }
+
+    #[derive(Deserialize)]
+    struct UrlTriggersResponse {
+        result: Option<Vec<ZabbixUrlTrigger>>,
+        error: Option<ZabbixError>
+    }
+
+    /// Expression isn't changed, it references items of web scenario by ids
+    #[derive(Serialize)]
+    struct UpdateTriggerParams {
+        triggerid: String,
         description: String,
-        expression: String,
         priority: String,
-        url: String
+        url: String,
+        comments: String,
+        /// Correlation tag carries url, so it's replaced when url changes
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tags: Option<Vec<ZabbixTag>>
     }
 
     #[derive(Deserialize)]
-    struct CreateTriggerResponse {
+    struct UpdateTriggerResponse {
+        result: Option<serde_json::Value>,
         error: Option<ZabbixError>
     }
 
-    pub fn create_trigger(client: &reqwest::blocking::Client,
-                          api_endpoint: &str, api_token: &str,
-                          host: &str, url: &str) -> EmptyResult {
-        debug!("create trigger for '{}', url '{}'", host, url);
+    #[derive(Serialize)]
+    struct GetManagedTriggersRequestParams {
+        output: Vec<String>,
+        tags: Vec<ZabbixTagFilter>,
+        #[serde(rename = "selectHosts")]
+        select_hosts: Vec<String>
+    }
 
-        let expression_body = format!("{}:web.test.fail[Check index page '{}'].last()", host, url);
+    #[derive(Deserialize)]
+    struct ManagedTriggersResponse {
+        result: Option<Vec<ZabbixTrigger>>,
+        error: Option<ZabbixError>
+    }
 
-        let expression_with_bracket = "{".to_string() + &expression_body;
+    /// All triggers managed by wszl (on every host)
+    pub fn find_managed_triggers(client: &reqwest::blocking::Client,
+                                 api_endpoint: &str, api_token: &str) -> OperationResult<Vec<ZabbixTrigger>> {
+        info!("searching triggers managed by wszl..");
+
+        let params = GetManagedTriggersRequestParams {
+            output: vec!["description".to_string(), "url".to_string(),
+                         "status".to_string(), "value".to_string()],
+            tags: vec![get_managed_tag_filter()],
+            select_hosts: vec!["hostid".to_string(), "host".to_string()]
+        };
 
-        let expression = expression_with_bracket + "}<>0";
+        let request: ZabbixRequest<GetManagedTriggersRequestParams> = ZabbixRequest::new(
+            "trigger.get", params, api_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: ManagedTriggersResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
 
-        let trigger_name = format!("Site '{}' is unavailable", url);
+                match search_response.result {
+                    Some(triggers) => {
+                        debug!("managed triggers found: {}", triggers.len());
+                        Ok(triggers)
+                    }
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        error!("unable to find zabbix triggers");
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find zabbix triggers");
+                Err(OperationError::Error)
+            }
+        }
+    }
 
-        let params = CreateRequestParams {
-            description: trigger_name,
-            expression,
+    /// Trigger which is created for web scenario of url
+    pub fn get_trigger_params(names: &ObjectNames, url: &str) -> TriggerParams {
+        TriggerParams {
+            description: names.trigger.to_string(),
+            expression: names.trigger_expression.to_string(),
             priority: "4".to_string(),
-            url: url.to_string()
-        };
+            url: url.to_string(),
+            comments: names.trigger_comments.to_string(),
+            tags: vec![get_managed_tag()],
+            correlation_mode: None,
+            correlation_tag: String::new()
+        }
+    }
+
+    /// Trigger gets tag with its url, so flapping check closes only its own problems
+    /// when Zabbix event correlation is in use. Long urls are cut and get hash, so tag values stay unique.
+    pub fn apply_correlation_tag(params: &mut TriggerParams, correlation_tag: &str) {
+        params.tags.push(ZabbixTag { tag: correlation_tag.to_string(),
+                                     value: sanitize_name(&params.url, TAG_VALUE_MAX_LENGTH) });
+        params.correlation_mode = Some(CORRELATION_MODE_TAG_VALUE);
+        params.correlation_tag = correlation_tag.to_string();
+    }
+
+    /// Returns id of created trigger
+    pub fn create_trigger(client: &reqwest::blocking::Client,
+                          api_endpoint: &str, api_token: &str, params: TriggerParams) -> StringResult {
+        debug!("create trigger '{}'", params.description);
+
+        let description = params.description.to_string();
 
-        let request: ZabbixRequest<CreateRequestParams> = ZabbixRequest::new(
+        let request: ZabbixRequest<TriggerParams> = ZabbixRequest::new(
             "trigger.create", params, api_token
         );
 
@@ -50,20 +209,176 @@ pub mod triggers {
                 let create_response: CreateTriggerResponse = serde_json::from_str(&response)
                     .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
 
-                match create_response.error {
-                    Some(_) => {
+                match create_response.result.and_then(|result| result.triggerids.into_iter().next()) {
+                    Some(triggerid) => {
+                        info!("trigger '{}' has been created", description);
+                        Ok(triggerid)
+                    }
+                    None => {
                         log_zabbix_error(&create_response.error);
-                        error!("unable to create trigger for '{}'", url);
+                        error!("unable to create trigger '{}'", description);
                         Err(OperationError::Error)
                     }
+                }
+            }
+            Err(_) => {
+                error!("unable to create trigger '{}'", description);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Managed triggers of web scenario of url on host
+    pub fn find_url_triggers(client: &reqwest::blocking::Client, api_endpoint: &str, api_token: &str,
+                             hostid: &str, url: &str) -> OperationResult<Vec<ZabbixUrlTrigger>> {
+        let mut filter = HashMap::new();
+        filter.insert("url".to_string(), url.to_string());
+
+        let params = GetUrlTriggersRequestParams {
+            output: vec!["triggerid".to_string()],
+            select_tags: vec!["tag".to_string(), "value".to_string()],
+            hostids: vec![hostid.to_string()],
+            tags: vec![get_managed_tag_filter()],
+            filter
+        };
+
+        let request: ZabbixRequest<GetUrlTriggersRequestParams> = ZabbixRequest::new(
+            "trigger.get", params, api_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: UrlTriggersResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match search_response.result {
+                    Some(triggers) => Ok(triggers),
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        error!("unable to find triggers of url '{}'", url);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find triggers of url '{}'", url);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Triggers of url on host without wszl tag, created by versions before tagging
+    pub fn find_legacy_url_triggers(client: &reqwest::blocking::Client, api_endpoint: &str, api_token: &str,
+                                    hostid: &str, url: &str, description: &str) ->
+                                                                OperationResult<Vec<ZabbixUrlTrigger>> {
+        let mut filter = HashMap::new();
+        filter.insert("url".to_string(), url.to_string());
+        filter.insert("description".to_string(), description.to_string());
+
+        let params = GetUntaggedUrlTriggersRequestParams {
+            output: vec!["triggerid".to_string()],
+            select_tags: vec!["tag".to_string(), "value".to_string()],
+            hostids: vec![hostid.to_string()],
+            filter
+        };
+
+        let request: ZabbixRequest<GetUntaggedUrlTriggersRequestParams> = ZabbixRequest::new(
+            "trigger.get", params, api_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: UrlTriggersResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match search_response.result {
+                    Some(triggers) => {
+                        let managed_tag = get_managed_tag();
+
+                        Ok(triggers.into_iter().filter(|trigger| !trigger.tags.iter().any(|tag| {
+                            tag.tag == managed_tag.tag && tag.value == managed_tag.value
+                        })).collect())
+                    }
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        error!("unable to find legacy triggers of url '{}'", url);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find legacy triggers of url '{}'", url);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Trigger gets wszl tag, its own tags are kept
+    pub fn tag_trigger(client: &reqwest::blocking::Client, api_endpoint: &str, api_token: &str,
+                       trigger: &ZabbixUrlTrigger) -> EmptyResult {
+        debug!("tag trigger '{}'", trigger.triggerid);
+
+        let mut tags = trigger.tags.clone();
+        tags.push(get_managed_tag());
+
+        let request: ZabbixRequest<TagTriggerParams> = ZabbixRequest::new(
+            "trigger.update", TagTriggerParams { triggerid: trigger.triggerid.to_string(), tags }, api_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let update_response: UpdateTriggerResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match update_response.result {
+                    Some(_) => Ok(()),
+                    None => {
+                        log_zabbix_error(&update_response.error);
+                        error!("unable to tag trigger '{}'", trigger.triggerid);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to tag trigger '{}'", trigger.triggerid);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    pub fn update_trigger(client: &reqwest::blocking::Client, api_endpoint: &str, api_token: &str,
+                          triggerid: &str, params: &TriggerParams) -> EmptyResult {
+        debug!("update trigger '{}'", triggerid);
+
+        let request: ZabbixRequest<UpdateTriggerParams> = ZabbixRequest::new(
+            "trigger.update",
+            UpdateTriggerParams {
+                triggerid: triggerid.to_string(),
+                description: params.description.to_string(),
+                priority: params.priority.to_string(),
+                url: params.url.to_string(),
+                comments: params.comments.to_string(),
+                tags: params.correlation_mode.map(|_| params.tags.clone())
+            },
+            api_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let update_response: UpdateTriggerResponse = serde_json::from_str(&response)
+                    .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+
+                match update_response.result {
+                    Some(_) => Ok(()),
                     None => {
-                        info!("trigger has been created for url '{}'", url);
-                        Ok(())
+                        log_zabbix_error(&update_response.error);
+                        error!("unable to update trigger '{}'", params.description);
+                        Err(OperationError::Error)
                     }
                 }
             }
             Err(_) => {
-                error!("unable to find zabbix items");
+                error!("unable to update trigger '{}'", params.description);
                 Err(OperationError::Error)
             }
         }
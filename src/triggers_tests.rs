@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod triggers_tests {
+    use std::collections::HashMap;
+
+    use crate::config::config::TemplatesConfig;
+    use crate::hosts::hosts::ZabbixHost;
+    use crate::templates::templates::NameTemplates;
+    use crate::triggers::triggers::{apply_correlation_tag, get_trigger_params, TriggerParams};
+
+    fn get_trigger(url: &str) -> TriggerParams {
+        let host = ZabbixHost { hostid: "10001".to_string(), host: "web-01".to_string(),
+                                maintenance_status: "0".to_string(), status: "0".to_string(),
+                                proxy_hostid: "0".to_string() };
+
+        let names = NameTemplates::new(&TemplatesConfig::default()).unwrap()
+                        .get_object_names(&host, url, &HashMap::new(), None).unwrap();
+
+        get_trigger_params(&names, url)
+    }
+
+    #[test]
+    fn correlation_tag_value_should_be_url() {
+        let mut trigger = get_trigger("https://example.com");
+        apply_correlation_tag(&mut trigger, "url");
+
+        assert_eq!(trigger.correlation_mode, Some(1));
+        assert_eq!(trigger.correlation_tag, "url");
+        assert_eq!(trigger.tags.last().map(|tag| (tag.tag.as_str(), tag.value.as_str())),
+                   Some(("url", "https://example.com")));
+    }
+
+    #[test]
+    fn correlation_tag_value_of_long_url_should_fit_zabbix_limit() {
+        let long_url = format!("https://example.com/search?q={}", "x".repeat(300));
+        let other_long_url = format!("https://example.com/search?q={}y", "x".repeat(300));
+
+        let mut trigger = get_trigger(&long_url);
+        apply_correlation_tag(&mut trigger, "url");
+
+        let mut other_trigger = get_trigger(&other_long_url);
+        apply_correlation_tag(&mut other_trigger, "url");
+
+        let value = &trigger.tags.last().unwrap().value;
+        assert_eq!(value.chars().count(), 255);
+        assert!(value.starts_with("https://example.com/search?q=xxx"));
+        assert_ne!(value, &other_trigger.tags.last().unwrap().value);
+        assert_eq!(trigger.url, long_url);
+    }
+}
@@ -0,0 +1,128 @@
+pub mod urlcheck {
+    use std::net::Ipv6Addr;
+
+    const SCHEME_SEPARATOR: &str = "://";
+    const DEFAULT_SCHEME: &str = "http";
+    const SUPPORTED_SCHEMES: [&str; 2] = ["http", "https"];
+
+    /// Validates captured url and fixes what can be fixed unambiguously:
+    /// surrounding whitespace, spaces (escaped as `%20`) and bare IPv6 address without port (gets brackets).
+    /// Url without scheme is kept without scheme, Zabbix requests it over http.
+    pub fn normalize_url(value: &str) -> Result<String, String> {
+        let value = value.trim().replace(' ', "%20");
+
+        if value.is_empty() {
+            return Err("url is empty".to_string())
+        }
+
+        if value.chars().any(char::is_whitespace) {
+            return Err("url contains whitespace".to_string())
+        }
+
+        let (scheme, address) = match value.find(SCHEME_SEPARATOR) {
+            Some(position) => (Some(&value[..position]), &value[position + SCHEME_SEPARATOR.len()..]),
+            None => (None, value.as_str())
+        };
+
+        if let Some(scheme) = scheme {
+            if !SUPPORTED_SCHEMES.contains(&scheme.to_lowercase().as_str()) {
+                return Err(format!("unsupported scheme '{}'", scheme))
+            }
+        }
+
+        let authority_end = address.find(['/', '?', '#']).unwrap_or(address.len());
+        let (authority, rest) = address.split_at(authority_end);
+
+        let (user_info, host_port) = match authority.rfind('@') {
+            Some(position) => (&authority[..=position], &authority[position + 1..]),
+            None => ("", authority)
+        };
+
+        let host_port = normalize_host_port(host_port)?;
+
+        let normalized = format!("{}{}{}{}",
+                                 scheme.map(|scheme| format!("{}{}", scheme, SCHEME_SEPARATOR)).unwrap_or_default(),
+                                 user_info, host_port, rest);
+
+        let parse_result = match scheme {
+            Some(_) => reqwest::Url::parse(&normalized),
+            None => reqwest::Url::parse(&format!("{}{}{}", DEFAULT_SCHEME, SCHEME_SEPARATOR, normalized))
+        };
+
+        match parse_result {
+            Ok(_) => Ok(normalized),
+            Err(e) => Err(e.to_string())
+        }
+    }
+
+    /// Server address for a TCP connection, `default_port` is added when address has no port.
+    /// Bare IPv6 address gets brackets.
+    pub fn get_server_address(value: &str, default_port: u16) -> Result<String, String> {
+        let host_port = normalize_host_port(value.trim())?;
+        let host_port = host_port.strip_suffix(':').unwrap_or(&host_port);
+
+        let has_port = match host_port.rfind(']') {
+            Some(position) => host_port[position..].contains(':'),
+            None => host_port.contains(':')
+        };
+
+        if has_port {
+            Ok(host_port.to_string())
+        } else {
+            Ok(format!("{}:{}", host_port, default_port))
+        }
+    }
+
+    /// `host`, `host:port`, `[ipv6]`, `[ipv6]:port` or bare `ipv6`
+    fn normalize_host_port(host_port: &str) -> Result<String, String> {
+        if host_port.is_empty() {
+            return Err("url has no host".to_string())
+        }
+
+        if let Some(bracketed) = host_port.strip_prefix('[') {
+            let (address, port) = match bracketed.find(']') {
+                Some(position) => (&bracketed[..position], &bracketed[position + 1..]),
+                None => return Err(format!("unclosed bracket of ipv6 address '{}'", host_port))
+            };
+
+            if address.parse::<Ipv6Addr>().is_err() {
+                return Err(format!("invalid ipv6 address '{}'", address))
+            }
+
+            if !port.is_empty() && !port.starts_with(':') {
+                return Err(format!("unexpected characters '{}' after ipv6 address", port))
+            }
+
+            validate_port(port.trim_start_matches(':'))?;
+
+            return Ok(host_port.to_string())
+        }
+
+        if host_port.matches(':').count() > 1 {
+            return match host_port.parse::<Ipv6Addr>() {
+                Ok(_) => Ok(format!("[{}]", host_port)),
+                Err(_) => Err(format!("invalid ipv6 address '{}', address with port must be in brackets \
+                                       like [2001:db8::1]:8443", host_port))
+            }
+        }
+
+        if let Some(position) = host_port.find(':') {
+            if position == 0 {
+                return Err("url has no host".to_string())
+            }
+
+            validate_port(&host_port[position + 1..])?;
+        }
+
+        Ok(host_port.to_string())
+    }
+
+    /// Empty port means the default one
+    fn validate_port(port: &str) -> Result<(), String> {
+        if port.is_empty() || port.parse::<u16>().map(|port| port > 0).unwrap_or(false) {
+            Ok(())
+        } else {
+            Err(format!("invalid port '{}'", port))
+        }
+    }
+}
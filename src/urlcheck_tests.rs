@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod urlcheck_tests {
+    use crate::urlcheck::urlcheck::{get_server_address, normalize_url};
+
+    #[test]
+    fn valid_urls_should_be_kept() {
+        for url in &["https://example.com/path?q=1", "example.com", "example.com:8080/status",
+                     "https://[2001:db8::1]:8443/path", "[2001:db8::1]:8443/path", "http://user@example.com"] {
+            assert_eq!(normalize_url(url), Ok(url.to_string()));
+        }
+    }
+
+    #[test]
+    fn bare_ipv6_address_should_get_brackets() {
+        assert_eq!(normalize_url("2001:db8::1"), Ok("[2001:db8::1]".to_string()));
+        assert_eq!(normalize_url("https://2001:db8::1/health"), Ok("https://[2001:db8::1]/health".to_string()));
+    }
+
+    #[test]
+    fn spaces_should_be_escaped() {
+        assert_eq!(normalize_url(" https://example.com/my page "), Ok("https://example.com/my%20page".to_string()));
+    }
+
+    #[test]
+    fn invalid_urls_should_be_rejected() {
+        assert!(normalize_url("").is_err());
+        assert!(normalize_url("example.com:99999").is_err());
+        assert!(normalize_url("example.com:port/path").is_err());
+        assert!(normalize_url(":8080").is_err());
+        assert!(normalize_url("[2001:db8::1/path").is_err());
+        assert!(normalize_url("[2001:db8::zz]:8443").is_err());
+        assert!(normalize_url("2001:db8::1:8443:x").is_err());
+        assert!(normalize_url("ftp://example.com").is_err());
+        assert!(normalize_url("https://").is_err());
+    }
+
+    #[test]
+    fn server_address_should_get_default_port() {
+        assert_eq!(get_server_address("zabbix", 10051), Ok("zabbix:10051".to_string()));
+        assert_eq!(get_server_address("zabbix:10052", 10051), Ok("zabbix:10052".to_string()));
+        assert_eq!(get_server_address("2001:db8::1", 10051), Ok("[2001:db8::1]:10051".to_string()));
+        assert_eq!(get_server_address("fe80::", 10051), Ok("[fe80::]:10051".to_string()));
+        assert_eq!(get_server_address("[2001:db8::1]", 10051), Ok("[2001:db8::1]:10051".to_string()));
+        assert_eq!(get_server_address("[2001:db8::1]:10052", 10051), Ok("[2001:db8::1]:10052".to_string()));
+        assert!(get_server_address("zabbix:port", 10051).is_err());
+        assert!(get_server_address("2001:db8::1:10052:x", 10051).is_err());
+    }
+}
@@ -0,0 +1,54 @@
+pub mod urlfilter {
+    use regex::Regex;
+
+    use crate::config::config::UrlsConfig;
+    use crate::errors::errors::OperationError;
+    use crate::types::types::OperationResult;
+
+    /// Allowlist/denylist of captured urls.
+    /// Url is accepted if it matches any of include patterns (when set) and none of exclude patterns.
+    pub struct UrlFilter {
+        include: Vec<Regex>,
+        exclude: Vec<Regex>
+    }
+
+    impl UrlFilter {
+        pub fn new(urls_config: &UrlsConfig) -> OperationResult<UrlFilter> {
+            Ok(UrlFilter {
+                include: get_regexes(&urls_config.include)?,
+                exclude: get_regexes(&urls_config.exclude)?
+            })
+        }
+
+        pub fn is_accepted(&self, url: &str) -> bool {
+            if !self.include.is_empty() && !self.include.iter().any(|regex| regex.is_match(url)) {
+                debug!("url '{}' doesn't match include patterns, skip", url);
+                return false
+            }
+
+            match self.exclude.iter().find(|regex| regex.is_match(url)) {
+                Some(regex) => {
+                    debug!("url '{}' matches exclude pattern '{}', skip", url, regex.as_str());
+                    false
+                }
+                None => true
+            }
+        }
+    }
+
+    fn get_regexes(patterns: &[String]) -> OperationResult<Vec<Regex>> {
+        let mut regexes = vec![];
+
+        for pattern in patterns {
+            match Regex::new(pattern) {
+                Ok(regex) => regexes.push(regex),
+                Err(e) => {
+                    error!("invalid url filter pattern '{}': {}", pattern, e);
+                    return Err(OperationError::Error)
+                }
+            }
+        }
+
+        Ok(regexes)
+    }
+}
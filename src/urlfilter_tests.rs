@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod urlfilter_tests {
+    use crate::config::config::{DedupScope, UrlsConfig};
+    use crate::urlfilter::urlfilter::UrlFilter;
+
+    fn get_urls_config(include: Vec<&str>, exclude: Vec<&str>) -> UrlsConfig {
+        UrlsConfig {
+            rewrites: vec![],
+            force_scheme: None,
+            append_path: None,
+            include: include.into_iter().map(String::from).collect(),
+            exclude: exclude.into_iter().map(String::from).collect(),
+            dedup_scope: DedupScope::Host,
+            blocklist_file: String::new()
+        }
+    }
+
+    #[test]
+    fn all_urls_should_be_accepted_without_patterns() {
+        let url_filter = UrlFilter::new(&get_urls_config(vec![], vec![])).unwrap();
+
+        assert!(url_filter.is_accepted("example.com"));
+        assert!(url_filter.is_accepted("internal.local"));
+    }
+
+    #[test]
+    fn excluded_urls_should_be_rejected() {
+        let url_filter = UrlFilter::new(&get_urls_config(vec![], vec!["\\.local$", "^默认$"])).unwrap();
+
+        assert!(url_filter.is_accepted("example.com"));
+        assert!(!url_filter.is_accepted("internal.local"));
+        assert!(!url_filter.is_accepted("默认"));
+    }
+
+    #[test]
+    fn only_included_urls_should_be_accepted() {
+        let url_filter = UrlFilter::new(
+            &get_urls_config(vec!["\\.example\\.com$"], vec!["^admin\\."])
+        ).unwrap();
+
+        assert!(url_filter.is_accepted("shop.example.com"));
+        assert!(!url_filter.is_accepted("admin.example.com"));
+        assert!(!url_filter.is_accepted("example.org"));
+    }
+
+    #[test]
+    fn invalid_pattern_should_be_rejected() {
+        assert!(UrlFilter::new(&get_urls_config(vec!["("], vec![])).is_err());
+    }
+}
@@ -0,0 +1,106 @@
+pub mod urlsfile {
+    use std::fs;
+    use std::path::Path;
+
+    use yaml_rust::YamlLoader;
+
+    use crate::discovery::discovery::ExternalUrl;
+    use crate::errors::errors::OperationError;
+    use crate::types::types::OperationResult;
+
+    const COMMENT_PREFIX: &str = "#";
+
+    /// Loads urls mapped to Zabbix hosts (by technical host name).
+    ///
+    /// YAML file (`.yml`/`.yaml`) is a map of host to url list, otherwise every line is `<host> <url>`.
+    pub fn load_urls_from_file(file_path: &Path) -> OperationResult<Vec<ExternalUrl>> {
+        info!("loading urls from file '{}'", file_path.display());
+
+        let content = fs::read_to_string(file_path)?;
+
+        let source = format!("file '{}'", file_path.display());
+
+        let is_yaml = matches!(file_path.extension().and_then(|extension| extension.to_str()),
+                               Some("yml") | Some("yaml"));
+
+        let urls = if is_yaml {
+            parse_yaml_urls(&content, &source)?
+
+        } else {
+            parse_plain_urls(&content, &source)?
+        };
+
+        info!("urls loaded from file: {}", urls.len());
+
+        Ok(urls)
+    }
+
+    pub fn parse_plain_urls(content: &str, source: &str) -> OperationResult<Vec<ExternalUrl>> {
+        let mut urls = vec![];
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with(COMMENT_PREFIX) {
+                continue;
+            }
+
+            let parts: Vec<&str> = line.split_whitespace().collect();
+
+            if parts.len() != 2 {
+                error!("{}, line {}: expected '<host> <url>'", source, index + 1);
+                return Err(OperationError::Error)
+            }
+
+            urls.push(ExternalUrl {
+                source: source.to_string(),
+                host: parts[0].to_string(),
+                url: parts[1].to_string()
+            });
+        }
+
+        Ok(urls)
+    }
+
+    pub fn parse_yaml_urls(content: &str, source: &str) -> OperationResult<Vec<ExternalUrl>> {
+        match YamlLoader::load_from_str(content) {
+            Ok(documents) => {
+                let mut urls = vec![];
+
+                if let Some(hosts) = documents.first().and_then(|document| document.as_hash()) {
+                    for (host, host_urls) in hosts {
+                        let host = match host.as_str() {
+                            Some(host) => host,
+                            None => {
+                                error!("{}: host name must be a string", source);
+                                return Err(OperationError::Error)
+                            }
+                        };
+
+                        match host_urls.as_vec() {
+                            Some(host_urls) => {
+                                for url in host_urls.iter().filter_map(|url| url.as_str()) {
+                                    urls.push(ExternalUrl {
+                                        source: source.to_string(),
+                                        host: host.to_string(),
+                                        url: url.to_string()
+                                    });
+                                }
+                            }
+                            None => {
+                                error!("{}: list of urls expected for host '{}'", source, host);
+                                return Err(OperationError::Error)
+                            }
+                        }
+                    }
+                }
+
+                Ok(urls)
+            }
+            Err(e) => {
+                error!("unable to parse {}: {}", source, e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
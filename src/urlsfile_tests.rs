@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod urlsfile_tests {
+    use std::path::Path;
+
+    use crate::urlsfile::urlsfile::{load_urls_from_file, parse_plain_urls};
+
+    #[test]
+    fn plain_urls_file_should_be_loaded() {
+        let urls = load_urls_from_file(Path::new("tests/urls.txt")).unwrap();
+
+        assert_eq!(urls.len(), 2);
+        assert_eq!(urls[0].host, "web-01");
+        assert_eq!(urls[0].url, "https://example.com");
+        assert_eq!(urls[1].host, "web-02");
+        assert_eq!(urls[1].url, "https://shop.example.com/healthz");
+    }
+
+    #[test]
+    fn yaml_urls_file_should_be_loaded() {
+        let urls = load_urls_from_file(Path::new("tests/urls.yml")).unwrap();
+
+        assert_eq!(urls.len(), 3);
+        assert_eq!(urls[0].host, "web-01");
+        assert_eq!(urls[1].host, "web-01");
+        assert_eq!(urls[1].url, "https://api.example.com");
+        assert_eq!(urls[2].host, "web-02");
+    }
+
+    #[test]
+    fn line_without_host_should_be_rejected() {
+        assert!(parse_plain_urls("https://example.com\n", "test").is_err());
+    }
+}
@@ -0,0 +1,68 @@
+pub mod urltransform {
+    use regex::Regex;
+
+    use crate::config::config::UrlsConfig;
+    use crate::errors::errors::OperationError;
+    use crate::types::types::OperationResult;
+
+    const SCHEME_SEPARATOR: &str = "://";
+
+    /// Rewrites discovered urls before web scenario creation:
+    /// regex replacements, then scheme forcing, then path appending.
+    pub struct UrlTransformer {
+        rewrites: Vec<(Regex, String)>,
+        force_scheme: Option<String>,
+        append_path: Option<String>
+    }
+
+    impl UrlTransformer {
+        pub fn new(urls_config: &UrlsConfig) -> OperationResult<UrlTransformer> {
+            let mut rewrites = vec![];
+
+            for rewrite in &urls_config.rewrites {
+                match Regex::new(&rewrite.search) {
+                    Ok(regex) => rewrites.push((regex, rewrite.replace.to_string())),
+                    Err(e) => {
+                        error!("invalid url rewrite pattern '{}': {}", rewrite.search, e);
+                        return Err(OperationError::Error)
+                    }
+                }
+            }
+
+            Ok(UrlTransformer {
+                rewrites,
+                force_scheme: urls_config.force_scheme.clone(),
+                append_path: urls_config.append_path.clone()
+            })
+        }
+
+        pub fn transform(&self, url: &str) -> String {
+            let mut result = url.to_string();
+
+            for (regex, replace) in &self.rewrites {
+                result = regex.replace_all(&result, replace.as_str()).to_string();
+            }
+
+            if let Some(scheme) = &self.force_scheme {
+                let address = match result.find(SCHEME_SEPARATOR) {
+                    Some(position) => &result[position + SCHEME_SEPARATOR.len()..],
+                    None => &result
+                };
+
+                result = format!("{}{}{}", scheme, SCHEME_SEPARATOR, address);
+            }
+
+            if let Some(path) = &self.append_path {
+                if !result.ends_with(path.as_str()) {
+                    result = format!("{}/{}", result.trim_end_matches('/'), path.trim_start_matches('/'));
+                }
+            }
+
+            if result != url {
+                debug!("url '{}' has been transformed to '{}'", url, result);
+            }
+
+            result
+        }
+    }
+}
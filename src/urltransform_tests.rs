@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod urltransform_tests {
+    use crate::config::config::{DedupScope, UrlRewriteConfig, UrlsConfig};
+    use crate::urltransform::urltransform::UrlTransformer;
+
+    fn get_urls_config() -> UrlsConfig {
+        UrlsConfig {
+            rewrites: vec![],
+            force_scheme: None,
+            append_path: None,
+            include: vec![],
+            exclude: vec![],
+            dedup_scope: DedupScope::Host,
+            blocklist_file: String::new()
+        }
+    }
+
+    #[test]
+    fn bare_host_name_should_get_scheme_and_path() {
+        let mut urls_config = get_urls_config();
+        urls_config.force_scheme = Some("https".to_string());
+        urls_config.append_path = Some("/healthz".to_string());
+
+        let transformer = UrlTransformer::new(&urls_config).unwrap();
+
+        assert_eq!(transformer.transform("example.com"), "https://example.com/healthz");
+        assert_eq!(transformer.transform("http://example.com/"), "https://example.com/healthz");
+        assert_eq!(transformer.transform("https://example.com/healthz"), "https://example.com/healthz");
+    }
+
+    #[test]
+    fn rewrites_should_be_applied_in_order() {
+        let mut urls_config = get_urls_config();
+        urls_config.rewrites = vec![
+            UrlRewriteConfig { search: "^www\\.".to_string(), replace: "".to_string() },
+            UrlRewriteConfig { search: "^(.+)\\.local$".to_string(), replace: "$1.example.com".to_string() }
+        ];
+
+        let transformer = UrlTransformer::new(&urls_config).unwrap();
+
+        assert_eq!(transformer.transform("www.shop.local"), "shop.example.com");
+    }
+
+    #[test]
+    fn invalid_rewrite_pattern_should_be_rejected() {
+        let mut urls_config = get_urls_config();
+        urls_config.rewrites = vec![
+            UrlRewriteConfig { search: "(".to_string(), replace: "".to_string() }
+        ];
+
+        assert!(UrlTransformer::new(&urls_config).is_err());
+    }
+}
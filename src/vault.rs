@@ -0,0 +1,109 @@
+pub mod vault {
+    use std::env;
+
+    use serde_json::{json, Value};
+
+    use crate::config::config::VaultConfig;
+    use crate::errors::errors::OperationError;
+    use crate::types::types::{OperationResult, StringResult};
+
+    const VAULT_TOKEN_HEADER: &str = "X-Vault-Token";
+    const VAULT_TOKEN_ENV_VAR: &str = "VAULT_TOKEN";
+
+    pub struct VaultCredentials {
+        /// Username from config is kept when secret doesn't have it
+        pub username: Option<String>,
+        pub password: String
+    }
+
+    pub fn get_secret_url(vault_config: &VaultConfig) -> String {
+        format!("{}/v1/{}/data/{}", vault_config.address,
+                vault_config.mount.trim_matches('/'), vault_config.path.trim_matches('/'))
+    }
+
+    /// Reads Zabbix API credentials from KV v2 secret
+    pub fn get_vault_credentials(client: &reqwest::blocking::Client,
+                                 vault_config: &VaultConfig) -> OperationResult<VaultCredentials> {
+        let token = get_vault_token(client, vault_config)?;
+
+        let secret_url = get_secret_url(vault_config);
+
+        info!("reading zabbix api credentials from vault '{}'", secret_url);
+
+        let response = send_vault_request(client.get(&secret_url).header(VAULT_TOKEN_HEADER, token))?;
+
+        get_credentials_from_response(vault_config, &response)
+    }
+
+    pub fn get_credentials_from_response(vault_config: &VaultConfig,
+                                         response: &Value) -> OperationResult<VaultCredentials> {
+        let secret = &response["data"]["data"];
+
+        match secret[&vault_config.password_key].as_str() {
+            Some(password) => Ok(VaultCredentials {
+                username: secret[&vault_config.username_key].as_str().map(String::from),
+                password: password.to_string()
+            }),
+            None => {
+                error!("vault secret '{}' doesn't have '{}' key", vault_config.path, vault_config.password_key);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn get_vault_token(client: &reqwest::blocking::Client, vault_config: &VaultConfig) -> StringResult {
+        if let Some(approle) = &vault_config.approle {
+            let login_url = format!("{}/v1/auth/approle/login", vault_config.address);
+
+            let body = json!({ "role_id": approle.role_id, "secret_id": approle.secret_id });
+
+            let response = send_vault_request(client.post(&login_url).json(&body))?;
+
+            return match response["auth"]["client_token"].as_str() {
+                Some(token) => Ok(token.to_string()),
+                None => {
+                    error!("unsupported vault approle login response");
+                    Err(OperationError::Error)
+                }
+            }
+        }
+
+        match &vault_config.token {
+            Some(token) => Ok(token.to_string()),
+            None => match env::var(VAULT_TOKEN_ENV_VAR) {
+                Ok(token) => Ok(token),
+                Err(_) => {
+                    error!("vault token wasn't set, use 'token', 'approle' or {} environment variable",
+                           VAULT_TOKEN_ENV_VAR);
+                    Err(OperationError::Error)
+                }
+            }
+        }
+    }
+
+    fn send_vault_request(request: reqwest::blocking::RequestBuilder) -> OperationResult<Value> {
+        match request.send() {
+            Ok(response) => {
+                let response_status = response.status();
+
+                if response_status.is_success() {
+                    match response.json::<Value>() {
+                        Ok(value) => Ok(value),
+                        Err(e) => {
+                            error!("unsupported vault response: {}", e);
+                            Err(OperationError::Error)
+                        }
+                    }
+
+                } else {
+                    error!("unexpected vault response code {}", response_status);
+                    Err(OperationError::Error)
+                }
+            }
+            Err(e) => {
+                error!("vault request error: {}", e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+}
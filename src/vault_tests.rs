@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod vault_tests {
+    use serde_json::json;
+
+    use crate::config::config::VaultConfig;
+    use crate::vault::vault::{get_credentials_from_response, get_secret_url};
+
+    fn get_vault_config() -> VaultConfig {
+        VaultConfig {
+            address: "https://vault:8200".to_string(),
+            mount: "secret".to_string(),
+            path: "/monitoring/zabbix".to_string(),
+            token: Some("s.token".to_string()),
+            approle: None,
+            username_key: "username".to_string(),
+            password_key: "password".to_string()
+        }
+    }
+
+    #[test]
+    fn secret_url_should_point_to_kv2_data() {
+        assert_eq!("https://vault:8200/v1/secret/data/monitoring/zabbix", get_secret_url(&get_vault_config()));
+    }
+
+    #[test]
+    fn credentials_should_be_taken_from_secret_data() {
+        let response = json!({ "data": { "data": { "username": "wszl", "password": "secret" }, "metadata": {} } });
+
+        let credentials = get_credentials_from_response(&get_vault_config(), &response).unwrap();
+
+        assert_eq!(credentials.username.unwrap(), "wszl");
+        assert_eq!(credentials.password, "secret");
+    }
+
+    #[test]
+    fn secret_without_password_should_be_error() {
+        let response = json!({ "data": { "data": { "username": "wszl" } } });
+
+        assert!(get_credentials_from_response(&get_vault_config(), &response).is_err());
+    }
+}
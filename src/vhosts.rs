@@ -0,0 +1,214 @@
+pub mod vhosts {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::config::config::VhostsDiscoveryConfig;
+    use crate::discovery::discovery::ExternalUrl;
+    use crate::types::types::OperationResult;
+
+    const HTTPS_PORT: &str = "443";
+
+    /// Virtual host from web server config
+    #[derive(PartialEq, Debug)]
+    pub struct VirtualHost {
+        pub name: String,
+        pub https: bool
+    }
+
+    /// Urls of virtual hosts from nginx/Apache config directories on this machine,
+    /// all of them are mapped to Zabbix host from config.
+    pub fn get_urls_from_vhost_configs(vhosts_config: &VhostsDiscoveryConfig) ->
+                                                            OperationResult<Vec<ExternalUrl>> {
+        info!("searching virtual hosts in web server configs..");
+
+        let mut urls = vec![];
+
+        for directory in &vhosts_config.nginx_dirs {
+            for (file_name, content) in read_config_files(Path::new(directory))? {
+                append_urls(&mut urls, &file_name, parse_nginx_config(&content), vhosts_config);
+            }
+        }
+
+        for directory in &vhosts_config.apache_dirs {
+            for (file_name, content) in read_config_files(Path::new(directory))? {
+                append_urls(&mut urls, &file_name, parse_apache_config(&content), vhosts_config);
+            }
+        }
+
+        info!("urls found in web server configs: {}", urls.len());
+
+        Ok(urls)
+    }
+
+    fn append_urls(urls: &mut Vec<ExternalUrl>, file_name: &str, virtual_hosts: Vec<VirtualHost>,
+                   vhosts_config: &VhostsDiscoveryConfig) {
+        for virtual_host in virtual_hosts {
+            let scheme = if virtual_host.https { "https" } else { "http" };
+            let url = format!("{}://{}", scheme, virtual_host.name);
+
+            if urls.iter().any(|existing_url| existing_url.url == url) {
+                continue;
+            }
+
+            debug!("config '{}', url '{}'", file_name, url);
+
+            urls.push(ExternalUrl {
+                source: format!("config '{}'", file_name),
+                host: vhosts_config.host.to_string(),
+                url
+            });
+        }
+    }
+
+    fn read_config_files(directory: &Path) -> OperationResult<Vec<(String, String)>> {
+        let mut files = vec![];
+
+        for entry in fs::read_dir(directory)? {
+            let path = entry?.path();
+
+            if !path.is_file() {
+                continue;
+            }
+
+            match fs::read_to_string(&path) {
+                Ok(content) => files.push((path.display().to_string(), content)),
+                Err(e) => warn!("unable to read config file '{}': {}", path.display(), e)
+            }
+        }
+
+        files.sort();
+
+        Ok(files)
+    }
+
+    /// Collects `server_name` values of `server` blocks. Block listening on 443 or with `ssl` is https.
+    pub fn parse_nginx_config(content: &str) -> Vec<VirtualHost> {
+        let content: String = content.lines()
+            .map(|line| line.split('#').next().unwrap_or_default())
+            .collect::<Vec<&str>>().join("\n");
+
+        let content = content.replace('{', " { ").replace('}', " } ").replace(';', " ; ");
+
+        let mut virtual_hosts = vec![];
+
+        let mut depth = 0;
+        let mut server_block_depth: Option<usize> = None;
+        let mut names: Vec<String> = vec![];
+        let mut https = false;
+
+        let mut directive: Vec<&str> = vec![];
+
+        for token in content.split_whitespace() {
+            match token {
+                "{" => {
+                    depth += 1;
+
+                    if server_block_depth.is_none() && directive == ["server"] {
+                        server_block_depth = Some(depth);
+                        names.clear();
+                        https = false;
+                    }
+
+                    directive.clear();
+                }
+                "}" => {
+                    if server_block_depth == Some(depth) {
+                        server_block_depth = None;
+
+                        for name in names.drain(..) {
+                            virtual_hosts.push(VirtualHost { name, https });
+                        }
+                    }
+
+                    depth = depth.saturating_sub(1);
+                    directive.clear();
+                }
+                ";" => {
+                    if server_block_depth == Some(depth) {
+                        match directive.first() {
+                            Some(&"server_name") => {
+                                names.extend(directive[1..].iter()
+                                    .filter(|name| is_supported_server_name(name))
+                                    .map(|name| name.to_string()));
+                            }
+                            Some(&"listen") if is_https_listen(&directive[1..]) => https = true,
+                            _ => {}
+                        }
+                    }
+
+                    directive.clear();
+                }
+                _ => directive.push(token)
+            }
+        }
+
+        virtual_hosts
+    }
+
+    /// Collects `ServerName`/`ServerAlias` values of `<VirtualHost>` sections.
+    /// Section on port 443 or with `SSLEngine on` is https.
+    pub fn parse_apache_config(content: &str) -> Vec<VirtualHost> {
+        let mut virtual_hosts = vec![];
+
+        let mut in_virtual_host = false;
+        let mut names: Vec<String> = vec![];
+        let mut https = false;
+
+        for line in content.lines() {
+            let line = line.trim();
+
+            if line.starts_with('#') {
+                continue;
+            }
+
+            let lowercase_line = line.to_lowercase();
+            let parts: Vec<&str> = line.split_whitespace().collect();
+
+            if lowercase_line.starts_with("<virtualhost") {
+                in_virtual_host = true;
+                names.clear();
+                https = lowercase_line.contains(&format!(":{}", HTTPS_PORT));
+
+            } else if lowercase_line.starts_with("</virtualhost") {
+                in_virtual_host = false;
+
+                for name in names.drain(..) {
+                    virtual_hosts.push(VirtualHost { name, https });
+                }
+
+            } else if in_virtual_host && !parts.is_empty() {
+                match parts[0].to_lowercase().as_str() {
+                    "servername" | "serveralias" => {
+                        names.extend(parts[1..].iter()
+                            .map(|name| get_apache_server_name(name))
+                            .filter(|name| is_supported_server_name(name))
+                            .map(|name| name.to_string()));
+                    }
+                    "sslengine" if parts.get(1).map(|value| value.eq_ignore_ascii_case("on")) == Some(true) => {
+                        https = true;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        virtual_hosts
+    }
+
+    fn is_https_listen(listen_values: &[&str]) -> bool {
+        listen_values.iter().any(|value| *value == "ssl" || *value == HTTPS_PORT ||
+                                         value.ends_with(&format!(":{}", HTTPS_PORT)))
+    }
+
+    /// `ServerName` may contain scheme and port: `https://example.com:443`
+    fn get_apache_server_name(value: &str) -> &str {
+        let value = value.split("://").last().unwrap_or(value);
+        value.split(':').next().unwrap_or(value)
+    }
+
+    /// Skips catch-all, wildcard and regex names, they can't be checked.
+    fn is_supported_server_name(name: &str) -> bool {
+        !name.is_empty() && name != "_" && name != "\"\"" && !name.contains('*') &&
+            !name.starts_with('~') && !name.contains('$')
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod vhosts_tests {
+    use crate::vhosts::vhosts::{parse_apache_config, parse_nginx_config, VirtualHost};
+
+    #[test]
+    fn nginx_server_names_should_be_parsed() {
+        let config = r#"
+            server {
+                listen 80 default_server;
+                server_name _;
+            }
+
+            server {
+                listen 443 ssl; # tls
+                server_name example.com
+                            www.example.com *.example.com;
+
+                location / {
+                    proxy_pass http://backend;
+                }
+            }
+
+            server {
+                listen 80;
+                server_name ~^(?<sub>.+)\.example\.net$ api.example.net;
+            }
+        "#;
+
+        assert_eq!(parse_nginx_config(config), vec![
+            VirtualHost { name: "example.com".to_string(), https: true },
+            VirtualHost { name: "www.example.com".to_string(), https: true },
+            VirtualHost { name: "api.example.net".to_string(), https: false }
+        ]);
+    }
+
+    #[test]
+    fn apache_server_names_should_be_parsed() {
+        let config = r#"
+            <VirtualHost *:80>
+                ServerName example.com
+                ServerAlias www.example.com
+            </VirtualHost>
+
+            <VirtualHost *:8443>
+                ServerName https://secure.example.com:8443
+                SSLEngine on
+            </VirtualHost>
+        "#;
+
+        assert_eq!(parse_apache_config(config), vec![
+            VirtualHost { name: "example.com".to_string(), https: false },
+            VirtualHost { name: "www.example.com".to_string(), https: false },
+            VirtualHost { name: "secure.example.com".to_string(), https: true }
+        ]);
+    }
+}
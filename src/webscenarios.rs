@@ -1,24 +1,108 @@
 pub mod webscenarios {
-    use std::collections::HashMap;
-
     use serde::Deserialize;
     use serde::Serialize;
 
     use crate::errors::errors::OperationError;
+    use crate::hosts::hosts::ZabbixHost;
     use crate::http::http::send_post_request;
     use crate::types::types::{EmptyResult, OperationResult};
     use crate::zabbix::zabbix;
-    use crate::zabbix::zabbix::{log_zabbix_error, ZabbixError, ZabbixRequest};
-    use crate::config::config::{WebScenarioConfig};
+    use crate::zabbix::zabbix::{get_managed_tag, get_managed_tag_filter, log_zabbix_error,
+                                ZabbixError, ZabbixRequest, ZabbixTag, ZabbixTagFilter};
+    use crate::config::config::{RetrieveMode, WebScenarioConfig};
+    use crate::templates::templates::ObjectNames;
 
     #[derive(Deserialize)]
     pub struct ZabbixWebScenario {
+        pub httptestid: String,
+        pub hostid: String,
+        pub name: String,
+        pub steps: Vec<ZabbixHttpStep>,
+        /// Selected only for hand-made and legacy web scenarios
+        #[serde(default)]
+        pub tags: Vec<ZabbixTag>
+    }
+
+    /// Existing step, its id keeps history of step items when scenario is renamed
+    #[derive(Deserialize)]
+    pub struct ZabbixHttpStep {
+        pub httpstepid: String,
+        pub no: String,
+        pub url: String,
+        /// Selected only for managed web scenarios
+        #[serde(default)]
         pub name: String
     }
 
     #[derive(Serialize)]
     struct GetWebScenariosRequestParams {
-        search: HashMap<String, String>
+        output: Vec<String>,
+        hostids: Vec<String>,
+        tags: Vec<ZabbixTagFilter>,
+        #[serde(rename = "selectSteps")]
+        select_steps: Vec<String>
+    }
+
+    #[derive(Serialize)]
+    struct GetAllWebScenariosRequestParams {
+        output: Vec<String>,
+        hostids: Vec<String>,
+        #[serde(rename = "selectSteps")]
+        select_steps: Vec<String>,
+        #[serde(rename = "selectTags")]
+        select_tags: Vec<String>
+    }
+
+    #[derive(Serialize)]
+    struct RenameWebScenarioParams {
+        httptestid: String,
+        name: String,
+        steps: Vec<RenameWebScenarioStep>
+    }
+
+    /// Only names are changed, the rest of definition is kept
+    #[derive(Serialize)]
+    struct UpdateWebScenarioNamesParams {
+        httptestid: String,
+        name: String,
+        steps: Vec<UpdateStepNameParams>
+    }
+
+    /// Steps are replaced by the given ones, so all of them are sent with their ids
+    #[derive(Serialize)]
+    struct UpdateStepNameParams {
+        httpstepid: String,
+        no: String,
+        name: String,
+        url: String
+    }
+
+    /// Whole definition is replaced, hand-made tags are kept
+    #[derive(Serialize)]
+    struct AdoptWebScenarioParams {
+        httptestid: String,
+        name: String,
+        steps: Vec<RenameWebScenarioStep>,
+        delay: String,
+        retries: u8,
+        http_proxy: String,
+        variables: Vec<HttpField>,
+        tags: Vec<ZabbixTag>
+    }
+
+    /// Only tags are changed, the rest of definition is kept
+    #[derive(Serialize)]
+    struct TagWebScenarioParams {
+        httptestid: String,
+        tags: Vec<ZabbixTag>
+    }
+
+    #[derive(Serialize)]
+    struct RenameWebScenarioStep {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        httpstepid: Option<String>,
+        #[serde(flatten)]
+        step: WebScenarioStep
     }
 
     #[derive(Deserialize)]
@@ -27,33 +111,118 @@ pub mod webscenarios {
         error: Option<ZabbixError>
     }
 
+    /// Web scenario managed by wszl with its hosts and steps
+    #[derive(Deserialize)]
+    pub struct ZabbixManagedWebScenario {
+        pub httptestid: String,
+        pub name: String,
+        /// 0 - enabled, 1 - disabled
+        pub status: String,
+        pub hosts: Vec<ZabbixHost>,
+        pub steps: Vec<ZabbixWebScenarioStep>
+    }
+
+    #[derive(Deserialize)]
+    pub struct ZabbixWebScenarioStep {
+        pub url: String
+    }
+
     #[derive(Serialize)]
-    struct CreateRequestParams {
-        name: String,
-        hostid: String,
-        steps: Vec<WebScenarioStep>,
-        delay: String,
-        retries: u8
+    struct GetManagedWebScenariosRequestParams {
+        output: Vec<String>,
+        tags: Vec<ZabbixTagFilter>,
+        #[serde(rename = "selectHosts")]
+        select_hosts: Vec<String>,
+        #[serde(rename = "selectSteps")]
+        select_steps: Vec<String>
+    }
+
+    #[derive(Deserialize)]
+    struct ManagedWebScenariosResponse {
+        result: Option<Vec<ZabbixManagedWebScenario>>,
+        error: Option<ZabbixError>
+    }
+
+    /// Response of create, update and delete methods, only ids of changed objects
+    #[derive(Deserialize)]
+    struct ChangeWebScenarioResponse {
+        result: Option<serde_json::Value>,
+        error: Option<ZabbixError>
     }
 
     #[derive(Serialize)]
-    struct WebScenarioStep {
-        name: String,
-        url: String,
-        status_codes: String,
-        no: u8
+    pub struct WebScenarioParams {
+        pub name: String,
+        pub hostid: String,
+        pub steps: Vec<WebScenarioStep>,
+        pub delay: String,
+        pub retries: u8,
+        /// Empty for direct requests
+        #[serde(skip_serializing_if = "String::is_empty")]
+        pub http_proxy: String,
+        /// Variables usable in all steps, i.e. `{user}`
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub variables: Vec<HttpField>,
+        pub tags: Vec<ZabbixTag>
+    }
+
+    /// Variable or header of scenario and step
+    #[derive(Serialize, Debug, PartialEq, Clone)]
+    pub struct HttpField {
+        pub name: String,
+        pub value: String
+    }
+
+    impl HttpField {
+        /// Variable names are wrapped in braces (`{token}`), the braces may be omitted in config and scripts
+        pub fn variable(name: &str, value: &str) -> HttpField {
+            let name = if name.starts_with('{') && name.ends_with('}') {
+                name.to_string()
+            } else {
+                format!("{{{}}}", name)
+            };
+
+            HttpField { name, value: value.to_string() }
+        }
+    }
+
+    #[derive(Serialize)]
+    pub struct WebScenarioStep {
+        pub name: String,
+        pub url: String,
+        pub status_codes: String,
+        /// Text which must be present in the response
+        #[serde(skip_serializing_if = "String::is_empty")]
+        pub required: String,
+        pub timeout: String,
+        /// 0 - body, 1 - headers, 2 - both
+        pub retrieve_mode: u8,
+        /// Request body, the step is sent as POST then
+        #[serde(skip_serializing_if = "String::is_empty")]
+        pub posts: String,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub headers: Vec<HttpField>,
+        /// Variables extracted from the response (`regex:...`) for the next steps
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub variables: Vec<HttpField>,
+        pub no: u8
+    }
+
+    /// Only fields of `ZabbixWebScenario`, steps and tags are selected separately
+    fn get_web_scenario_output() -> Vec<String> {
+        vec!["httptestid".to_string(), "hostid".to_string(), "name".to_string()]
     }
 
     pub fn find_web_scenarios(client: &reqwest::blocking::Client,
-                              api_endpoint: &str, auth_token: &str) ->
+                              api_endpoint: &str, auth_token: &str, host_ids: &[String]) ->
                                                         OperationResult<Vec<ZabbixWebScenario>> {
-        info!("searching web scenarios..");
-
-        let mut search_params = HashMap::new();
-        search_params.insert("key_".to_string(), "Check index page '".to_string());
+        info!("searching web scenarios managed by wszl..");
 
         let params = GetWebScenariosRequestParams {
-            search: search_params
+            output: get_web_scenario_output(),
+            hostids: host_ids.to_vec(),
+            tags: vec![get_managed_tag_filter()],
+            select_steps: vec!["httpstepid".to_string(), "no".to_string(), "url".to_string(), "name".to_string()]
         };
 
         let request: ZabbixRequest<GetWebScenariosRequestParams> = ZabbixRequest::new(
@@ -83,42 +252,157 @@ pub mod webscenarios {
         }
     }
 
-    pub fn create_web_scenario(client: &reqwest::blocking::Client,
-                               api_endpoint: &str, auth_token: &str,
-                               scenario_config: &WebScenarioConfig,
-                               item_url: &str, host_id: &str) -> EmptyResult {
-        info!("creating web scenario for '{}'", item_url);
-        debug!("host-id: '{}'", host_id);
+    /// Web scenarios of hosts without wszl tag, i.e. created by hand
+    pub fn find_unmanaged_web_scenarios(client: &reqwest::blocking::Client,
+                                        api_endpoint: &str, auth_token: &str, host_ids: &[String]) ->
+                                                        OperationResult<Vec<ZabbixWebScenario>> {
+        info!("searching web scenarios not managed by wszl..");
+
+        let params = GetAllWebScenariosRequestParams {
+            output: get_web_scenario_output(),
+            hostids: host_ids.to_vec(),
+            select_steps: vec!["httpstepid".to_string(), "no".to_string(), "url".to_string()],
+            select_tags: vec!["tag".to_string(), "value".to_string()]
+        };
+
+        let request: ZabbixRequest<GetAllWebScenariosRequestParams> = ZabbixRequest::new(
+            "httptest.get", params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: WebScenariosResponse = serde_json::from_str(&response)
+                                            .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+                match search_response.result {
+                    Some(web_scenarios) => {
+                        let web_scenarios: Vec<ZabbixWebScenario> = web_scenarios.into_iter()
+                            .filter(|web_scenario| !is_managed(&web_scenario.tags)).collect();
+
+                        debug!("unmanaged web scenarios found: {}", web_scenarios.len());
+                        Ok(web_scenarios)
+                    },
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        error!("unable to find unmanaged web scenarios");
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find unmanaged web scenarios");
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn is_managed(tags: &[ZabbixTag]) -> bool {
+        let managed_tag = get_managed_tag();
+        tags.iter().any(|tag| tag.tag == managed_tag.tag && tag.value == managed_tag.value)
+    }
+
+    /// All web scenarios managed by wszl (on every host)
+    pub fn find_managed_web_scenarios(client: &reqwest::blocking::Client,
+                                      api_endpoint: &str, auth_token: &str) ->
+                                                OperationResult<Vec<ZabbixManagedWebScenario>> {
+        info!("searching all web scenarios managed by wszl..");
 
-        let mut search_params = HashMap::new();
-        search_params.insert("key_".to_string(), "Check index page '".to_string());
+        let params = GetManagedWebScenariosRequestParams {
+            output: vec!["httptestid".to_string(), "name".to_string(), "status".to_string()],
+            tags: vec![get_managed_tag_filter()],
+            select_hosts: vec!["hostid".to_string(), "host".to_string()],
+            select_steps: vec!["url".to_string()]
+        };
+
+        let request: ZabbixRequest<GetManagedWebScenariosRequestParams> = ZabbixRequest::new(
+            "httptest.get", params, auth_token
+        );
 
-        let scenario_name = format!("Check index page '{}'", item_url);
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let search_response: ManagedWebScenariosResponse = serde_json::from_str(&response)
+                                            .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+                match search_response.result {
+                    Some(web_scenarios) => {
+                        debug!("managed web scenarios found: {}", web_scenarios.len());
+                        Ok(web_scenarios)
+                    },
+                    None => {
+                        log_zabbix_error(&search_response.error);
+                        error!("unable to find zabbix web scenarios");
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to find zabbix web scenarios");
+                Err(OperationError::Error)
+            }
+        }
+    }
 
+    /// Web scenario which is created for url
+    pub fn get_web_scenario_params(scenario_config: &WebScenarioConfig, names: &ObjectNames,
+                                   item_url: &str, host_id: &str) -> WebScenarioParams {
         let step = WebScenarioStep {
-            name: "Get page".to_string(),
+            name: names.step.to_string(),
             url: item_url.to_string(),
             status_codes: scenario_config.expected_status_code.to_string(),
+            required: String::new(),
+            timeout: scenario_config.response_timeout.to_string(),
+            retrieve_mode: get_retrieve_mode_value(scenario_config.retrieve_mode),
+            posts: String::new(),
+            headers: vec![],
+            variables: vec![],
             no: 1
         };
 
-        let params = CreateRequestParams {
-            name: scenario_name,
+        WebScenarioParams {
+            name: names.scenario.to_string(),
             hostid: host_id.to_string(),
             delay: scenario_config.update_interval.to_string(),
             retries: scenario_config.attempts,
+            http_proxy: String::new(),
+            variables: scenario_config.variables.iter()
+                            .map(|variable| HttpField::variable(&variable.name, &variable.value)).collect(),
             steps: vec![step],
-        };
+            tags: vec![get_managed_tag()]
+        }
+    }
+
+    pub fn get_retrieve_mode_value(retrieve_mode: RetrieveMode) -> u8 {
+        match retrieve_mode {
+            RetrieveMode::Body => 0,
+            RetrieveMode::Headers => 1,
+            RetrieveMode::Both => 2
+        }
+    }
 
+    pub fn create_web_scenario(client: &reqwest::blocking::Client,
+                               api_endpoint: &str, auth_token: &str, params: WebScenarioParams) -> EmptyResult {
+        let item_url = params.steps.first().map(|step| step.url.to_string()).unwrap_or_default();
 
-        let request: ZabbixRequest<CreateRequestParams> = ZabbixRequest::new(
+        info!("creating web scenario for '{}'", item_url);
+        debug!("host-id: '{}'", params.hostid);
+
+        let request: ZabbixRequest<WebScenarioParams> = ZabbixRequest::new(
             "httptest.create", params, auth_token
         );
 
         match send_post_request(client, api_endpoint, request) {
-            Ok(_) => {
-                info!("web scenario has been created for '{}'", item_url);
-                Ok(())
+            Ok(response) => {
+                let create_response: ChangeWebScenarioResponse = serde_json::from_str(&response)
+                                            .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+                match create_response.result {
+                    Some(_) => {
+                        info!("web scenario has been created for '{}'", item_url);
+                        Ok(())
+                    }
+                    None => {
+                        log_zabbix_error(&create_response.error);
+                        error!("unable to create web scenario for '{}'", item_url);
+                        Err(OperationError::Error)
+                    }
+                }
             }
             Err(_) => {
                 error!("unable to create web scenario for '{}'", item_url);
@@ -126,4 +410,195 @@ pub mod webscenarios {
             }
         }
     }
+
+    /// Items of web scenario are deleted with it, so are the triggers built on them
+    pub fn delete_web_scenario(client: &reqwest::blocking::Client,
+                               api_endpoint: &str, auth_token: &str, httptestid: &str) -> EmptyResult {
+        debug!("delete web scenario '{}'", httptestid);
+
+        let request: ZabbixRequest<Vec<String>> = ZabbixRequest::new(
+            "httptest.delete", vec![httptestid.to_string()], auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let delete_response: ChangeWebScenarioResponse = serde_json::from_str(&response)
+                                            .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+                match delete_response.result {
+                    Some(_) => Ok(()),
+                    None => {
+                        log_zabbix_error(&delete_response.error);
+                        error!("unable to delete web scenario '{}'", httptestid);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to delete web scenario '{}'", httptestid);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Updates name and steps of existing web scenario, steps with the same number keep their ids,
+    /// so collected history stays with the scenario.
+    pub fn rename_web_scenario(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                               web_scenario: &ZabbixWebScenario, params: WebScenarioParams) -> EmptyResult {
+        info!("renaming web scenario '{}' to '{}'", web_scenario.name, params.name);
+
+        let steps = get_rename_steps(web_scenario, params.steps);
+
+        let request: ZabbixRequest<RenameWebScenarioParams> = ZabbixRequest::new(
+            "httptest.update",
+            RenameWebScenarioParams { httptestid: web_scenario.httptestid.to_string(), name: params.name, steps },
+            auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let update_response: ChangeWebScenarioResponse = serde_json::from_str(&response)
+                                            .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+                match update_response.result {
+                    Some(_) => Ok(()),
+                    None => {
+                        log_zabbix_error(&update_response.error);
+                        error!("unable to rename web scenario '{}'", web_scenario.name);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to rename web scenario '{}'", web_scenario.name);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Renames web scenario and its first step, other steps and the rest of definition are kept.
+    /// Steps keep their ids, so collected history stays with the scenario.
+    pub fn update_web_scenario_names(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                                     web_scenario: &ZabbixWebScenario, names: &ObjectNames) -> EmptyResult {
+        info!("renaming web scenario '{}' to '{}'", web_scenario.name, names.scenario);
+
+        let steps = web_scenario.steps.iter().map(|step| UpdateStepNameParams {
+            httpstepid: step.httpstepid.to_string(),
+            no: step.no.to_string(),
+            name: if step.no == "1" { names.step.to_string() } else { step.name.to_string() },
+            url: step.url.to_string()
+        }).collect();
+
+        let request: ZabbixRequest<UpdateWebScenarioNamesParams> = ZabbixRequest::new(
+            "httptest.update",
+            UpdateWebScenarioNamesParams {
+                httptestid: web_scenario.httptestid.to_string(), name: names.scenario.to_string(), steps
+            },
+            auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let update_response: ChangeWebScenarioResponse = serde_json::from_str(&response)
+                                            .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+                match update_response.result {
+                    Some(_) => Ok(()),
+                    None => {
+                        log_zabbix_error(&update_response.error);
+                        error!("unable to rename web scenario '{}'", web_scenario.name);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to rename web scenario '{}'", web_scenario.name);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Hand-made web scenario gets definition from config and wszl tag, so it is managed from now on.
+    /// Steps with the same number keep their ids, so collected history stays with the scenario.
+    pub fn adopt_web_scenario(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                              web_scenario: &ZabbixWebScenario, params: WebScenarioParams) -> EmptyResult {
+        info!("adopting web scenario '{}' as '{}'", web_scenario.name, params.name);
+
+        let mut tags = web_scenario.tags.clone();
+        tags.extend(params.tags);
+
+        let adopt_params = AdoptWebScenarioParams {
+            httptestid: web_scenario.httptestid.to_string(),
+            name: params.name,
+            steps: get_rename_steps(web_scenario, params.steps),
+            delay: params.delay,
+            retries: params.retries,
+            http_proxy: params.http_proxy,
+            variables: params.variables,
+            tags
+        };
+
+        let request: ZabbixRequest<AdoptWebScenarioParams> = ZabbixRequest::new(
+            "httptest.update", adopt_params, auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let update_response: ChangeWebScenarioResponse = serde_json::from_str(&response)
+                                            .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+                match update_response.result {
+                    Some(_) => Ok(()),
+                    None => {
+                        log_zabbix_error(&update_response.error);
+                        error!("unable to adopt web scenario '{}'", web_scenario.name);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to adopt web scenario '{}'", web_scenario.name);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Web scenario gets wszl tag, its own tags and definition are kept
+    pub fn tag_web_scenario(client: &reqwest::blocking::Client, api_endpoint: &str, auth_token: &str,
+                            web_scenario: &ZabbixWebScenario) -> EmptyResult {
+        info!("tagging web scenario '{}' as managed by wszl", web_scenario.name);
+
+        let mut tags = web_scenario.tags.clone();
+        tags.push(get_managed_tag());
+
+        let request: ZabbixRequest<TagWebScenarioParams> = ZabbixRequest::new(
+            "httptest.update",
+            TagWebScenarioParams { httptestid: web_scenario.httptestid.to_string(), tags },
+            auth_token
+        );
+
+        match send_post_request(client, api_endpoint, request) {
+            Ok(response) => {
+                let update_response: ChangeWebScenarioResponse = serde_json::from_str(&response)
+                                            .expect(zabbix::UNSUPPORTED_RESPONSE_MESSAGE);
+                match update_response.result {
+                    Some(_) => Ok(()),
+                    None => {
+                        log_zabbix_error(&update_response.error);
+                        error!("unable to tag web scenario '{}'", web_scenario.name);
+                        Err(OperationError::Error)
+                    }
+                }
+            }
+            Err(_) => {
+                error!("unable to tag web scenario '{}'", web_scenario.name);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn get_rename_steps(web_scenario: &ZabbixWebScenario, steps: Vec<WebScenarioStep>) -> Vec<RenameWebScenarioStep> {
+        steps.into_iter().map(|step| RenameWebScenarioStep {
+            httpstepid: web_scenario.steps.iter()
+                            .find(|existing_step| existing_step.no == step.no.to_string())
+                            .map(|existing_step| existing_step.httpstepid.to_string()),
+            step
+        }).collect()
+    }
 }
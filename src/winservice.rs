@@ -0,0 +1,222 @@
+pub mod winservice {
+    use std::env;
+    use std::error::Error;
+    use std::ffi::OsString;
+    use std::io;
+    use std::iter::once;
+    use std::ptr;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use log::{Level, Record};
+    use log4rs::append::Append;
+    use windows_service::define_windows_service;
+    use windows_service::service::{ServiceAccess, ServiceControl, ServiceControlAccept, ServiceErrorControl,
+                                   ServiceExitCode, ServiceInfo, ServiceStartType, ServiceState, ServiceStatus,
+                                   ServiceType};
+    use windows_service::service_control_handler::{self, ServiceControlHandlerResult, ServiceStatusHandle};
+    use windows_service::service_dispatcher;
+    use windows_service::service_manager::{ServiceManager, ServiceManagerAccess};
+    use windows_sys::Win32::Foundation::HANDLE;
+    use windows_sys::Win32::System::EventLog::{DeregisterEventSource, EVENTLOG_ERROR_TYPE, EVENTLOG_INFORMATION_TYPE,
+                                               EVENTLOG_WARNING_TYPE, RegisterEventSourceW, ReportEventW};
+
+    use crate::errors::errors::OperationError;
+    use crate::shutdown::shutdown::request_shutdown;
+    use crate::types::types::EmptyResult;
+
+    pub const SERVICE_NAME: &str = "wszl";
+    const SERVICE_DISPLAY_NAME: &str = "WSZL";
+    const SERVICE_DESCRIPTION: &str = "Web scenarios for Zabbix low level discovery";
+    const SERVICE_TYPE: ServiceType = ServiceType::OWN_PROCESS;
+
+    /// Daemon loop, returns exit code
+    pub type ServiceMain = Box<dyn FnOnce() -> i32 + Send>;
+
+    static SERVICE_MAIN: Mutex<Option<ServiceMain>> = Mutex::new(None);
+
+    define_windows_service!(ffi_service_main, service_main);
+
+    /// Blocks until the service is stopped. Process must be started by service control manager.
+    pub fn run_as_service(main: ServiceMain) -> EmptyResult {
+        *SERVICE_MAIN.lock().unwrap() = Some(main);
+
+        match service_dispatcher::start(SERVICE_NAME, ffi_service_main) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                error!("unable to start windows service: {}", e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    fn service_main(_arguments: Vec<OsString>) {
+        let event_handler = |control_event| -> ServiceControlHandlerResult {
+            match control_event {
+                ServiceControl::Interrogate => ServiceControlHandlerResult::NoError,
+                ServiceControl::Stop | ServiceControl::Shutdown => {
+                    info!("windows service stop has been requested");
+                    request_shutdown();
+                    ServiceControlHandlerResult::NoError
+                }
+                _ => ServiceControlHandlerResult::NotImplemented
+            }
+        };
+
+        match service_control_handler::register(SERVICE_NAME, event_handler) {
+            Ok(status_handle) => {
+                set_service_status(&status_handle, ServiceState::Running, 0);
+
+                let main = SERVICE_MAIN.lock().unwrap().take();
+
+                let exit_code = match main {
+                    Some(main) => main(),
+                    None => 1
+                };
+
+                set_service_status(&status_handle, ServiceState::Stopped, exit_code);
+            }
+            Err(e) => error!("unable to register windows service control handler: {}", e)
+        }
+    }
+
+    fn set_service_status(status_handle: &ServiceStatusHandle, state: ServiceState, exit_code: i32) {
+        let controls_accepted = if state == ServiceState::Running {
+            ServiceControlAccept::STOP | ServiceControlAccept::SHUTDOWN
+        } else { ServiceControlAccept::empty() };
+
+        let exit_code = if exit_code == 0 {
+            ServiceExitCode::NO_ERROR
+        } else { ServiceExitCode::ServiceSpecific(exit_code as u32) };
+
+        let status = ServiceStatus {
+            service_type: SERVICE_TYPE,
+            current_state: state,
+            controls_accepted,
+            exit_code,
+            checkpoint: 0,
+            wait_hint: Duration::default(),
+            process_id: None
+        };
+
+        if let Err(e) = status_handle.set_service_status(status) {
+            error!("unable to set windows service status: {}", e);
+        }
+    }
+
+    /// Registers auto-started service which runs this executable with `launch_arguments`
+    pub fn install_service(launch_arguments: Vec<OsString>) -> EmptyResult {
+        let executable_path = env::current_exe()?;
+
+        let service_info = ServiceInfo {
+            name: OsString::from(SERVICE_NAME),
+            display_name: OsString::from(SERVICE_DISPLAY_NAME),
+            service_type: SERVICE_TYPE,
+            start_type: ServiceStartType::AutoStart,
+            error_control: ServiceErrorControl::Normal,
+            executable_path,
+            launch_arguments,
+            dependencies: vec![],
+            account_name: None,
+            account_password: None
+        };
+
+        let result = ServiceManager::local_computer(None::<&str>,
+                                                    ServiceManagerAccess::CONNECT | ServiceManagerAccess::CREATE_SERVICE)
+            .and_then(|manager| manager.create_service(&service_info, ServiceAccess::CHANGE_CONFIG))
+            .and_then(|service| service.set_description(SERVICE_DESCRIPTION));
+
+        match result {
+            Ok(_) => {
+                info!("windows service '{}' has been installed", SERVICE_NAME);
+                Ok(())
+            }
+            Err(e) => {
+                error!("unable to install windows service: {}", e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Stops (if running) and deletes the service
+    pub fn uninstall_service() -> EmptyResult {
+        let result = ServiceManager::local_computer(None::<&str>, ServiceManagerAccess::CONNECT)
+            .and_then(|manager| {
+                let service_access = ServiceAccess::QUERY_STATUS | ServiceAccess::STOP | ServiceAccess::DELETE;
+                manager.open_service(SERVICE_NAME, service_access)
+            })
+            .and_then(|service| {
+                service.delete()?;
+
+                if service.query_status()?.current_state != ServiceState::Stopped {
+                    service.stop()?;
+                }
+
+                Ok(())
+            });
+
+        match result {
+            Ok(_) => {
+                info!("windows service '{}' has been uninstalled", SERVICE_NAME);
+                Ok(())
+            }
+            Err(e) => {
+                error!("unable to uninstall windows service: {}", e);
+                Err(OperationError::Error)
+            }
+        }
+    }
+
+    /// Writes records to Windows Application event log
+    #[derive(Debug)]
+    pub struct EventLogAppender {
+        handle: HANDLE
+    }
+
+    impl EventLogAppender {
+        pub fn new(source: &str) -> EventLogAppender {
+            let source = get_wide_string(source);
+            let handle = unsafe { RegisterEventSourceW(ptr::null(), source.as_ptr()) };
+            EventLogAppender { handle }
+        }
+    }
+
+    impl Append for EventLogAppender {
+        fn append(&self, record: &Record) -> Result<(), Box<dyn Error + Sync + Send>> {
+            if self.handle == 0 {
+                return Ok(())
+            }
+
+            let event_type = match record.level() {
+                Level::Error => EVENTLOG_ERROR_TYPE,
+                Level::Warn => EVENTLOG_WARNING_TYPE,
+                _ => EVENTLOG_INFORMATION_TYPE
+            };
+
+            let message = get_wide_string(&format!("[{}] {}", record.target(), record.args()));
+            let strings = [message.as_ptr()];
+
+            let reported = unsafe {
+                ReportEventW(self.handle, event_type, 0, 0, ptr::null_mut(), 1, 0, strings.as_ptr(), ptr::null())
+            };
+
+            if reported == 0 {
+                Err(Box::new(io::Error::last_os_error()))
+            } else { Ok(()) }
+        }
+
+        fn flush(&self) {}
+    }
+
+    impl Drop for EventLogAppender {
+        fn drop(&mut self) {
+            if self.handle != 0 {
+                unsafe { DeregisterEventSource(self.handle); }
+            }
+        }
+    }
+
+    fn get_wide_string(value: &str) -> Vec<u16> {
+        value.encode_utf16().chain(once(0)).collect()
+    }
+}
@@ -6,6 +6,15 @@ pub mod zabbix {
 
     pub const UNSUPPORTED_RESPONSE_MESSAGE: &str = "unsupported zabbix api response";
 
+    pub const MANAGED_TAG_NAME: &str = "managed-by";
+    pub const MANAGED_TAG_VALUE: &str = "wszl";
+
+    /// Tag filter operator 'equals' for *.get methods
+    const TAG_OPERATOR_EQUALS: u8 = 1;
+
+    /// Tag filter operator 'exists' for *.get methods
+    const TAG_OPERATOR_EXISTS: u8 = 4;
+
     #[derive(Serialize)]
     pub struct ZabbixRequest<P: Serialize> {
         pub jsonrpc: String,
@@ -34,14 +43,71 @@ pub mod zabbix {
         pub data: String
     }
 
+    #[derive(Deserialize)]
+    struct ZabbixErrorResponse {
+        error: Option<ZabbixError>
+    }
+
+    /// Error of JSON-RPC response, `None` for response with result or unsupported one
+    pub fn get_response_error(response_text: &str) -> Option<ZabbixError> {
+        serde_json::from_str::<ZabbixErrorResponse>(response_text).ok().and_then(|response| response.error)
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct ZabbixTag {
+        pub tag: String,
+        pub value: String
+    }
+
+    #[derive(Serialize)]
+    pub struct ZabbixTagFilter {
+        pub tag: String,
+        pub value: String,
+        pub operator: u8
+    }
+
+    /// Ownership marker, wszl stamps it on every created object.
+    pub fn get_managed_tag() -> ZabbixTag {
+        ZabbixTag {
+            tag: MANAGED_TAG_NAME.to_string(),
+            value: MANAGED_TAG_VALUE.to_string()
+        }
+    }
+
+    /// Tag with value must have equal value, tag without value only has to be present
+    pub fn get_tag_filter(tag: &str, value: Option<&str>) -> ZabbixTagFilter {
+        ZabbixTagFilter {
+            tag: tag.to_string(),
+            value: value.unwrap_or_default().to_string(),
+            operator: if value.is_some() { TAG_OPERATOR_EQUALS } else { TAG_OPERATOR_EXISTS }
+        }
+    }
+
+    pub fn get_managed_tag_filter() -> ZabbixTagFilter {
+        ZabbixTagFilter {
+            tag: MANAGED_TAG_NAME.to_string(),
+            value: MANAGED_TAG_VALUE.to_string(),
+            operator: TAG_OPERATOR_EQUALS
+        }
+    }
+
+    /// Item key parameter, quoted like Zabbix does in keys of web scenario items
+    /// when it contains `,` or `]` or starts with `"`, `[` or space
+    pub fn quote_key_param(param: &str) -> String {
+        let needs_quotes = param.starts_with(['"', '[', ' ']) || param.contains([',', ']']);
+
+        if needs_quotes && !param.ends_with('\\') {
+            format!("\"{}\"", param.replace('"', "\\\""))
+        } else {
+            param.to_string()
+        }
+    }
+
     pub fn log_zabbix_error(zabbix_error: &Option<ZabbixError>) {
-        match zabbix_error {
-            Some(error) => {
-                error!("error {}", error.code);
-                error!("- message: '{}'", error.message);
-                error!("- data: '{}'", error.data);
-            }
-            None => {}
+        if let Some(error) = zabbix_error {
+            error!("error {}", error.code);
+            error!("- message: '{}'", error.message);
+            error!("- data: '{}'", error.data);
         }
     }
 }